@@ -0,0 +1,308 @@
+//! Command-line interface for the `genesis` binary
+//!
+//! Defines the clap argument structure used by `src/main.rs`. The CLI resolves the
+//! effective [`Config`] through the layered [`ConfigLoader`] (system, user and local
+//! files, `GENESIS_*` environment variables, then `--set` and `--seed` as the
+//! command-line layer) and presets the [`SimulationClock`] from `--start-time` and
+//! `--speed` (falling back to `time.playback_speed`).
+//!
+//! # Examples
+//!
+//! ```text
+//! genesis --set particle.initial_count=500000 --speed 1e6
+//! genesis --config demos/orbit.toml --seed 42 run
+//! genesis --set camera.initial_mode=free validate-config
+//! genesis print-config --sources
+//! genesis --config presets/old_demo.toml migrate-config
+//! genesis --set camera.orbit_distance=250 print-config > demos/far.toml
+//! genesis --headless --until 380000 --output recombination.csv
+//! genesis --headless --until 1e9 --save-session gyr.session
+//! genesis --resume gyr.session
+//! ```
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use crate::config::{Config, ConfigError, ConfigLayer, ConfigLoader, LayeredConfig};
+use crate::session::DEFAULT_SESSION_PATH;
+use crate::time::SimulationClock;
+
+/// Command-line arguments for the `genesis` binary
+#[derive(Debug, Parser)]
+#[command(name = "genesis", version, about = "GENESIS - real-time Big Bang and cosmological evolution simulator")]
+pub struct Cli {
+    /// Configuration file to use as the local layer instead of `./genesis.toml`
+    #[arg(short, long, value_name = "PATH", global = true)]
+    pub config: Option<PathBuf>,
+
+    /// Override a configuration value, e.g. `--set particle.initial_count=500000` (repeatable)
+    #[arg(long = "set", value_name = "KEY=VALUE", global = true)]
+    pub overrides: Vec<String>,
+
+    /// Random seed for stochastic initial conditions (sets `physics.seed`)
+    #[arg(long, value_name = "SEED", global = true)]
+    pub seed: Option<u64>,
+
+    /// Cosmic time to start the simulation at, in years
+    #[arg(long, value_name = "YEARS", global = true)]
+    pub start_time: Option<f64>,
+
+    /// Initial time acceleration factor (clamped to 1x–10¹²x; defaults to `time.playback_speed`)
+    #[arg(long, value_name = "FACTOR", global = true)]
+    pub speed: Option<f64>,
+
+    /// Run the physics without a window or renderer, then exit
+    #[arg(long, global = true)]
+    pub headless: bool,
+
+    /// Cosmic time in years at which a headless run stops (defaults to the present day)
+    #[arg(long, value_name = "YEARS", global = true, requires = "headless")]
+    pub until: Option<f64>,
+
+    /// CSV file that a headless run writes its samples to
+    #[arg(long, value_name = "PATH", global = true, requires = "headless")]
+    pub output: Option<PathBuf>,
+
+    /// Session file to resume from; its configuration replaces the configuration layers
+    #[arg(long, value_name = "PATH", global = true)]
+    pub resume: Option<PathBuf>,
+
+    /// Session file written by F5 (and read by F9), or at the end of a headless run
+    #[arg(long, value_name = "PATH", global = true)]
+    pub save_session: Option<PathBuf>,
+
+    /// Action to perform (defaults to `run`)
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Subcommands accepted by the `genesis` binary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Subcommand)]
+pub enum Command {
+    /// Launch the interactive simulation (default)
+    Run,
+    /// Load and validate the effective configuration, then exit
+    ValidateConfig,
+    /// Print the effective configuration as TOML, then exit
+    PrintConfig {
+        /// Show which layer supplied each value
+        #[arg(long)]
+        sources: bool,
+    },
+    /// Upgrade the local configuration file (or `--config`) to the current schema version
+    MigrateConfig,
+}
+
+impl Cli {
+    /// Returns the requested subcommand, defaulting to [`Command::Run`]
+    pub fn command(&self) -> Command {
+        self.command.unwrap_or(Command::Run)
+    }
+
+    /// Returns the file used as the local configuration layer
+    ///
+    /// This is `--config` if given, otherwise `./genesis.toml`.
+    pub fn local_config_path(&self) -> PathBuf {
+        self.config
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("genesis.toml"))
+    }
+
+    /// Returns the file that the interactive save and load actions use
+    ///
+    /// This is `--save-session` if given, then `--resume`, otherwise
+    /// [`DEFAULT_SESSION_PATH`].
+    pub fn session_path(&self) -> PathBuf {
+        self.save_session
+            .clone()
+            .or_else(|| self.resume.clone())
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_SESSION_PATH))
+    }
+
+    /// Resolves the effective configuration from the command-line arguments
+    ///
+    /// Merges the standard configuration layers, using `--config` (which must exist)
+    /// in place of `./genesis.toml` if given. The `--set` overrides and `--seed` form
+    /// the command-line layer. The result is not validated.
+    ///
+    /// # Returns
+    /// * `Ok(LayeredConfig)` with the merged configuration and value sources
+    /// * `Err(ConfigError)` with every unreadable file, unknown key or invalid value
+    pub fn load_config(&self) -> Result<LayeredConfig, ConfigError> {
+        self.load_config_with(ConfigLoader::standard())
+    }
+
+    /// Resolves the effective configuration on top of the layers of `loader`
+    ///
+    /// Like [`Cli::load_config`], but starting from the given files and environment
+    /// instead of [`ConfigLoader::standard`].
+    pub fn load_config_with(&self, mut loader: ConfigLoader) -> Result<LayeredConfig, ConfigError> {
+        if let Some(path) = &self.config {
+            loader = loader.required_file(ConfigLayer::Local, path);
+        }
+
+        loader = loader.overrides(self.overrides.iter().cloned());
+        if let Some(seed) = self.seed {
+            loader = loader.override_from("--seed", format!("physics.seed={}", seed));
+        }
+
+        loader.load()
+    }
+
+    /// Builds the initial [`SimulationClock`] from `--start-time` and `--speed`
+    ///
+    /// Without `--speed`, the playback speed starts at `config.time.playback_speed`.
+    /// The stepping mode and logarithmic rate are taken from `config.time`. The start
+    /// time is queued as a seek, applied on the clock's first tick.
+    ///
+    /// # Returns
+    /// * `Ok(SimulationClock)` preset with the requested time and speed
+    /// * `Err(String)` if the start time is negative or not finite
+    pub fn clock(&self, config: &Config) -> Result<SimulationClock, String> {
+        let mut clock = SimulationClock::new();
+
+        if let Some(years) = self.start_time {
+            if !years.is_finite() || years < 0.0 {
+                return Err(format!(
+                    "--start-time must be a non-negative number of years, got {}",
+                    years
+                ));
+            }
+            clock.seek_to(years);
+        }
+
+        clock.set_speed(self.speed.unwrap_or(config.time.playback_speed));
+        clock.set_mode(config.time.mode, config.time.decades_per_second);
+
+        Ok(clock)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_defaults_to_run() {
+        let cli = Cli::try_parse_from(["genesis"]).unwrap();
+        assert_eq!(cli.command(), Command::Run);
+        assert!(cli.overrides.is_empty());
+    }
+
+    #[test]
+    fn test_cli_parses_subcommands() {
+        let cli = Cli::try_parse_from(["genesis", "validate-config"]).unwrap();
+        assert_eq!(cli.command(), Command::ValidateConfig);
+
+        let cli = Cli::try_parse_from(["genesis", "print-config", "--sources"]).unwrap();
+        assert_eq!(cli.command(), Command::PrintConfig { sources: true });
+
+        let cli = Cli::try_parse_from(["genesis", "--config", "old.toml", "migrate-config"]).unwrap();
+        assert_eq!(cli.command(), Command::MigrateConfig);
+        assert_eq!(cli.local_config_path(), PathBuf::from("old.toml"));
+    }
+
+    #[test]
+    fn test_cli_collects_repeated_overrides() {
+        let cli = Cli::try_parse_from([
+            "genesis",
+            "--set",
+            "particle.initial_count=500000",
+            "--set",
+            "camera.initial_mode=free",
+            "print-config",
+            "--seed",
+            "7",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.overrides.len(), 2);
+        assert_eq!(cli.seed, Some(7));
+    }
+
+    #[test]
+    fn test_cli_parses_headless_flags() {
+        let cli = Cli::try_parse_from(["genesis", "--headless", "--until", "1e7", "--output", "out.csv"]).unwrap();
+        assert!(cli.headless);
+        assert_eq!(cli.until, Some(1e7));
+        assert_eq!(cli.output, Some(PathBuf::from("out.csv")));
+
+        assert!(Cli::try_parse_from(["genesis", "--until", "1e7"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_session_path() {
+        let cli = Cli::try_parse_from(["genesis"]).unwrap();
+        assert_eq!(cli.session_path(), PathBuf::from(DEFAULT_SESSION_PATH));
+
+        let cli = Cli::try_parse_from(["genesis", "--resume", "gyr.session"]).unwrap();
+        assert_eq!(cli.session_path(), PathBuf::from("gyr.session"));
+
+        let cli = Cli::try_parse_from(["genesis", "--resume", "gyr.session", "--save-session", "next.session"]).unwrap();
+        assert_eq!(cli.resume, Some(PathBuf::from("gyr.session")));
+        assert_eq!(cli.session_path(), PathBuf::from("next.session"));
+    }
+
+    #[test]
+    fn test_cli_load_config_applies_command_line_layer() {
+        let local = std::env::temp_dir().join("genesis_cli_local.toml");
+        std::fs::write(&local, "[particle]\ninitial_count = 20\nmax_count = 300000\n").unwrap();
+
+        let cli = Cli::try_parse_from([
+            "genesis",
+            "--config",
+            local.to_str().unwrap(),
+            "--set",
+            "particle.initial_count=5000",
+            "--seed",
+            "11",
+        ])
+        .unwrap();
+        let loader = ConfigLoader::new().env(std::iter::empty::<(String, String)>());
+        let layered = cli.load_config_with(loader).unwrap();
+        let _ = std::fs::remove_file(&local);
+
+        assert_eq!(layered.config.particle.initial_count, 5000);
+        assert_eq!(layered.config.particle.max_count, 300_000);
+        assert_eq!(layered.sources.get("particle.max_count").unwrap().layer, ConfigLayer::Local);
+        assert_eq!(layered.config.window, Config::default().window);
+        assert_eq!(layered.config.physics.seed, Some(11));
+        let seed = layered.sources.get("physics.seed").unwrap();
+        assert_eq!(seed.layer, ConfigLayer::CommandLine);
+        assert_eq!(seed.location.as_ref().map(|l| l.origin.as_str()), Some("--seed"));
+        let count = layered.sources.get("particle.initial_count").unwrap();
+        assert_eq!(count.location.as_ref().map(|l| l.origin.as_str()), Some("--set"));
+    }
+
+    #[test]
+    fn test_cli_clock_presets() {
+        let cli = Cli::try_parse_from(["genesis", "--start-time", "380000", "--speed", "1e6"]).unwrap();
+        let mut clock = cli.clock(&Config::default()).unwrap();
+        clock.tick(0.0);
+
+        assert_eq!(clock.years(), 380_000.0);
+        assert_eq!(clock.speed(), 1e6);
+    }
+
+    #[test]
+    fn test_cli_clock_uses_configured_playback_speed() {
+        let cli = Cli::try_parse_from(["genesis"]).unwrap();
+        let mut config = Config::default();
+        config.time.playback_speed = 5e4;
+
+        assert_eq!(cli.clock(&config).unwrap().speed(), 5e4);
+    }
+
+    #[test]
+    fn test_cli_clock_clamps_speed() {
+        let cli = Cli::try_parse_from(["genesis", "--speed", "1e15"]).unwrap();
+        assert_eq!(cli.clock(&Config::default()).unwrap().speed(), 1e12);
+    }
+
+    #[test]
+    fn test_cli_clock_rejects_negative_start() {
+        let cli = Cli::try_parse_from(["genesis", "--start-time=-5"]).unwrap();
+        assert!(cli.clock(&Config::default()).is_err());
+    }
+}
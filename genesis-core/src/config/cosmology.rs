@@ -0,0 +1,397 @@
+//! Cosmological parameter configuration
+//!
+//! The `[cosmology]` section holds the background density parameters, the Hubble
+//! constant, the primordial power spectrum and the CMB temperature. A named preset
+//! can be selected with `preset = "planck2018"`; it sets every parameter, and any
+//! parameter written explicitly (in the same file or a higher layer) overrides it:
+//!
+//! ```toml
+//! [cosmology]
+//! preset = "planck2018"
+//! omega_b = 0.06          # everything else stays at the Planck 2018 values
+//! ```
+//!
+//! Within a file the preset is applied before the other keys regardless of the
+//! order they are written in.
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use super::ConfigError;
+
+/// Meters per megaparsec
+const METERS_PER_MPC: f64 = 3.085_677_581_491_367e22;
+
+/// Tolerance on Ω_m + Ω_r + Ω_Λ when checking it against the declared curvature
+pub const CURVATURE_TOLERANCE: f64 = 1e-3;
+
+/// A named set of cosmological parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CosmologyPreset {
+    /// Planck 2018 best fit (TT,TE,EE+lowE+lensing): flat ΛCDM
+    Planck2018,
+    /// Einstein–de Sitter: flat, matter only (Ω_m = 1)
+    EinsteinDeSitter,
+    /// de Sitter: flat, cosmological constant only (Ω_Λ = 1)
+    DeSitter,
+    /// Open universe: Ω_m = 0.3 with no dark energy (Ω_k ≈ 0.7)
+    Open,
+    /// Planck 2018 with a tripled baryon fraction (Ω_b = 0.15)
+    HighBaryon,
+}
+
+impl CosmologyPreset {
+    /// Every preset, in documentation order
+    pub const ALL: [CosmologyPreset; 5] = [
+        CosmologyPreset::Planck2018,
+        CosmologyPreset::EinsteinDeSitter,
+        CosmologyPreset::DeSitter,
+        CosmologyPreset::Open,
+        CosmologyPreset::HighBaryon,
+    ];
+
+    /// Returns the name used in configuration files
+    pub fn name(self) -> &'static str {
+        match self {
+            CosmologyPreset::Planck2018 => "planck2018",
+            CosmologyPreset::EinsteinDeSitter => "einstein_de_sitter",
+            CosmologyPreset::DeSitter => "de_sitter",
+            CosmologyPreset::Open => "open",
+            CosmologyPreset::HighBaryon => "high_baryon",
+        }
+    }
+
+    /// Looks up a preset by name (case-insensitive; `eds` is accepted for
+    /// Einstein–de Sitter and `-` for `_`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase().replace('-', "_");
+        if name == "eds" {
+            return Some(CosmologyPreset::EinsteinDeSitter);
+        }
+        Self::ALL.into_iter().find(|preset| preset.name() == name)
+    }
+
+    /// Returns the full parameter set of the preset
+    pub fn config(self) -> CosmologyConfig {
+        let planck = CosmologyConfig {
+            preset: Some(self.name().to_string()),
+            curvature: "flat".to_string(),
+            omega_m: 0.3153,
+            omega_b: 0.0493,
+            omega_lambda: 0.6847,
+            omega_r: 9.1e-5,
+            h0: 67.36,
+            sigma_8: 0.8111,
+            n_s: 0.9649,
+            a_s: 2.1e-9,
+            t_cmb: 2.7255,
+        };
+        match self {
+            CosmologyPreset::Planck2018 => planck,
+            CosmologyPreset::EinsteinDeSitter => CosmologyConfig {
+                omega_m: 1.0,
+                omega_b: 0.05,
+                omega_lambda: 0.0,
+                omega_r: 0.0,
+                ..planck
+            },
+            CosmologyPreset::DeSitter => CosmologyConfig {
+                omega_m: 0.0,
+                omega_b: 0.0,
+                omega_lambda: 1.0,
+                omega_r: 0.0,
+                ..planck
+            },
+            CosmologyPreset::Open => CosmologyConfig {
+                curvature: "open".to_string(),
+                omega_m: 0.3,
+                omega_b: 0.045,
+                omega_lambda: 0.0,
+                h0: 70.0,
+                ..planck
+            },
+            CosmologyPreset::HighBaryon => CosmologyConfig {
+                omega_b: 0.15,
+                ..planck
+            },
+        }
+    }
+}
+
+/// Cosmological parameters (`[cosmology]` section)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct CosmologyConfig {
+    /// Preset the parameters started from (None if set individually)
+    pub preset: Option<String>,
+    /// Spatial curvature: "flat", "open" (Ω_k > 0) or "closed" (Ω_k < 0)
+    pub curvature: String,
+    /// Total matter density parameter Ω_m (baryons + dark matter)
+    pub omega_m: f64,
+    /// Baryon density parameter Ω_b (part of Ω_m)
+    pub omega_b: f64,
+    /// Dark energy (cosmological constant) density parameter Ω_Λ
+    pub omega_lambda: f64,
+    /// Radiation density parameter Ω_r (photons + relativistic neutrinos)
+    pub omega_r: f64,
+    /// Hubble constant H₀ in km/s/Mpc
+    pub h0: f64,
+    /// Amplitude of matter fluctuations in 8 h⁻¹ Mpc spheres σ₈
+    pub sigma_8: f64,
+    /// Scalar spectral index n_s for P(k) ∝ k^(n_s – 1)
+    pub n_s: f64,
+    /// Scalar amplitude A_s of the primordial power spectrum
+    pub a_s: f64,
+    /// CMB temperature today T_CMB in Kelvin
+    pub t_cmb: f64,
+}
+
+impl Default for CosmologyConfig {
+    /// Planck 2018 parameters without a named preset, so that a saved default
+    /// configuration reloads unchanged
+    fn default() -> Self {
+        CosmologyConfig {
+            preset: None,
+            ..CosmologyPreset::Planck2018.config()
+        }
+    }
+}
+
+impl CosmologyConfig {
+    /// Dotted keys of the parameters a preset sets
+    pub const PARAMETER_KEYS: &'static [&'static str] = &[
+        "cosmology.curvature",
+        "cosmology.omega_m",
+        "cosmology.omega_b",
+        "cosmology.omega_lambda",
+        "cosmology.omega_r",
+        "cosmology.h0",
+        "cosmology.sigma_8",
+        "cosmology.n_s",
+        "cosmology.a_s",
+        "cosmology.t_cmb",
+    ];
+
+    /// Replaces every parameter with those of a named preset
+    ///
+    /// # Returns
+    /// * `Ok(())` if the preset exists
+    /// * `Err(ConfigError::InvalidValue)` listing the known presets otherwise
+    pub fn apply_preset(&mut self, name: &str) -> Result<(), ConfigError> {
+        let preset = CosmologyPreset::from_name(name).ok_or_else(|| ConfigError::InvalidValue {
+            key: "cosmology.preset".to_string(),
+            value: name.to_string(),
+            reason: format!("expected one of {}", preset_names()),
+            location: None,
+        })?;
+        *self = preset.config();
+        Ok(())
+    }
+
+    /// Total density parameter Ω_m + Ω_r + Ω_Λ
+    pub fn omega_total(&self) -> f64 {
+        self.omega_m + self.omega_r + self.omega_lambda
+    }
+
+    /// Curvature density parameter Ω_k = 1 − Ω_total
+    pub fn omega_k(&self) -> f64 {
+        1.0 - self.omega_total()
+    }
+
+    /// Dimensionless Hubble parameter h = H₀ / (100 km/s/Mpc)
+    pub fn little_h(&self) -> f64 {
+        self.h0 / 100.0
+    }
+
+    /// Hubble constant in s⁻¹
+    pub fn h0_per_second(&self) -> f64 {
+        self.h0 * 1e3 / METERS_PER_MPC
+    }
+
+    /// Validates the cosmology configuration
+    ///
+    /// Besides range checks, the density parameters must be consistent with the
+    /// declared curvature: |Ω_k| ≤ [`CURVATURE_TOLERANCE`] for a flat universe, and
+    /// Ω_k beyond the tolerance with the right sign for open or closed universes.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all validations pass
+    /// * `Err(ConfigError)` describing each invalid field by its dotted path
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut errors = Vec::new();
+
+        if let Some(preset) = &self.preset {
+            if CosmologyPreset::from_name(preset).is_none() {
+                errors.push(ConfigError::invalid(
+                    "cosmology.preset",
+                    format!("must be one of {}, got \"{}\"", preset_names(), preset),
+                ));
+            }
+        }
+
+        for (field, value) in [
+            ("cosmology.omega_m", self.omega_m),
+            ("cosmology.omega_b", self.omega_b),
+            ("cosmology.omega_r", self.omega_r),
+        ] {
+            if !(value.is_finite() && value >= 0.0) {
+                errors.push(ConfigError::invalid(field, format!("must be non-negative, got {}", value)));
+            }
+        }
+        if !self.omega_lambda.is_finite() {
+            errors.push(ConfigError::invalid(
+                "cosmology.omega_lambda",
+                format!("must be finite, got {}", self.omega_lambda),
+            ));
+        }
+        for (field, value) in [
+            ("cosmology.h0", self.h0),
+            ("cosmology.sigma_8", self.sigma_8),
+            ("cosmology.n_s", self.n_s),
+            ("cosmology.a_s", self.a_s),
+            ("cosmology.t_cmb", self.t_cmb),
+        ] {
+            if !(value.is_finite() && value > 0.0) {
+                errors.push(ConfigError::invalid(field, format!("must be positive, got {}", value)));
+            }
+        }
+        if self.omega_b > self.omega_m {
+            errors.push(ConfigError::invalid(
+                "cosmology.omega_b",
+                format!("({}) cannot exceed omega_m ({})", self.omega_b, self.omega_m),
+            ));
+        }
+
+        let omega_k = self.omega_k();
+        let consistent = match self.curvature.as_str() {
+            "flat" => Some(omega_k.abs() <= CURVATURE_TOLERANCE),
+            "open" => Some(omega_k > CURVATURE_TOLERANCE),
+            "closed" => Some(omega_k < -CURVATURE_TOLERANCE),
+            _ => None,
+        };
+        match consistent {
+            None => errors.push(ConfigError::invalid(
+                "cosmology.curvature",
+                format!(
+                    "must be \"flat\", \"open\" or \"closed\", got \"{}\"",
+                    self.curvature
+                ),
+            )),
+            Some(false) if errors.is_empty() => errors.push(ConfigError::invalid(
+                "cosmology.curvature",
+                format!(
+                    "(\"{}\") is inconsistent with omega_m + omega_r + omega_lambda = {} (omega_k = {:.4})",
+                    self.curvature,
+                    self.omega_total(),
+                    omega_k
+                ),
+            )),
+            Some(_) => {}
+        }
+
+        ConfigError::collect(errors)
+    }
+}
+
+fn preset_names() -> String {
+    CosmologyPreset::ALL
+        .iter()
+        .map(|preset| format!("\"{}\"", preset.name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_preset_is_valid() {
+        for preset in CosmologyPreset::ALL {
+            let config = preset.config();
+            assert!(
+                config.validate().is_ok(),
+                "{} should validate: {:?}",
+                preset.name(),
+                config.validate()
+            );
+            assert_eq!(CosmologyPreset::from_name(preset.name()), Some(preset));
+        }
+    }
+
+    #[test]
+    fn test_default_is_planck2018() {
+        let config = CosmologyConfig::default();
+        assert_eq!(config.preset, None);
+        assert_eq!(config.h0, CosmologyPreset::Planck2018.config().h0);
+        assert!((config.omega_total() - 1.0).abs() < CURVATURE_TOLERANCE);
+        assert!((config.little_h() - 0.6736).abs() < 1e-12);
+        // H₀ = 67.36 km/s/Mpc ≈ 2.18 × 10⁻¹⁸ s⁻¹
+        assert!((config.h0_per_second() - 2.183e-18).abs() < 1e-21);
+    }
+
+    #[test]
+    fn test_preset_aliases() {
+        assert_eq!(CosmologyPreset::from_name("EdS"), Some(CosmologyPreset::EinsteinDeSitter));
+        assert_eq!(CosmologyPreset::from_name("de-sitter"), Some(CosmologyPreset::DeSitter));
+        assert_eq!(CosmologyPreset::from_name("lcdm"), None);
+    }
+
+    #[test]
+    fn test_apply_unknown_preset_lists_names() {
+        let mut config = CosmologyConfig::default();
+        let error = config.apply_preset("steady_state").unwrap_err();
+        assert_eq!(error.field(), Some("cosmology.preset"));
+        assert!(error.to_string().contains("\"einstein_de_sitter\""));
+        assert_eq!(config, CosmologyConfig::default());
+    }
+
+    #[test]
+    fn test_flat_curvature_requires_unit_total_density() {
+        let config = CosmologyConfig {
+            omega_lambda: 0.5,
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err();
+        assert_eq!(error.field(), Some("cosmology.curvature"));
+        assert!(error.to_string().contains("inconsistent"));
+    }
+
+    #[test]
+    fn test_closed_curvature_requires_excess_density() {
+        let mut config = CosmologyPreset::Open.config();
+        config.curvature = "closed".to_string();
+        assert!(config.validate().is_err());
+
+        config.omega_m = 1.5;
+        config.omega_b = 0.1;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_baryons_cannot_exceed_matter() {
+        let config = CosmologyConfig {
+            omega_b: 0.4,
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err();
+        assert_eq!(error.field(), Some("cosmology.omega_b"));
+    }
+
+    #[test]
+    fn test_invalid_parameters_are_all_reported() {
+        let config = CosmologyConfig {
+            curvature: "saddle".to_string(),
+            h0: 0.0,
+            t_cmb: -2.7,
+            omega_r: -1e-5,
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err();
+        let fields: Vec<_> = error.errors().iter().filter_map(|e| e.field()).collect();
+        assert_eq!(
+            fields,
+            vec!["cosmology.omega_r", "cosmology.h0", "cosmology.t_cmb", "cosmology.curvature"]
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! Epoch boundary configuration
+//!
+//! The `[epochs]` section sets the physical thresholds that mark the transitions
+//! between cosmic epochs. Boundaries are criteria on the state of the universe
+//! rather than fixed times, so the moment each one is crossed follows the
+//! cosmological parameters:
+//!
+//! ```toml
+//! [epochs]
+//! inflation_end_epsilon = 1.0       # slow-roll ε at which inflation ends
+//! qcd_temperature_mev = 155.0       # quark confinement
+//! bbn_start_temperature_mev = 0.1   # deuterium survives, light elements form
+//! bbn_end_temperature_mev = 0.03    # light element formation complete
+//! recombination_ionization = 0.1    # free electron fraction at CMB release
+//! star_formation_redshift = 30.0    # first stars
+//! reionization_redshift = 6.0       # reionization complete
+//! ```
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use super::ConfigError;
+
+/// Thresholds between cosmic epochs (`[epochs]` section)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct EpochConfig {
+    /// First slow-roll parameter ε at which inflation ends
+    pub inflation_end_epsilon: f64,
+    /// Temperature of the QCD transition in MeV, below which quarks are confined
+    pub qcd_temperature_mev: f64,
+    /// Temperature in MeV below which deuterium survives and nucleosynthesis begins
+    pub bbn_start_temperature_mev: f64,
+    /// Temperature in MeV below which nucleosynthesis has finished
+    pub bbn_end_temperature_mev: f64,
+    /// Free electron fraction x_e below which the CMB is released
+    pub recombination_ionization: f64,
+    /// Redshift at which the first stars form
+    pub star_formation_redshift: f64,
+    /// Redshift at which reionization completes and galaxies assemble
+    pub reionization_redshift: f64,
+}
+
+impl Default for EpochConfig {
+    fn default() -> Self {
+        Self {
+            inflation_end_epsilon: 1.0,
+            qcd_temperature_mev: 155.0,
+            bbn_start_temperature_mev: 0.1,
+            bbn_end_temperature_mev: 0.03,
+            recombination_ionization: 0.1,
+            star_formation_redshift: 30.0,
+            reionization_redshift: 6.0,
+        }
+    }
+}
+
+impl EpochConfig {
+    /// Validates the epoch thresholds
+    ///
+    /// # Returns
+    /// * `Ok(())` if all validations pass
+    /// * `Err(ConfigError)` describing each invalid field by its dotted path
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut errors = Vec::new();
+
+        for (field, value) in [
+            ("epochs.inflation_end_epsilon", self.inflation_end_epsilon),
+            ("epochs.qcd_temperature_mev", self.qcd_temperature_mev),
+            ("epochs.bbn_start_temperature_mev", self.bbn_start_temperature_mev),
+            ("epochs.bbn_end_temperature_mev", self.bbn_end_temperature_mev),
+        ] {
+            if !(value.is_finite() && value > 0.0) {
+                errors.push(ConfigError::invalid(field, format!("must be positive, got {}", value)));
+            }
+        }
+        if self.bbn_start_temperature_mev >= self.qcd_temperature_mev {
+            errors.push(ConfigError::invalid(
+                "epochs.bbn_start_temperature_mev",
+                format!(
+                    "({}) must be below qcd_temperature_mev ({})",
+                    self.bbn_start_temperature_mev, self.qcd_temperature_mev
+                ),
+            ));
+        }
+        if self.bbn_end_temperature_mev >= self.bbn_start_temperature_mev {
+            errors.push(ConfigError::invalid(
+                "epochs.bbn_end_temperature_mev",
+                format!(
+                    "({}) must be below bbn_start_temperature_mev ({})",
+                    self.bbn_end_temperature_mev, self.bbn_start_temperature_mev
+                ),
+            ));
+        }
+
+        let ionization = self.recombination_ionization;
+        if !(ionization > 0.0 && ionization < 1.0) {
+            errors.push(ConfigError::invalid(
+                "epochs.recombination_ionization",
+                format!("must be between 0 and 1, got {}", ionization),
+            ));
+        }
+
+        for (field, value) in [
+            ("epochs.star_formation_redshift", self.star_formation_redshift),
+            ("epochs.reionization_redshift", self.reionization_redshift),
+        ] {
+            if !(value.is_finite() && value >= 0.0) {
+                errors.push(ConfigError::invalid(field, format!("must be non-negative, got {}", value)));
+            }
+        }
+        if self.reionization_redshift >= self.star_formation_redshift {
+            errors.push(ConfigError::invalid(
+                "epochs.reionization_redshift",
+                format!(
+                    "({}) must be below star_formation_redshift ({})",
+                    self.reionization_redshift, self.star_formation_redshift
+                ),
+            ));
+        }
+
+        ConfigError::collect(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_valid() {
+        assert!(EpochConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_thresholds_must_be_ordered() {
+        let config = EpochConfig {
+            bbn_start_temperature_mev: 200.0,
+            bbn_end_temperature_mev: 300.0,
+            star_formation_redshift: 5.0,
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err();
+        let fields: Vec<_> = error.errors().iter().filter_map(|e| e.field()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "epochs.bbn_start_temperature_mev",
+                "epochs.bbn_end_temperature_mev",
+                "epochs.reionization_redshift"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ionization_must_be_a_fraction() {
+        let config = EpochConfig {
+            recombination_ionization: 1.0,
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err();
+        assert_eq!(error.field(), Some("epochs.recombination_ionization"));
+    }
+}
@@ -0,0 +1,351 @@
+//! Typed configuration errors
+//!
+//! [`ConfigError`] carries the dotted field path of the offending value and, where
+//! known, the [`SourceLocation`] it came from (file with TOML line/column, environment
+//! variable, or command-line option). Validation collects every failure into a single
+//! [`ConfigError::Multiple`] instead of stopping at the first.
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// Where a configuration value was written
+///
+/// `origin` is a file path, an environment variable name, or a command-line option.
+/// Line and column are 1-based and only present for values read from files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// File path, environment variable name, or command-line option
+    pub origin: String,
+    /// 1-based line number within the file
+    pub line: Option<u32>,
+    /// 1-based column number within the line
+    pub column: Option<u32>,
+}
+
+impl SourceLocation {
+    /// Creates a location without line information (environment or command line)
+    pub fn new(origin: impl Into<String>) -> Self {
+        Self {
+            origin: origin.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// Creates a file location from a byte offset into the file's contents
+    pub fn from_offset(origin: impl Into<String>, content: &str, offset: usize) -> Self {
+        let offset = offset.min(content.len());
+        let before = &content[..offset];
+        let line = before.matches('\n').count() as u32 + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() as u32 + 1;
+        Self {
+            origin: origin.into(),
+            line: Some(line),
+            column: Some(column),
+        }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.origin)?;
+        if let Some(line) = self.line {
+            write!(f, ":{}", line)?;
+            if let Some(column) = self.column {
+                write!(f, ":{}", column)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error produced while loading, overriding or validating configuration
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    /// A configuration file could not be read
+    Read {
+        /// Path of the file
+        path: PathBuf,
+        /// Underlying I/O error message
+        message: String,
+    },
+    /// A configuration file could not be written
+    Write {
+        /// Path of the file
+        path: PathBuf,
+        /// Underlying I/O error message
+        message: String,
+    },
+    /// The configuration could not be serialized to TOML
+    Serialize {
+        /// Serializer error message
+        message: String,
+    },
+    /// A configuration file that was explicitly requested does not exist
+    MissingFile {
+        /// Path of the file
+        path: PathBuf,
+    },
+    /// A configuration file is not valid TOML
+    Parse {
+        /// Position of the syntax error
+        location: SourceLocation,
+        /// Parser error message
+        message: String,
+    },
+    /// A configuration file was written by a newer schema version
+    UnsupportedSchema {
+        /// File that declares the version
+        location: SourceLocation,
+        /// Schema version found in the file
+        found: u32,
+        /// Newest schema version this build understands
+        supported: u32,
+    },
+    /// A key does not correspond to any configuration field
+    UnknownKey {
+        /// Dotted key path as written
+        key: String,
+        /// Where the key was written
+        location: Option<SourceLocation>,
+    },
+    /// A value could not be converted to the type of its field
+    InvalidValue {
+        /// Dotted field path
+        key: String,
+        /// Value as written
+        value: String,
+        /// Why the conversion failed
+        reason: String,
+        /// Where the value was written
+        location: Option<SourceLocation>,
+    },
+    /// A command-line override is not of the form `<section>.<field>=<value>`
+    MalformedOverride {
+        /// The override as given
+        assignment: String,
+    },
+    /// A value parsed correctly but failed validation
+    Invalid {
+        /// Dotted field path
+        field: String,
+        /// Description of the violated constraint
+        message: String,
+        /// Where the value was written (None for defaults)
+        location: Option<SourceLocation>,
+    },
+    /// Several errors collected together
+    Multiple(Vec<ConfigError>),
+}
+
+impl ConfigError {
+    /// Creates a validation error for a field
+    pub fn invalid(field: &str, message: impl Into<String>) -> Self {
+        ConfigError::Invalid {
+            field: field.to_string(),
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// Combines collected errors into a single result
+    ///
+    /// # Returns
+    /// * `Ok(())` if `errors` is empty
+    /// * `Err(error)` with the single error, or [`ConfigError::Multiple`] for several
+    pub fn collect(errors: Vec<ConfigError>) -> Result<(), ConfigError> {
+        let mut flat = Vec::with_capacity(errors.len());
+        for error in errors {
+            match error {
+                ConfigError::Multiple(inner) => flat.extend(inner),
+                other => flat.push(other),
+            }
+        }
+        match flat.len() {
+            0 => Ok(()),
+            1 => Err(flat.remove(0)),
+            _ => Err(ConfigError::Multiple(flat)),
+        }
+    }
+
+    /// Returns the individual errors (a single error yields itself)
+    pub fn errors(&self) -> Vec<&ConfigError> {
+        match self {
+            ConfigError::Multiple(inner) => inner.iter().flat_map(|e| e.errors()).collect(),
+            other => vec![other],
+        }
+    }
+
+    /// Returns the dotted field path this error refers to, if any
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            ConfigError::UnknownKey { key, .. } | ConfigError::InvalidValue { key, .. } => Some(key),
+            ConfigError::Invalid { field, .. } => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Returns where the offending value was written, if known
+    pub fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            ConfigError::Parse { location, .. }
+            | ConfigError::UnsupportedSchema { location, .. } => Some(location),
+            ConfigError::UnknownKey { location, .. }
+            | ConfigError::InvalidValue { location, .. }
+            | ConfigError::Invalid { location, .. } => location.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Attaches a source location to a key-level error that does not have one yet
+    #[must_use]
+    pub fn with_location(mut self, source: SourceLocation) -> Self {
+        match &mut self {
+            ConfigError::UnknownKey { location, .. }
+            | ConfigError::InvalidValue { location, .. }
+            | ConfigError::Invalid { location, .. }
+                if location.is_none() =>
+            {
+                *location = Some(source);
+            }
+            ConfigError::Multiple(inner) => {
+                for error in inner.iter_mut() {
+                    *error = error.clone().with_location(source.clone());
+                }
+            }
+            _ => {}
+        }
+        self
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn prefix(f: &mut fmt::Formatter<'_>, location: &Option<SourceLocation>) -> fmt::Result {
+            match location {
+                Some(location) => write!(f, "{}: ", location),
+                None => Ok(()),
+            }
+        }
+
+        match self {
+            ConfigError::Read { path, message } => write!(
+                f,
+                "Failed to read configuration file '{}': {}",
+                path.display(),
+                message
+            ),
+            ConfigError::Write { path, message } => write!(
+                f,
+                "Failed to write configuration file '{}': {}",
+                path.display(),
+                message
+            ),
+            ConfigError::Serialize { message } => {
+                write!(f, "Failed to serialize configuration: {}", message)
+            }
+            ConfigError::MissingFile { path } => {
+                write!(f, "Configuration file '{}' does not exist", path.display())
+            }
+            ConfigError::Parse { location, message } => {
+                write!(f, "{}: Failed to parse configuration file: {}", location, message)
+            }
+            ConfigError::UnsupportedSchema {
+                location,
+                found,
+                supported,
+            } => write!(
+                f,
+                "{}: schema_version {} is newer than the supported version {}",
+                location, found, supported
+            ),
+            ConfigError::UnknownKey { key, location } => {
+                prefix(f, location)?;
+                write!(f, "Unknown configuration key '{}'", key)
+            }
+            ConfigError::InvalidValue {
+                key,
+                value,
+                reason,
+                location,
+            } => {
+                prefix(f, location)?;
+                write!(f, "Invalid value '{}' for '{}': {}", value, key, reason)
+            }
+            ConfigError::MalformedOverride { assignment } => write!(
+                f,
+                "Invalid override '{}': expected the form <section>.<field>=<value>",
+                assignment
+            ),
+            ConfigError::Invalid {
+                field,
+                message,
+                location,
+            } => {
+                prefix(f, location)?;
+                write!(f, "{} {}", field, message)
+            }
+            ConfigError::Multiple(errors) => {
+                writeln!(f, "{} configuration errors:", errors.len())?;
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "  - {}", error)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_location_from_offset() {
+        let content = "[camera]\ninitial_mode = \"x\"\n";
+        let offset = content.find("\"x\"").unwrap();
+        let location = SourceLocation::from_offset("genesis.toml", content, offset);
+
+        assert_eq!(location.line, Some(2));
+        assert_eq!(location.column, Some(16));
+        assert_eq!(location.to_string(), "genesis.toml:2:16");
+    }
+
+    #[test]
+    fn test_collect_flattens_and_unwraps_single() {
+        assert_eq!(ConfigError::collect(Vec::new()), Ok(()));
+
+        let single = ConfigError::collect(vec![ConfigError::invalid("a.b", "is bad")]).unwrap_err();
+        assert_eq!(single.field(), Some("a.b"));
+
+        let nested = ConfigError::collect(vec![
+            ConfigError::Multiple(vec![
+                ConfigError::invalid("a.b", "is bad"),
+                ConfigError::invalid("a.c", "is bad"),
+            ]),
+            ConfigError::invalid("a.d", "is bad"),
+        ])
+        .unwrap_err();
+        assert_eq!(nested.errors().len(), 3);
+    }
+
+    #[test]
+    fn test_with_location_does_not_overwrite() {
+        let first = SourceLocation::new("GENESIS_WINDOW_WIDTH");
+        let error = ConfigError::invalid("window.width", "must be positive, got 0")
+            .with_location(first.clone())
+            .with_location(SourceLocation::new("--set"));
+
+        assert_eq!(error.location(), Some(&first));
+        assert_eq!(
+            error.to_string(),
+            "GENESIS_WINDOW_WIDTH: window.width must be positive, got 0"
+        );
+    }
+}
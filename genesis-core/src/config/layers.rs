@@ -0,0 +1,888 @@
+//! Layered configuration loading
+//!
+//! Merges configuration from several sources at the level of individual keys.
+//! Layers are applied in increasing priority:
+//!
+//! 1. [`ConfigLayer::Default`] - Built-in defaults from [`Config::default`]
+//! 2. [`ConfigLayer::System`] - `/etc/genesis/config.toml`
+//! 3. [`ConfigLayer::User`] - `~/.config/genesis/config.toml`
+//! 4. [`ConfigLayer::Local`] - `./genesis.toml` (or the file given with `--config`)
+//! 5. [`ConfigLayer::Environment`] - `GENESIS_<SECTION>_<FIELD>` environment variables
+//! 6. [`ConfigLayer::CommandLine`] - `--set` and other command-line overrides
+//!
+//! A site-wide file can therefore set `particle.max_count` while a local file only
+//! changes `particle.initial_count`. Every effective value records the layer that
+//! supplied it in [`ConfigSources`], so ops can tell why a setting is what it is.
+//! Values read from files also record their line and column, which
+//! [`LayeredConfig::validate`] attaches to validation errors. Files written with an
+//! older `schema_version` are migrated as they are read (see [`super::migrate`]).
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml_edit::{ImDocument, TableLike};
+
+use super::migrate::{self, MigrationReport, MigrationTarget, SCHEMA_VERSION, SCHEMA_VERSION_KEY};
+use super::{Config, ConfigError, CosmologyConfig, SourceLocation};
+
+/// Prefix for environment variables that override configuration keys
+pub const ENV_PREFIX: &str = "GENESIS_";
+
+/// A source of configuration values, ordered from lowest to highest priority
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConfigLayer {
+    /// Built-in default values
+    Default,
+    /// System-wide configuration file
+    System,
+    /// User-specific configuration file
+    User,
+    /// Workspace-local configuration file
+    Local,
+    /// `GENESIS_*` environment variables
+    Environment,
+    /// Command-line overrides
+    CommandLine,
+}
+
+impl fmt::Display for ConfigLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ConfigLayer::Default => "default",
+            ConfigLayer::System => "system",
+            ConfigLayer::User => "user",
+            ConfigLayer::Local => "local",
+            ConfigLayer::Environment => "environment",
+            ConfigLayer::CommandLine => "command line",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Where an effective configuration value came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueSource {
+    /// Layer that supplied the value
+    pub layer: ConfigLayer,
+    /// File position, environment variable name, or command-line option (None for defaults)
+    pub location: Option<SourceLocation>,
+}
+
+impl fmt::Display for ValueSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} ({})", self.layer, location),
+            None => write!(f, "{}", self.layer),
+        }
+    }
+}
+
+/// The source of every effective configuration value, keyed by dotted key path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigSources {
+    entries: BTreeMap<String, ValueSource>,
+}
+
+impl Default for ConfigSources {
+    fn default() -> Self {
+        let entries = Config::KEYS
+            .iter()
+            .map(|key| {
+                (
+                    key.to_string(),
+                    ValueSource {
+                        layer: ConfigLayer::Default,
+                        location: None,
+                    },
+                )
+            })
+            .collect();
+        Self { entries }
+    }
+}
+
+impl ConfigSources {
+    /// Returns the source of a configuration key, or `None` if the key is unknown
+    pub fn get(&self, key: &str) -> Option<&ValueSource> {
+        self.entries.get(key)
+    }
+
+    /// Iterates over `(key, source)` pairs in key order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ValueSource)> {
+        self.entries.iter().map(|(key, source)| (key.as_str(), source))
+    }
+
+    /// Records the source of a key; a preset also supplies every parameter it sets
+    fn record(&mut self, key: &str, layer: ConfigLayer, location: SourceLocation) {
+        if key == "cosmology.preset" {
+            for parameter in CosmologyConfig::PARAMETER_KEYS {
+                self.record(parameter, layer, location.clone());
+            }
+        }
+        self.entries.insert(
+            key.to_string(),
+            ValueSource {
+                layer,
+                location: Some(location),
+            },
+        );
+    }
+}
+
+/// A merged configuration together with the source of each of its values
+#[derive(Debug, Clone)]
+pub struct LayeredConfig {
+    /// The effective configuration after all layers have been applied
+    pub config: Config,
+    /// The layer that supplied each effective value
+    pub sources: ConfigSources,
+    /// Migrations applied to files written with an older schema version
+    pub migrations: Vec<MigrationReport>,
+}
+
+impl LayeredConfig {
+    /// Validates the merged configuration, locating each failure at its source
+    ///
+    /// Each validation error is annotated with the file position, environment
+    /// variable or command-line option that supplied the offending value.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all validations pass
+    /// * `Err(ConfigError)` with every failure and its source location
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        self.config.validate().map_err(|error| self.locate(error))
+    }
+
+    fn locate(&self, error: ConfigError) -> ConfigError {
+        match error {
+            ConfigError::Multiple(errors) => {
+                ConfigError::Multiple(errors.into_iter().map(|e| self.locate(e)).collect())
+            }
+            error => {
+                let location = error
+                    .field()
+                    .and_then(|field| self.sources.get(field))
+                    .and_then(|source| source.location.clone());
+                match location {
+                    Some(location) => error.with_location(location),
+                    None => error,
+                }
+            }
+        }
+    }
+
+    /// Formats one line per key showing its effective value and source
+    ///
+    /// ```text
+    /// particle.initial_count = 500000    # command line (--set)
+    /// particle.max_count     = 1000000   # system (/etc/genesis/config.toml)
+    /// ```
+    pub fn report(&self) -> String {
+        let rows: Vec<(String, String)> = Config::KEYS
+            .iter()
+            .map(|key| {
+                let value = self.config.get_value(key).unwrap_or_default();
+                let source = self
+                    .sources
+                    .get(key)
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                (format!("{:<width$} = {}", key, value, width = key_width()), source)
+            })
+            .collect();
+
+        let assignment_width = rows.iter().map(|(a, _)| a.chars().count()).max().unwrap_or(0);
+        rows.iter()
+            .map(|(assignment, source)| {
+                format!("{:<width$}   # {}\n", assignment, source, width = assignment_width)
+            })
+            .collect()
+    }
+}
+
+fn key_width() -> usize {
+    Config::KEYS.iter().map(|key| key.len()).max().unwrap_or(0)
+}
+
+/// Returns the environment variable name that overrides a dotted key
+///
+/// `particle.initial_count` maps to `GENESIS_PARTICLE_INITIAL_COUNT`.
+pub fn env_var_name(key: &str) -> String {
+    format!("{}{}", ENV_PREFIX, key.replace('.', "_").to_uppercase())
+}
+
+/// A configuration file contributing one layer
+#[derive(Debug, Clone)]
+struct FileLayer {
+    layer: ConfigLayer,
+    path: PathBuf,
+    required: bool,
+}
+
+/// Builds a [`LayeredConfig`] from files, environment variables and overrides
+///
+/// # Example
+///
+/// ```no_run
+/// use genesis_core::config::ConfigLoader;
+///
+/// let layered = ConfigLoader::standard()
+///     .overrides(["particle.initial_count=500000"])
+///     .load()
+///     .expect("configuration should load");
+/// print!("{}", layered.report());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConfigLoader {
+    files: Vec<FileLayer>,
+    env: Vec<(String, String)>,
+    /// `(option, assignment)` pairs; the option is recorded as the value's origin
+    overrides: Vec<(String, String)>,
+}
+
+impl ConfigLoader {
+    /// Creates a loader with no layers other than the built-in defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a loader for the standard system, user and local files plus the
+    /// process environment
+    pub fn standard() -> Self {
+        let mut loader = Self::new().file(ConfigLayer::System, "/etc/genesis/config.toml");
+        if let Some(dir) = dirs::config_local_dir() {
+            loader = loader.file(ConfigLayer::User, dir.join("genesis").join("config.toml"));
+        }
+        loader
+            .file(ConfigLayer::Local, "./genesis.toml")
+            .env_os(std::env::vars_os())
+    }
+
+    /// Adds an optional configuration file for a layer (skipped if it does not exist)
+    pub fn file(mut self, layer: ConfigLayer, path: impl Into<PathBuf>) -> Self {
+        self.files.push(FileLayer {
+            layer,
+            path: path.into(),
+            required: false,
+        });
+        self
+    }
+
+    /// Replaces any files for a layer with a file that must exist
+    pub fn required_file(mut self, layer: ConfigLayer, path: impl Into<PathBuf>) -> Self {
+        self.files.retain(|file| file.layer != layer);
+        self.files.push(FileLayer {
+            layer,
+            path: path.into(),
+            required: true,
+        });
+        self
+    }
+
+    /// Sets the environment variables to read `GENESIS_*` overrides from
+    ///
+    /// Variables that do not correspond to a configuration key are ignored.
+    pub fn env<I, K, V>(mut self, vars: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.env = vars
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .filter(|(key, _)| key.starts_with(ENV_PREFIX))
+            .collect();
+        self
+    }
+
+    /// Sets the environment variables from platform strings, as given by
+    /// [`std::env::vars_os`]
+    ///
+    /// Variables without the `GENESIS_` prefix are ignored. A `GENESIS_*` variable
+    /// whose name or value is not valid UTF-8 is skipped with a warning on stderr.
+    pub fn env_os<I>(self, vars: I) -> Self
+    where
+        I: IntoIterator<Item = (OsString, OsString)>,
+    {
+        let vars: Vec<(String, String)> = vars
+            .into_iter()
+            .filter(|(key, _)| key.to_string_lossy().starts_with(ENV_PREFIX))
+            .filter_map(|(key, value)| match (key.into_string(), value.into_string()) {
+                (Ok(key), Ok(value)) => Some((key, value)),
+                (Ok(key), Err(_)) => {
+                    eprintln!("Warning: ignoring {}: value is not valid UTF-8", key);
+                    None
+                }
+                (Err(key), _) => {
+                    eprintln!(
+                        "Warning: ignoring {}: name is not valid UTF-8",
+                        key.to_string_lossy()
+                    );
+                    None
+                }
+            })
+            .collect();
+        self.env(vars)
+    }
+
+    /// Appends command-line `key=value` overrides given with `--set` (applied in order)
+    pub fn overrides<I, S>(mut self, assignments: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for assignment in assignments {
+            self = self.override_from("--set", assignment);
+        }
+        self
+    }
+
+    /// Appends a command-line `key=value` override set by a dedicated `option`
+    ///
+    /// The option (e.g. `--seed`) is recorded as the origin of the value, so
+    /// `print-config --sources` names the flag the user actually passed.
+    pub fn override_from(mut self, option: impl Into<String>, assignment: impl Into<String>) -> Self {
+        self.overrides.push((option.into(), assignment.into()));
+        self
+    }
+
+    /// Merges all layers into an effective configuration
+    ///
+    /// Every layer is applied even after a failure, so all unknown keys and invalid
+    /// values are reported together. The merged configuration is not validated;
+    /// call [`LayeredConfig::validate`] afterwards.
+    ///
+    /// # Returns
+    /// * `Ok(LayeredConfig)` with the merged configuration and value sources
+    /// * `Err(ConfigError)` if a file cannot be read or parsed, a required file is
+    ///   missing, or any layer contains an unknown key or invalid value
+    pub fn load(&self) -> Result<LayeredConfig, ConfigError> {
+        let mut config = Config::default();
+        let mut sources = ConfigSources::default();
+        let mut migrations = Vec::new();
+        let mut errors = Vec::new();
+
+        let mut files: Vec<&FileLayer> = self.files.iter().collect();
+        files.sort_by_key(|file| file.layer);
+
+        for file in files {
+            if !file.path.exists() {
+                if file.required {
+                    errors.push(ConfigError::MissingFile {
+                        path: file.path.clone(),
+                    });
+                }
+                continue;
+            }
+            match apply_file(&mut config, &mut sources, file.layer, &file.path) {
+                Ok(Some(report)) => migrations.push(report),
+                Ok(None) => {}
+                Err(error) => errors.push(error),
+            }
+        }
+
+        for key in Config::KEYS {
+            let name = env_var_name(key);
+            if let Some((_, value)) = self.env.iter().find(|(var, _)| *var == name) {
+                let location = SourceLocation::new(name);
+                match config.set_value(key, value) {
+                    Ok(()) => sources.record(key, ConfigLayer::Environment, location),
+                    Err(error) => errors.push(error.with_location(location)),
+                }
+            }
+        }
+
+        for (option, assignment) in &self.overrides {
+            let location = SourceLocation::new(option.as_str());
+            match config.apply_override(assignment) {
+                Ok(()) => {
+                    if let Some((key, _)) = assignment.split_once('=') {
+                        sources.record(key.trim(), ConfigLayer::CommandLine, location);
+                    }
+                }
+                Err(error) => errors.push(error.with_location(location)),
+            }
+        }
+
+        ConfigError::collect(errors)?;
+        Ok(LayeredConfig {
+            config,
+            sources,
+            migrations,
+        })
+    }
+}
+
+/// A leaf value read from a configuration file
+///
+/// Offsets are `None` for values added by a migration.
+struct FileValue {
+    key: String,
+    value: String,
+    key_offset: Option<usize>,
+    value_offset: Option<usize>,
+}
+
+impl MigrationTarget for Vec<FileValue> {
+    fn contains(&self, key: &str) -> bool {
+        self.iter().any(|entry| entry.key == key)
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> bool {
+        let mut renamed = false;
+        for entry in self.iter_mut().filter(|entry| entry.key == from) {
+            entry.key = to.to_string();
+            renamed = true;
+        }
+        renamed
+    }
+
+    fn move_section(&mut self, from: &str, to: &str) -> bool {
+        let prefix = format!("{}.", from);
+        let mut moved = false;
+        for entry in self.iter_mut() {
+            if let Some(field) = entry.key.strip_prefix(&prefix) {
+                entry.key = format!("{}.{}", to, field);
+                moved = true;
+            }
+        }
+        moved
+    }
+
+    fn remove(&mut self, key: &str) -> bool {
+        let before = self.len();
+        self.retain(|entry| entry.key != key);
+        self.len() != before
+    }
+
+    fn insert(&mut self, key: &str, value: &str) {
+        let value = value
+            .parse::<toml_edit::Value>()
+            .map_or_else(|_| value.to_string(), |v| value_to_string(&v));
+        self.push(FileValue {
+            key: key.to_string(),
+            value,
+            key_offset: None,
+            value_offset: None,
+        });
+    }
+}
+
+/// Reads a TOML file and applies each of its leaf values to `config`
+///
+/// Files older than [`SCHEMA_VERSION`] are migrated first. Unknown keys and invalid
+/// values are collected and returned together, each located at its line and column
+/// in the file.
+///
+/// # Returns
+/// * `Ok(Some(report))` if the file was migrated from an older schema version
+/// * `Ok(None)` if the file is current
+/// * `Err(ConfigError)` with every problem found in the file
+fn apply_file(
+    config: &mut Config,
+    sources: &mut ConfigSources,
+    layer: ConfigLayer,
+    path: &Path,
+) -> Result<Option<MigrationReport>, ConfigError> {
+    let origin = path.display().to_string();
+    let content = fs::read_to_string(path).map_err(|e| ConfigError::Read {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    let document = ImDocument::parse(content.as_str()).map_err(|e| ConfigError::Parse {
+        location: SourceLocation::from_offset(
+            origin.as_str(),
+            &content,
+            e.span().map_or(0, |span| span.start),
+        ),
+        message: e.message().to_string(),
+    })?;
+
+    let mut values = Vec::new();
+    flatten_table("", document.as_table(), &mut values);
+
+    let locate = |offset: Option<usize>| match offset {
+        Some(offset) => SourceLocation::from_offset(origin.as_str(), &content, offset),
+        None => SourceLocation::new(origin.as_str()),
+    };
+
+    let version = match values.iter().position(|entry| entry.key == SCHEMA_VERSION_KEY) {
+        Some(index) => {
+            let entry = values.remove(index);
+            entry.value.parse::<u32>().map_err(|e| ConfigError::InvalidValue {
+                key: entry.key.clone(),
+                value: entry.value.clone(),
+                reason: e.to_string(),
+                location: Some(locate(entry.value_offset)),
+            })?
+        }
+        None => 0,
+    };
+    migrate::check_version(version, &origin)?;
+    let report = (version < SCHEMA_VERSION)
+        .then(|| migrate::apply_migrations(&mut values, version, &origin));
+
+    // A preset replaces a whole section, so apply it before the keys overriding it
+    values.sort_by_key(|entry| !entry.key.ends_with(".preset"));
+
+    let mut errors = Vec::new();
+    for entry in values {
+        let key_location = locate(entry.key_offset);
+        let value_location = locate(entry.value_offset);
+        match config.set_value(&entry.key, &entry.value) {
+            Ok(()) => sources.record(&entry.key, layer, value_location),
+            Err(error @ ConfigError::UnknownKey { .. }) => {
+                errors.push(error.with_location(key_location))
+            }
+            Err(error) => errors.push(error.with_location(value_location)),
+        }
+    }
+    ConfigError::collect(errors)?;
+    Ok(report)
+}
+
+/// Flattens nested TOML tables into dotted-key leaf values with their byte offsets
+fn flatten_table(prefix: &str, table: &dyn TableLike, out: &mut Vec<FileValue>) {
+    for (name, item) in table.iter() {
+        let key = if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+
+        if let Some(inner) = item.as_table_like() {
+            flatten_table(&key, inner, out);
+            continue;
+        }
+
+        let key_offset = table
+            .get_key_value(name)
+            .and_then(|(k, _)| k.span())
+            .map(|span| span.start);
+        let value_offset = item.span().map(|span| span.start).or(key_offset);
+        let value = match item.as_value() {
+            Some(value) => value_to_string(value),
+            None => item.to_string().trim().to_string(),
+        };
+        out.push(FileValue {
+            key,
+            value,
+            key_offset,
+            value_offset,
+        });
+    }
+}
+
+/// Converts a TOML value to the plain string form accepted by [`Config::set_value`]
+fn value_to_string(value: &toml_edit::Value) -> String {
+    match value {
+        toml_edit::Value::String(s) => s.value().clone(),
+        toml_edit::Value::Integer(i) => i.value().to_string(),
+        toml_edit::Value::Float(f) => f.value().to_string(),
+        toml_edit::Value::Boolean(b) => b.value().to_string(),
+        other => other.to_string().trim().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(name);
+        fs::write(&path, content).expect("Failed to write test config file");
+        path
+    }
+
+    #[test]
+    fn test_env_var_name() {
+        assert_eq!(env_var_name("particle.initial_count"), "GENESIS_PARTICLE_INITIAL_COUNT");
+        assert_eq!(env_var_name("window.vsync"), "GENESIS_WINDOW_VSYNC");
+    }
+
+    #[test]
+    fn test_defaults_only() {
+        let layered = ConfigLoader::new().load().unwrap();
+        assert_eq!(layered.config.particle.initial_count, 100_000);
+        for (_, source) in layered.sources.iter() {
+            assert_eq!(source.layer, ConfigLayer::Default);
+        }
+    }
+
+    #[test]
+    fn test_layers_merge_per_key() {
+        let system = write_temp(
+            "genesis_layers_system.toml",
+            "[particle]\nmax_count = 2000000\ninitial_count = 1000\n",
+        );
+        let local = write_temp("genesis_layers_local.toml", "[particle]\ninitial_count = 5000\n");
+
+        let layered = ConfigLoader::new()
+            .file(ConfigLayer::Local, &local)
+            .file(ConfigLayer::System, &system)
+            .load()
+            .unwrap();
+        let _ = fs::remove_file(&system);
+        let _ = fs::remove_file(&local);
+
+        assert_eq!(layered.config.particle.max_count, 2_000_000);
+        assert_eq!(layered.config.particle.initial_count, 5000);
+        assert_eq!(layered.config.particle.base_size, 2.0);
+        assert_eq!(layered.sources.get("particle.max_count").unwrap().layer, ConfigLayer::System);
+        assert_eq!(layered.sources.get("particle.initial_count").unwrap().layer, ConfigLayer::Local);
+        assert_eq!(layered.sources.get("particle.base_size").unwrap().layer, ConfigLayer::Default);
+    }
+
+    #[test]
+    fn test_env_and_command_line_override_files() {
+        let local = write_temp(
+            "genesis_layers_env.toml",
+            "[camera]\ninitial_mode = \"free\"\norbit_distance = 10.0\n",
+        );
+
+        let layered = ConfigLoader::new()
+            .file(ConfigLayer::Local, &local)
+            .env([
+                ("GENESIS_CAMERA_ORBIT_DISTANCE", "50"),
+                ("GENESIS_CAMERA_INITIAL_MODE", "orbit"),
+                ("GENESIS_UNRELATED_SETTING", "ignored"),
+                ("PATH", "/usr/bin"),
+            ])
+            .overrides(["camera.initial_mode=free"])
+            .load()
+            .unwrap();
+        let _ = fs::remove_file(&local);
+
+        assert_eq!(layered.config.camera.orbit_distance, 50.0);
+        assert_eq!(layered.config.camera.initial_mode, "free");
+
+        let distance = layered.sources.get("camera.orbit_distance").unwrap();
+        assert_eq!(distance.layer, ConfigLayer::Environment);
+        assert_eq!(
+            distance.location.as_ref().map(|l| l.origin.as_str()),
+            Some("GENESIS_CAMERA_ORBIT_DISTANCE")
+        );
+        assert_eq!(
+            layered.sources.get("camera.initial_mode").unwrap().layer,
+            ConfigLayer::CommandLine
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_env_os_skips_non_utf8_variables() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let invalid = || OsString::from_vec(vec![0x66, 0x6f, 0xff]);
+        let layered = ConfigLoader::new()
+            .env_os([
+                (OsString::from("PATH"), invalid()),
+                (OsString::from("GENESIS_PARTICLE_MAX_COUNT"), invalid()),
+                (OsString::from_vec(b"GENESIS_\xff".to_vec()), OsString::from("1")),
+                (OsString::from("GENESIS_PARTICLE_INITIAL_COUNT"), OsString::from("42")),
+            ])
+            .load()
+            .unwrap();
+
+        assert_eq!(layered.config.particle.initial_count, 42);
+        assert_eq!(layered.config.particle.max_count, Config::default().particle.max_count);
+        let source = layered.sources.get("particle.max_count").unwrap();
+        assert_eq!(source.layer, ConfigLayer::Default);
+    }
+
+    #[test]
+    fn test_cosmology_preset_is_applied_before_overrides() {
+        let system = write_temp(
+            "genesis_layers_preset_system.toml",
+            "[cosmology]\npreset = \"eds\"\nh0 = 50.0\n",
+        );
+        let local = write_temp(
+            "genesis_layers_preset_local.toml",
+            "[cosmology]\nomega_b = 0.06\npreset = \"planck2018\"\n",
+        );
+
+        let layered = ConfigLoader::new()
+            .file(ConfigLayer::System, &system)
+            .file(ConfigLayer::Local, &local)
+            .env([("GENESIS_COSMOLOGY_SIGMA_8", "0.75")])
+            .load()
+            .unwrap();
+        let _ = fs::remove_file(&system);
+        let _ = fs::remove_file(&local);
+
+        let cosmology = &layered.config.cosmology;
+        assert_eq!(cosmology.preset.as_deref(), Some("planck2018"));
+        assert_eq!(cosmology.omega_b, 0.06, "explicit key should override the preset");
+        assert_eq!(cosmology.h0, 67.36, "a later preset replaces earlier layers");
+        assert_eq!(cosmology.sigma_8, 0.75);
+
+        let h0 = layered.sources.get("cosmology.h0").unwrap();
+        assert_eq!(h0.layer, ConfigLayer::Local);
+        assert_eq!(h0.location.as_ref().and_then(|l| l.line), Some(3));
+        assert_eq!(
+            layered.sources.get("cosmology.sigma_8").unwrap().layer,
+            ConfigLayer::Environment
+        );
+        assert!(layered.validate().is_ok());
+    }
+
+    #[test]
+    fn test_required_file_missing() {
+        let result = ConfigLoader::new()
+            .required_file(ConfigLayer::Local, "genesis_layers_missing.toml")
+            .load();
+        assert!(result.unwrap_err().to_string().contains("does not exist"));
+    }
+
+    #[test]
+    fn test_unknown_key_in_file_is_an_error() {
+        let local = write_temp("genesis_layers_unknown.toml", "[particle]\ncount = 5\n");
+        let result = ConfigLoader::new().file(ConfigLayer::Local, &local).load();
+        let _ = fs::remove_file(&local);
+        assert!(result.unwrap_err().to_string().contains("particle.count"));
+    }
+
+    #[test]
+    fn test_invalid_env_value_names_variable() {
+        let result = ConfigLoader::new()
+            .env([("GENESIS_WINDOW_WIDTH", "wide")])
+            .load();
+        assert!(result.unwrap_err().to_string().contains("GENESIS_WINDOW_WIDTH"));
+    }
+
+    #[test]
+    fn test_file_errors_are_collected_with_line_and_column() {
+        let local = write_temp(
+            "genesis_layers_positions.toml",
+            "[particle]\ncount = 5\n\n[window]\nwidth = \"wide\"\n",
+        );
+        let error = ConfigLoader::new()
+            .file(ConfigLayer::Local, &local)
+            .load()
+            .unwrap_err();
+        let _ = fs::remove_file(&local);
+
+        let errors = error.errors();
+        assert_eq!(errors.len(), 2, "both bad keys should be reported: {}", error);
+
+        assert!(matches!(errors[0], ConfigError::UnknownKey { .. }));
+        let unknown = errors[0].location().unwrap();
+        assert_eq!((unknown.line, unknown.column), (Some(2), Some(1)));
+
+        assert_eq!(errors[1].field(), Some("window.width"));
+        let invalid = errors[1].location().unwrap();
+        assert_eq!((invalid.line, invalid.column), (Some(5), Some(9)));
+    }
+
+    #[test]
+    fn test_parse_error_has_line_and_column() {
+        let local = write_temp("genesis_layers_syntax.toml", "[camera]\ninitial_mode = \n");
+        let error = ConfigLoader::new()
+            .file(ConfigLayer::Local, &local)
+            .load()
+            .unwrap_err();
+        let _ = fs::remove_file(&local);
+
+        assert!(matches!(error, ConfigError::Parse { .. }));
+        assert_eq!(error.location().unwrap().line, Some(2));
+    }
+
+    #[test]
+    fn test_validate_locates_each_invalid_value() {
+        let local = write_temp(
+            "genesis_layers_validate.toml",
+            "[window]\ntitle = \"\"\n\n[camera]\norbit_distance = -1.0\n",
+        );
+        let layered = ConfigLoader::new()
+            .file(ConfigLayer::Local, &local)
+            .env([("GENESIS_PARTICLE_BASE_SIZE", "0")])
+            .load()
+            .unwrap();
+        let _ = fs::remove_file(&local);
+
+        let error = layered.validate().unwrap_err();
+        let errors = error.errors();
+        assert_eq!(errors.len(), 3, "every failure should be collected: {}", error);
+
+        let find = |field: &str| {
+            errors
+                .iter()
+                .find(|e| e.field() == Some(field))
+                .and_then(|e| e.location())
+                .unwrap_or_else(|| panic!("{} should have a location", field))
+        };
+        assert_eq!(find("particle.base_size").origin, "GENESIS_PARTICLE_BASE_SIZE");
+        assert_eq!(find("camera.orbit_distance").line, Some(5));
+        assert_eq!(find("window.title").line, Some(2));
+        assert!(error.to_string().contains(":5:18: camera.orbit_distance must be positive"));
+    }
+
+    #[test]
+    fn test_legacy_file_is_migrated_on_load() {
+        let local = write_temp(
+            "genesis_layers_legacy.toml",
+            "[particle]\nparticle_count = 5000\ncolor_cool = \"#0000ff\"\n\n[camera]\norbit_radius = -1.0\n",
+        );
+        let layered = ConfigLoader::new()
+            .file(ConfigLayer::Local, &local)
+            .load()
+            .unwrap();
+        let _ = fs::remove_file(&local);
+
+        assert_eq!(layered.config.particle.initial_count, 5000);
+        assert_eq!(layered.migrations.len(), 1);
+        assert_eq!(layered.migrations[0].from_version, 0);
+        assert_eq!(layered.migrations[0].changes.len(), 3);
+
+        // Errors on renamed keys point at the original line in the file
+        let error = layered.validate().unwrap_err();
+        assert_eq!(error.field(), Some("camera.orbit_distance"));
+        assert_eq!(error.location().unwrap().line, Some(6));
+    }
+
+    #[test]
+    fn test_current_schema_is_not_migrated() {
+        let local = write_temp(
+            "genesis_layers_current.toml",
+            &format!("schema_version = {}\n[particle]\ninitial_count = 10\n", SCHEMA_VERSION),
+        );
+        let layered = ConfigLoader::new()
+            .file(ConfigLayer::Local, &local)
+            .load()
+            .unwrap();
+        let _ = fs::remove_file(&local);
+
+        assert!(layered.migrations.is_empty());
+        assert_eq!(layered.config.particle.initial_count, 10);
+    }
+
+    #[test]
+    fn test_newer_schema_fails_to_load() {
+        let local = write_temp("genesis_layers_future.toml", "schema_version = 999\n");
+        let result = ConfigLoader::new().file(ConfigLayer::Local, &local).load();
+        let _ = fs::remove_file(&local);
+
+        assert!(matches!(result.unwrap_err(), ConfigError::UnsupportedSchema { found: 999, .. }));
+    }
+
+    #[test]
+    fn test_report_lists_every_key_with_source() {
+        let layered = ConfigLoader::new()
+            .overrides(["particle.initial_count=500000"])
+            .load()
+            .unwrap();
+        let report = layered.report();
+
+        assert_eq!(report.lines().count(), Config::KEYS.len());
+        let line = report
+            .lines()
+            .find(|line| line.starts_with("particle.initial_count"))
+            .unwrap();
+        assert!(line.contains("500000"));
+        assert!(line.contains("# command line (--set)"));
+    }
+}
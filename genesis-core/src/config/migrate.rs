@@ -0,0 +1,565 @@
+//! Configuration schema versioning and migration
+//!
+//! Every configuration file written by [`Config::save`](super::Config::save) carries a
+//! top-level `schema_version`. Files without one are treated as version 0 (the
+//! unversioned Phase 1 layout). When an older file is loaded, the [`MIGRATIONS`]
+//! registered for each version between it and [`SCHEMA_VERSION`] are applied in order,
+//! and the resulting [`MigrationReport`] lists every renamed, moved, removed or
+//! defaulted key so shared preset files can be upgraded knowingly.
+//!
+//! Migrations are declarative [`MigrationStep`]s. The same steps are applied to the
+//! loader's flattened keys (keeping the original line and column for error messages)
+//! and, by [`migrate_file`], to the TOML document itself so the file can be rewritten
+//! in place with its comments intact.
+//!
+//! # Adding a migration
+//!
+//! Bump [`SCHEMA_VERSION`] and append a [`Migration`] whose `from_version` is the
+//! previous version, listing the steps that turn the old layout into the new one.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use toml_edit::{DocumentMut, Item, Table};
+
+use super::{ConfigError, SourceLocation};
+
+/// Current configuration schema version
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Top-level key holding the schema version of a configuration file
+pub const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// A single declarative change between two schema versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationStep {
+    /// A key was renamed or moved to another section
+    RenameKey {
+        /// Old dotted key path
+        from: &'static str,
+        /// New dotted key path
+        to: &'static str,
+    },
+    /// Every key of a section moved to another section
+    MoveSection {
+        /// Old section name
+        from: &'static str,
+        /// New section name
+        to: &'static str,
+    },
+    /// A key is no longer supported and is dropped
+    RemoveKey {
+        /// Dotted key path
+        key: &'static str,
+        /// Why the key was removed
+        reason: &'static str,
+    },
+    /// A key is written with an explicit value if the file does not set it
+    ///
+    /// Used when a default changes, to keep the behaviour older files relied on.
+    AddDefault {
+        /// Dotted key path
+        key: &'static str,
+        /// Value as a TOML literal (strings must be quoted)
+        value: &'static str,
+    },
+}
+
+/// The steps that upgrade a file from `from_version` to `from_version + 1`
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Schema version this migration upgrades from
+    pub from_version: u32,
+    /// Steps applied in order
+    pub steps: &'static [MigrationStep],
+}
+
+/// Registered migrations, ordered by `from_version`
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        // Version 0: unversioned Phase 1 drafts of genesis.toml
+        from_version: 0,
+        steps: &[
+            MigrationStep::RenameKey {
+                from: "time.initial_time_acceleration",
+                to: "time.playback_speed",
+            },
+            MigrationStep::RemoveKey {
+                key: "time.initial_time",
+                reason: "the start time is set with --start-time",
+            },
+            MigrationStep::RenameKey {
+                from: "particle.particle_count",
+                to: "particle.initial_count",
+            },
+            MigrationStep::RenameKey {
+                from: "particle.particle_size_base",
+                to: "particle.base_size",
+            },
+            MigrationStep::RemoveKey {
+                key: "particle.particle_size_variation",
+                reason: "particle sizes are derived from energy",
+            },
+            MigrationStep::RemoveKey {
+                key: "particle.color_hot",
+                reason: "particle colors are derived from temperature",
+            },
+            MigrationStep::RemoveKey {
+                key: "particle.color_cool",
+                reason: "particle colors are derived from temperature",
+            },
+            MigrationStep::RenameKey {
+                from: "camera.orbit_radius",
+                to: "camera.orbit_distance",
+            },
+            MigrationStep::RemoveKey {
+                key: "display.show_epoch_info",
+                reason: "the epoch indicator is not configurable",
+            },
+        ],
+    },
+    Migration {
+        // Version 1: cosmological parameters moved into their own [cosmology] section
+        from_version: 1,
+        steps: &[MigrationStep::RenameKey {
+            from: "physics.spectral_index",
+            to: "cosmology.n_s",
+        }],
+    },
+];
+
+/// A change made to a configuration file by a migration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationChange {
+    /// A key was renamed or moved
+    Renamed {
+        /// Old dotted key path
+        from: String,
+        /// New dotted key path
+        to: String,
+    },
+    /// A section was moved
+    MovedSection {
+        /// Old section name
+        from: String,
+        /// New section name
+        to: String,
+    },
+    /// A key was dropped
+    Removed {
+        /// Dotted key path
+        key: String,
+        /// Why the key was removed
+        reason: String,
+    },
+    /// A key that was not set was given an explicit value
+    Defaulted {
+        /// Dotted key path
+        key: String,
+        /// Value as a TOML literal
+        value: String,
+    },
+}
+
+impl fmt::Display for MigrationChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationChange::Renamed { from, to } => write!(f, "renamed {} to {}", from, to),
+            MigrationChange::MovedSection { from, to } => {
+                write!(f, "moved section [{}] to [{}]", from, to)
+            }
+            MigrationChange::Removed { key, reason } => write!(f, "removed {} ({})", key, reason),
+            MigrationChange::Defaulted { key, value } => write!(f, "set {} = {}", key, value),
+        }
+    }
+}
+
+/// What a migration changed in one configuration file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Path of the migrated file
+    pub origin: String,
+    /// Schema version the file was written with
+    pub from_version: u32,
+    /// Schema version the file was upgraded to
+    pub to_version: u32,
+    /// Every change, in the order applied
+    pub changes: Vec<MigrationChange>,
+}
+
+impl MigrationReport {
+    /// Returns true if the migration did not change any key
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Migrated '{}' from schema version {} to {}",
+            self.origin, self.from_version, self.to_version
+        )?;
+        if self.changes.is_empty() {
+            return write!(f, " (no changes)");
+        }
+        write!(f, ":")?;
+        for change in &self.changes {
+            write!(f, "\n  - {}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// A set of configuration keys that migration steps can be applied to
+pub(crate) trait MigrationTarget {
+    /// Returns true if the dotted key is set
+    fn contains(&self, key: &str) -> bool;
+    /// Renames a dotted key, returning false if it was not set
+    fn rename(&mut self, from: &str, to: &str) -> bool;
+    /// Moves every key of a section, returning false if the section was not set
+    fn move_section(&mut self, from: &str, to: &str) -> bool;
+    /// Removes a dotted key, returning false if it was not set
+    fn remove(&mut self, key: &str) -> bool;
+    /// Sets a dotted key that is not yet set to a TOML literal
+    fn insert(&mut self, key: &str, value: &str);
+}
+
+/// Checks that a file's schema version can be loaded by this build
+pub(crate) fn check_version(version: u32, origin: &str) -> Result<(), ConfigError> {
+    if version > SCHEMA_VERSION {
+        return Err(ConfigError::UnsupportedSchema {
+            location: SourceLocation::new(origin),
+            found: version,
+            supported: SCHEMA_VERSION,
+        });
+    }
+    Ok(())
+}
+
+/// Applies every registered migration from `version` up to [`SCHEMA_VERSION`]
+pub(crate) fn apply_migrations(
+    target: &mut impl MigrationTarget,
+    version: u32,
+    origin: &str,
+) -> MigrationReport {
+    apply(target, version, SCHEMA_VERSION, MIGRATIONS, origin)
+}
+
+fn apply(
+    target: &mut impl MigrationTarget,
+    from_version: u32,
+    to_version: u32,
+    migrations: &[Migration],
+    origin: &str,
+) -> MigrationReport {
+    let mut changes = Vec::new();
+    for migration in migrations
+        .iter()
+        .filter(|m| m.from_version >= from_version && m.from_version < to_version)
+    {
+        for step in migration.steps {
+            if let Some(change) = apply_step(target, step) {
+                changes.push(change);
+            }
+        }
+    }
+    MigrationReport {
+        origin: origin.to_string(),
+        from_version,
+        to_version,
+        changes,
+    }
+}
+
+fn apply_step(target: &mut impl MigrationTarget, step: &MigrationStep) -> Option<MigrationChange> {
+    match *step {
+        MigrationStep::RenameKey { from, to } => {
+            target.rename(from, to).then(|| MigrationChange::Renamed {
+                from: from.to_string(),
+                to: to.to_string(),
+            })
+        }
+        MigrationStep::MoveSection { from, to } => {
+            target.move_section(from, to).then(|| MigrationChange::MovedSection {
+                from: from.to_string(),
+                to: to.to_string(),
+            })
+        }
+        MigrationStep::RemoveKey { key, reason } => {
+            target.remove(key).then(|| MigrationChange::Removed {
+                key: key.to_string(),
+                reason: reason.to_string(),
+            })
+        }
+        MigrationStep::AddDefault { key, value } => {
+            if target.contains(key) {
+                return None;
+            }
+            target.insert(key, value);
+            Some(MigrationChange::Defaulted {
+                key: key.to_string(),
+                value: value.to_string(),
+            })
+        }
+    }
+}
+
+/// Upgrades a configuration file on disk to the current schema version
+///
+/// The file is rewritten only if it is older than [`SCHEMA_VERSION`]. Comments and
+/// the layout of untouched keys are preserved.
+///
+/// # Returns
+/// * `Ok(MigrationReport)` describing the changes (empty if already current)
+/// * `Err(ConfigError)` if the file cannot be read, parsed or written, or was
+///   written by a newer version
+pub fn migrate_file(path: impl AsRef<Path>) -> Result<MigrationReport, ConfigError> {
+    let path = path.as_ref();
+    let origin = path.display().to_string();
+    let content = fs::read_to_string(path).map_err(|e| ConfigError::Read {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    let mut document: DocumentMut = content.parse().map_err(|e: toml_edit::TomlError| {
+        ConfigError::Parse {
+            location: SourceLocation::from_offset(
+                origin.as_str(),
+                &content,
+                e.span().map_or(0, |span| span.start),
+            ),
+            message: e.message().to_string(),
+        }
+    })?;
+
+    let version = match document.get(SCHEMA_VERSION_KEY) {
+        None => 0,
+        Some(item) => item
+            .as_integer()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| ConfigError::InvalidValue {
+                key: SCHEMA_VERSION_KEY.to_string(),
+                value: item.to_string().trim().to_string(),
+                reason: "expected a non-negative integer".to_string(),
+                location: Some(SourceLocation::new(origin.as_str())),
+            })?,
+    };
+    check_version(version, &origin)?;
+    if version == SCHEMA_VERSION {
+        return Ok(MigrationReport {
+            origin,
+            from_version: version,
+            to_version: SCHEMA_VERSION,
+            changes: Vec::new(),
+        });
+    }
+
+    let report = apply_migrations(&mut document, version, &origin);
+    document[SCHEMA_VERSION_KEY] = toml_edit::value(i64::from(SCHEMA_VERSION));
+
+    fs::write(path, document.to_string()).map_err(|e| ConfigError::Write {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    Ok(report)
+}
+
+/// Splits `section.field` into its section (empty for top-level keys) and field
+fn split_key(key: &str) -> (&str, &str) {
+    key.rsplit_once('.').unwrap_or(("", key))
+}
+
+fn section_mut<'a>(document: &'a mut DocumentMut, section: &str) -> Option<&'a mut Table> {
+    if section.is_empty() {
+        Some(document.as_table_mut())
+    } else {
+        document.get_mut(section)?.as_table_mut()
+    }
+}
+
+fn section_or_insert<'a>(document: &'a mut DocumentMut, section: &str) -> Option<&'a mut Table> {
+    if section.is_empty() {
+        Some(document.as_table_mut())
+    } else {
+        document
+            .entry(section)
+            .or_insert_with(toml_edit::table)
+            .as_table_mut()
+    }
+}
+
+impl MigrationTarget for DocumentMut {
+    fn contains(&self, key: &str) -> bool {
+        let (section, field) = split_key(key);
+        let table = if section.is_empty() {
+            Some(self.as_table())
+        } else {
+            self.get(section).and_then(Item::as_table)
+        };
+        table.is_some_and(|table| table.contains_key(field))
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> bool {
+        let (from_section, from_field) = split_key(from);
+        let Some((key, item)) =
+            section_mut(self, from_section).and_then(|table| table.remove_entry(from_field))
+        else {
+            return false;
+        };
+        let (to_section, to_field) = split_key(to);
+        let renamed = toml_edit::Key::new(to_field).with_leaf_decor(key.leaf_decor().clone());
+        match section_or_insert(self, to_section) {
+            Some(table) => {
+                table.insert_formatted(&renamed, item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn move_section(&mut self, from: &str, to: &str) -> bool {
+        let Some(item) = self.as_table_mut().remove(from) else {
+            return false;
+        };
+        if !self.as_table().contains_key(to) {
+            self.as_table_mut().insert(to, item);
+            return true;
+        }
+        if let (Some(source), Some(target)) = (item.as_table(), section_or_insert(self, to)) {
+            for (key, value) in source.iter() {
+                target.insert(key, value.clone());
+            }
+        }
+        true
+    }
+
+    fn remove(&mut self, key: &str) -> bool {
+        let (section, field) = split_key(key);
+        section_mut(self, section)
+            .and_then(|table| table.remove(field))
+            .is_some()
+    }
+
+    fn insert(&mut self, key: &str, value: &str) {
+        let (section, field) = split_key(key);
+        if let (Ok(value), Some(table)) = (value.parse::<toml_edit::Value>(), section_or_insert(self, section)) {
+            table.insert(field, Item::Value(value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY: &str = "\
+# Demo preset from the Phase 1 drafts
+[particle]
+particle_count = 5000 # keep it light
+particle_size_base = 1.5
+color_hot = \"#ffffff\"
+
+[camera]
+orbit_radius = 40.0
+";
+
+    #[test]
+    fn test_migrations_are_contiguous() {
+        for (i, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.from_version, i as u32, "migrations must be ordered without gaps");
+        }
+        assert_eq!(MIGRATIONS.len() as u32, SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_document_migration_reports_changes() {
+        let mut document: DocumentMut = LEGACY.parse().unwrap();
+        let report = apply_migrations(&mut document, 0, "legacy.toml");
+
+        assert_eq!(
+            report.changes,
+            vec![
+                MigrationChange::Renamed {
+                    from: "particle.particle_count".to_string(),
+                    to: "particle.initial_count".to_string(),
+                },
+                MigrationChange::Renamed {
+                    from: "particle.particle_size_base".to_string(),
+                    to: "particle.base_size".to_string(),
+                },
+                MigrationChange::Removed {
+                    key: "particle.color_hot".to_string(),
+                    reason: "particle colors are derived from temperature".to_string(),
+                },
+                MigrationChange::Renamed {
+                    from: "camera.orbit_radius".to_string(),
+                    to: "camera.orbit_distance".to_string(),
+                },
+            ]
+        );
+        let rendered = document.to_string();
+        assert!(rendered.contains("initial_count = 5000 # keep it light"), "got:\n{}", rendered);
+        assert!(rendered.contains("# Demo preset from the Phase 1 drafts"));
+        assert!(!rendered.contains("color_hot"));
+    }
+
+    #[test]
+    fn test_move_section_and_add_default_steps() {
+        const STEPS: &[MigrationStep] = &[
+            MigrationStep::MoveSection {
+                from: "hud",
+                to: "display",
+            },
+            MigrationStep::AddDefault {
+                key: "display.show_fps",
+                value: "false",
+            },
+            MigrationStep::AddDefault {
+                key: "display.show_particle_count",
+                value: "false",
+            },
+        ];
+        let migrations = [Migration {
+            from_version: 0,
+            steps: STEPS,
+        }];
+
+        let mut document: DocumentMut = "[hud]\nshow_fps = true\n".parse().unwrap();
+        let report = apply(&mut document, 0, 1, &migrations, "hud.toml");
+
+        assert_eq!(report.changes.len(), 2, "show_fps is already set: {}", report);
+        assert!(MigrationTarget::contains(&document, "display.show_fps"));
+        assert_eq!(document["display"]["show_fps"].as_bool(), Some(true));
+        assert_eq!(document["display"]["show_particle_count"].as_bool(), Some(false));
+        assert!(document.get("hud").is_none());
+    }
+
+    #[test]
+    fn test_migrate_file_rewrites_and_stamps_version() {
+        let path = std::env::temp_dir().join("genesis_migrate_file.toml");
+        fs::write(&path, LEGACY).unwrap();
+
+        let report = migrate_file(&path).unwrap();
+        let rewritten = fs::read_to_string(&path).unwrap();
+        let again = migrate_file(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(report.from_version, 0);
+        assert_eq!(report.changes.len(), 4);
+        assert!(
+            rewritten.starts_with(&format!("schema_version = {}\n", SCHEMA_VERSION)),
+            "got:\n{}",
+            rewritten
+        );
+        assert!(again.is_empty(), "a current file should not change: {}", again);
+    }
+
+    #[test]
+    fn test_newer_schema_is_rejected() {
+        let error = check_version(SCHEMA_VERSION + 1, "future.toml").unwrap_err();
+        assert!(matches!(error, ConfigError::UnsupportedSchema { .. }));
+        assert!(error.to_string().contains("future.toml"));
+    }
+}
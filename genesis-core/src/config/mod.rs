@@ -0,0 +1,1403 @@
+//! Configuration for Genesis Engine
+//!
+//! This module defines the configuration structure for Phase 1 parameters.
+//! The configuration supports TOML deserialization and provides sensible defaults.
+//!
+//! # Implementation Status
+//!
+//! - Config structs are fully defined with Default implementations
+//! - TOML serialization/deserialization via serde is configured
+//! - Config::load() method is implemented and reads from ./genesis.toml
+//! - ParticleConfig field names match genesis.toml (initial_count, max_count, base_size)
+//! - CameraConfig field names match genesis.toml (initial_mode, orbit_distance)
+//! - Config::load() merges system, user and local files plus `GENESIS_*` environment
+//!   variables key by key (see [`layers`])
+//! - Every config struct implements `Serialize`; [`Config::save`] writes the effective
+//!   configuration back to a TOML file (see [`save`])
+//! - Files carry a `schema_version`; older layouts are upgraded on load with a
+//!   [`MigrationReport`] of what changed (see [`migrate`])
+//! - Validation and loading report typed [`ConfigError`]s with field paths and source
+//!   locations; [`Config::try_load`] returns them instead of falling back to defaults
+//! - The `[cosmology]` section holds the cosmological parameters and can start from a
+//!   named preset such as `planck2018` (see [`cosmology`])
+//! - The `[epochs]` section holds the physical thresholds between cosmic epochs
+//!   (see [`epochs`])
+
+pub mod cosmology;
+pub mod epochs;
+pub mod error;
+pub mod layers;
+pub mod migrate;
+pub mod save;
+
+pub use cosmology::{CosmologyConfig, CosmologyPreset};
+pub use epochs::EpochConfig;
+pub use error::{ConfigError, SourceLocation};
+pub use layers::{ConfigLayer, ConfigLoader, ConfigSources, LayeredConfig, ValueSource};
+pub use migrate::{migrate_file, MigrationReport, SCHEMA_VERSION};
+
+use serde::{Deserialize, Serialize};
+use bevy::prelude::Resource;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::time::TimeMode;
+
+/// Time configuration settings for cosmic simulation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimeConfig {
+    /// Minimum time acceleration factor (1.0 = no acceleration)
+    pub time_acceleration_min: f64,
+    /// Maximum time acceleration factor (10¹² = maximum acceleration)
+    pub time_acceleration_max: f64,
+    /// Playback speed the timeline starts at (within the acceleration range)
+    pub playback_speed: f64,
+    /// Width of the crossfade window around each epoch boundary, in decades of cosmic time
+    pub epoch_transition_decades: f64,
+    /// How real time maps onto cosmic time: "linear" or "logarithmic"
+    pub mode: TimeMode,
+    /// Decades of cosmic time per real second in logarithmic mode
+    pub decades_per_second: f64,
+}
+
+impl Default for TimeConfig {
+    fn default() -> Self {
+        Self {
+            time_acceleration_min: 1.0,
+            time_acceleration_max: 1e12,
+            playback_speed: 1.0,
+            epoch_transition_decades: 0.5,
+            mode: TimeMode::Linear,
+            decades_per_second: 1.0,
+        }
+    }
+}
+
+impl TimeConfig {
+    /// Validates the time configuration
+    ///
+    /// Every failing field is reported, not just the first.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all validations pass
+    /// * `Err(ConfigError)` describing each invalid field by its dotted path
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut errors = Vec::new();
+        if self.time_acceleration_min <= 0.0 {
+            errors.push(ConfigError::invalid(
+                "time.time_acceleration_min",
+                format!("must be positive, got {}", self.time_acceleration_min),
+            ));
+        }
+        if self.time_acceleration_max <= 0.0 {
+            errors.push(ConfigError::invalid(
+                "time.time_acceleration_max",
+                format!("must be positive, got {}", self.time_acceleration_max),
+            ));
+        }
+        if !(self.epoch_transition_decades.is_finite() && self.epoch_transition_decades >= 0.0) {
+            errors.push(ConfigError::invalid(
+                "time.epoch_transition_decades",
+                format!("must be non-negative, got {}", self.epoch_transition_decades),
+            ));
+        }
+        if !(self.decades_per_second.is_finite() && self.decades_per_second > 0.0) {
+            errors.push(ConfigError::invalid(
+                "time.decades_per_second",
+                format!("must be positive, got {}", self.decades_per_second),
+            ));
+        }
+        if errors.is_empty() && self.time_acceleration_min >= self.time_acceleration_max {
+            errors.push(ConfigError::invalid(
+                "time.time_acceleration_min",
+                format!(
+                    "({}) must be less than time_acceleration_max ({})",
+                    self.time_acceleration_min, self.time_acceleration_max
+                ),
+            ));
+        }
+        if errors.is_empty()
+            && !(self.time_acceleration_min..=self.time_acceleration_max).contains(&self.playback_speed)
+        {
+            errors.push(ConfigError::invalid(
+                "time.playback_speed",
+                format!(
+                    "({}) must be between time_acceleration_min ({}) and time_acceleration_max ({})",
+                    self.playback_speed, self.time_acceleration_min, self.time_acceleration_max
+                ),
+            ));
+        }
+        ConfigError::collect(errors)
+    }
+}
+
+/// Particle system configuration settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct ParticleConfig {
+    /// Initial number of particles to simulate
+    pub initial_count: usize,
+    /// Maximum number of particles allowed
+    pub max_count: usize,
+    /// Base size for particle rendering in world units
+    pub base_size: f32,
+}
+
+impl Default for ParticleConfig {
+    fn default() -> Self {
+        Self {
+            initial_count: 100_000,
+            max_count: 1_000_000,
+            base_size: 2.0,
+        }
+    }
+}
+
+impl ParticleConfig {
+    /// Validates the particle configuration
+    ///
+    /// Every failing field is reported, not just the first.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all validations pass
+    /// * `Err(ConfigError)` describing each invalid field by its dotted path
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut errors = Vec::new();
+        if self.initial_count == 0 {
+            errors.push(ConfigError::invalid(
+                "particle.initial_count",
+                format!("must be positive, got {}", self.initial_count),
+            ));
+        }
+        if self.max_count == 0 {
+            errors.push(ConfigError::invalid(
+                "particle.max_count",
+                format!("must be positive, got {}", self.max_count),
+            ));
+        }
+        if errors.is_empty() && self.initial_count > self.max_count {
+            errors.push(ConfigError::invalid(
+                "particle.initial_count",
+                format!(
+                    "({}) cannot exceed max_count ({})",
+                    self.initial_count, self.max_count
+                ),
+            ));
+        }
+        if self.base_size <= 0.0 {
+            errors.push(ConfigError::invalid(
+                "particle.base_size",
+                format!("must be positive, got {}", self.base_size),
+            ));
+        }
+        ConfigError::collect(errors)
+    }
+}
+
+/// Camera system configuration settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CameraConfig {
+    /// Initial camera mode: "free" or "orbit"
+    pub initial_mode: String,
+    /// Default orbit distance for orbit camera mode
+    pub orbit_distance: f64,
+}
+
+impl Default for CameraConfig {
+    fn default() -> Self {
+        Self {
+            initial_mode: "orbit".to_string(),
+            orbit_distance: 100.0,
+        }
+    }
+}
+
+impl CameraConfig {
+    /// Validates the camera configuration
+    ///
+    /// Every failing field is reported, not just the first.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all validations pass
+    /// * `Err(ConfigError)` describing each invalid field by its dotted path
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut errors = Vec::new();
+        if self.initial_mode != "free" && self.initial_mode != "orbit" {
+            errors.push(ConfigError::invalid(
+                "camera.initial_mode",
+                format!("must be \"free\" or \"orbit\", got \"{}\"", self.initial_mode),
+            ));
+        }
+        if self.orbit_distance <= 0.0 {
+            errors.push(ConfigError::invalid(
+                "camera.orbit_distance",
+                format!("must be positive, got {}", self.orbit_distance),
+            ));
+        }
+        ConfigError::collect(errors)
+    }
+}
+
+/// Window configuration settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowConfig {
+    /// Window width in pixels
+    pub width: u32,
+    /// Window height in pixels
+    pub height: u32,
+    /// Window title
+    pub title: String,
+    /// Vertical synchronization (vsync) enabled
+    pub vsync: bool,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            width: 1280,
+            height: 720,
+            title: "Genesis Engine - Big Bang Simulator".to_string(),
+            vsync: true,
+        }
+    }
+}
+
+impl WindowConfig {
+    /// Validates the window configuration
+    ///
+    /// Every failing field is reported, not just the first.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all validations pass
+    /// * `Err(ConfigError)` describing each invalid field by its dotted path
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut errors = Vec::new();
+        if self.width == 0 {
+            errors.push(ConfigError::invalid(
+                "window.width",
+                format!("must be positive, got {}", self.width),
+            ));
+        }
+        if self.width > 3840 {
+            errors.push(ConfigError::invalid(
+                "window.width",
+                format!("({}) exceeds maximum of 3840", self.width),
+            ));
+        }
+        if self.height == 0 {
+            errors.push(ConfigError::invalid(
+                "window.height",
+                format!("must be positive, got {}", self.height),
+            ));
+        }
+        if self.height > 2160 {
+            errors.push(ConfigError::invalid(
+                "window.height",
+                format!("({}) exceeds maximum of 2160", self.height),
+            ));
+        }
+        if self.title.is_empty() {
+            errors.push(ConfigError::invalid("window.title", "cannot be empty"));
+        }
+        if self.title.len() > 200 {
+            errors.push(ConfigError::invalid(
+                "window.title",
+                format!("length ({}) exceeds maximum of 200", self.title.len()),
+            ));
+        }
+        ConfigError::collect(errors)
+    }
+}
+
+/// Display/HUD configuration settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    /// Display FPS counter in HUD
+    pub show_fps: bool,
+    /// Display particle count
+    pub show_particle_count: bool,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            show_fps: true,
+            show_particle_count: true,
+        }
+    }
+}
+
+impl DisplayConfig {
+    /// Validates the display configuration
+    ///
+    /// # Returns
+    /// * `Ok(())` if all validations pass
+    /// * `Err(ConfigError)` describing each invalid field by its dotted path
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        // DisplayConfig only contains boolean fields which are always valid
+        Ok(())
+    }
+}
+
+/// Physics configuration settings for stochastic initial conditions and stepping
+///
+/// Physics advances on a fixed timestep, independent of the frame rate, so a run is
+/// reproducible from its seed and inputs. The cosmological parameters themselves
+/// live in [`CosmologyConfig`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PhysicsConfig {
+    /// Random seed for stochastic initial conditions (None = seeded from entropy)
+    pub seed: Option<u64>,
+    /// Fixed physics steps per second of real time
+    pub timestep_hz: f64,
+    /// Integration substeps per fixed step
+    pub substeps: u32,
+}
+
+impl Default for PhysicsConfig {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            timestep_hz: 60.0,
+            substeps: 1,
+        }
+    }
+}
+
+impl PhysicsConfig {
+    /// Validates the physics configuration
+    ///
+    /// # Returns
+    /// * `Ok(())` if all validations pass
+    /// * `Err(ConfigError)` describing each invalid field by its dotted path
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        // Every seed is valid; an unset seed is drawn from entropy
+        let mut errors = Vec::new();
+        if !(self.timestep_hz.is_finite() && self.timestep_hz > 0.0) {
+            errors.push(ConfigError::invalid(
+                "physics.timestep_hz",
+                format!("must be positive, got {}", self.timestep_hz),
+            ));
+        }
+        if self.substeps == 0 {
+            errors.push(ConfigError::invalid("physics.substeps", "must be at least 1"));
+        }
+        ConfigError::collect(errors)
+    }
+}
+
+/// Main configuration structure for Genesis Engine
+///
+/// This struct contains all Phase 1 parameters for the engine configuration.
+/// It supports deserialization from TOML format and provides sensible defaults.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Time configuration
+    pub time: TimeConfig,
+    /// Particle system configuration
+    pub particle: ParticleConfig,
+    /// Camera configuration
+    pub camera: CameraConfig,
+    /// Window configuration
+    pub window: WindowConfig,
+    /// Display/HUD configuration
+    pub display: DisplayConfig,
+    /// Physics configuration
+    pub physics: PhysicsConfig,
+    /// Cosmological parameters
+    pub cosmology: CosmologyConfig,
+    /// Thresholds between cosmic epochs
+    pub epochs: EpochConfig,
+}
+
+impl Config {
+    /// Creates a new configuration with default values
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates the configuration
+    ///
+    /// This method validates all sub-configurations: time, particle, camera, window, display,
+    /// physics, cosmology and epochs.
+    /// Failures from every section are collected rather than stopping at the first.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all validations pass
+    /// * `Err(ConfigError)` with every failure ([`ConfigError::Multiple`] if more than one)
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let errors = [
+            self.time.validate(),
+            self.particle.validate(),
+            self.camera.validate(),
+            self.window.validate(),
+            self.display.validate(),
+            self.physics.validate(),
+            self.cosmology.validate(),
+            self.epochs.validate(),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect();
+        ConfigError::collect(errors)
+    }
+
+    /// Dotted key paths of every configurable value, in `genesis.toml` order
+    ///
+    /// These are the keys accepted by [`Config::set_value`] and [`Config::get_value`].
+    pub const KEYS: &'static [&'static str] = &[
+        "time.time_acceleration_min",
+        "time.time_acceleration_max",
+        "time.playback_speed",
+        "time.epoch_transition_decades",
+        "time.mode",
+        "time.decades_per_second",
+        "particle.initial_count",
+        "particle.max_count",
+        "particle.base_size",
+        "camera.initial_mode",
+        "camera.orbit_distance",
+        "window.width",
+        "window.height",
+        "window.title",
+        "window.vsync",
+        "display.show_fps",
+        "display.show_particle_count",
+        "physics.seed",
+        "physics.timestep_hz",
+        "physics.substeps",
+        "cosmology.preset",
+        "cosmology.curvature",
+        "cosmology.omega_m",
+        "cosmology.omega_b",
+        "cosmology.omega_lambda",
+        "cosmology.omega_r",
+        "cosmology.h0",
+        "cosmology.sigma_8",
+        "cosmology.n_s",
+        "cosmology.a_s",
+        "cosmology.t_cmb",
+        "epochs.inflation_end_epsilon",
+        "epochs.qcd_temperature_mev",
+        "epochs.bbn_start_temperature_mev",
+        "epochs.bbn_end_temperature_mev",
+        "epochs.recombination_ionization",
+        "epochs.star_formation_redshift",
+        "epochs.reionization_redshift",
+    ];
+
+    /// Returns the current value of a configuration key formatted as a string
+    ///
+    /// The returned string is accepted by [`Config::set_value`] for the same key.
+    /// An unset `physics.seed` or `cosmology.preset` is reported as `"none"`.
+    ///
+    /// # Returns
+    /// * `Some(String)` with the formatted value if the key exists
+    /// * `None` if the key is unknown
+    pub fn get_value(&self, key: &str) -> Option<String> {
+        let value = match key {
+            "time.time_acceleration_min" => self.time.time_acceleration_min.to_string(),
+            "time.time_acceleration_max" => self.time.time_acceleration_max.to_string(),
+            "time.playback_speed" => self.time.playback_speed.to_string(),
+            "time.epoch_transition_decades" => self.time.epoch_transition_decades.to_string(),
+            "time.mode" => self.time.mode.to_string(),
+            "time.decades_per_second" => self.time.decades_per_second.to_string(),
+            "particle.initial_count" => self.particle.initial_count.to_string(),
+            "particle.max_count" => self.particle.max_count.to_string(),
+            "particle.base_size" => self.particle.base_size.to_string(),
+            "camera.initial_mode" => self.camera.initial_mode.clone(),
+            "camera.orbit_distance" => self.camera.orbit_distance.to_string(),
+            "window.width" => self.window.width.to_string(),
+            "window.height" => self.window.height.to_string(),
+            "window.title" => self.window.title.clone(),
+            "window.vsync" => self.window.vsync.to_string(),
+            "display.show_fps" => self.display.show_fps.to_string(),
+            "display.show_particle_count" => self.display.show_particle_count.to_string(),
+            "physics.seed" => self
+                .physics
+                .seed
+                .map_or_else(|| "none".to_string(), |seed| seed.to_string()),
+            "physics.timestep_hz" => self.physics.timestep_hz.to_string(),
+            "physics.substeps" => self.physics.substeps.to_string(),
+            "cosmology.preset" => self
+                .cosmology
+                .preset
+                .clone()
+                .unwrap_or_else(|| "none".to_string()),
+            "cosmology.curvature" => self.cosmology.curvature.clone(),
+            "cosmology.omega_m" => self.cosmology.omega_m.to_string(),
+            "cosmology.omega_b" => self.cosmology.omega_b.to_string(),
+            "cosmology.omega_lambda" => self.cosmology.omega_lambda.to_string(),
+            "cosmology.omega_r" => self.cosmology.omega_r.to_string(),
+            "cosmology.h0" => self.cosmology.h0.to_string(),
+            "cosmology.sigma_8" => self.cosmology.sigma_8.to_string(),
+            "cosmology.n_s" => self.cosmology.n_s.to_string(),
+            "cosmology.a_s" => self.cosmology.a_s.to_string(),
+            "cosmology.t_cmb" => self.cosmology.t_cmb.to_string(),
+            "epochs.inflation_end_epsilon" => self.epochs.inflation_end_epsilon.to_string(),
+            "epochs.qcd_temperature_mev" => self.epochs.qcd_temperature_mev.to_string(),
+            "epochs.bbn_start_temperature_mev" => self.epochs.bbn_start_temperature_mev.to_string(),
+            "epochs.bbn_end_temperature_mev" => self.epochs.bbn_end_temperature_mev.to_string(),
+            "epochs.recombination_ionization" => self.epochs.recombination_ionization.to_string(),
+            "epochs.star_formation_redshift" => self.epochs.star_formation_redshift.to_string(),
+            "epochs.reionization_redshift" => self.epochs.reionization_redshift.to_string(),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// Sets a single configuration value addressed by its dotted key path
+    ///
+    /// Keys take the form `<section>.<field>`, mirroring the layout of `genesis.toml`
+    /// (e.g. `particle.initial_count` or `camera.initial_mode`). The value is parsed
+    /// according to the type of the target field. String values may optionally be
+    /// wrapped in double quotes.
+    ///
+    /// Setting `cosmology.preset` replaces every cosmological parameter with the
+    /// preset's values, so it should be applied before individual parameters.
+    ///
+    /// The resulting configuration is not validated; call [`Config::validate`] afterwards.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the key exists and the value parses as the field's type
+    /// * `Err(ConfigError::UnknownKey)` if the key is unknown
+    /// * `Err(ConfigError::InvalidValue)` if the value cannot be parsed
+    pub fn set_value(&mut self, key: &str, value: &str) -> Result<(), ConfigError> {
+        let value = value.trim();
+        match key {
+            "time.time_acceleration_min" => self.time.time_acceleration_min = parse_value(key, value)?,
+            "time.time_acceleration_max" => self.time.time_acceleration_max = parse_value(key, value)?,
+            "time.playback_speed" => self.time.playback_speed = parse_value(key, value)?,
+            "time.epoch_transition_decades" => {
+                self.time.epoch_transition_decades = parse_value(key, value)?
+            }
+            "time.mode" => self.time.mode = parse_value(key, value)?,
+            "time.decades_per_second" => self.time.decades_per_second = parse_value(key, value)?,
+            "particle.initial_count" => self.particle.initial_count = parse_value(key, value)?,
+            "particle.max_count" => self.particle.max_count = parse_value(key, value)?,
+            "particle.base_size" => self.particle.base_size = parse_value(key, value)?,
+            "camera.initial_mode" => self.camera.initial_mode = unquote(value).to_string(),
+            "camera.orbit_distance" => self.camera.orbit_distance = parse_value(key, value)?,
+            "window.width" => self.window.width = parse_value(key, value)?,
+            "window.height" => self.window.height = parse_value(key, value)?,
+            "window.title" => self.window.title = unquote(value).to_string(),
+            "window.vsync" => self.window.vsync = parse_value(key, value)?,
+            "display.show_fps" => self.display.show_fps = parse_value(key, value)?,
+            "display.show_particle_count" => self.display.show_particle_count = parse_value(key, value)?,
+            "physics.seed" => {
+                self.physics.seed = if value.eq_ignore_ascii_case("none") {
+                    None
+                } else {
+                    Some(parse_value(key, value)?)
+                }
+            }
+            "physics.timestep_hz" => self.physics.timestep_hz = parse_value(key, value)?,
+            "physics.substeps" => self.physics.substeps = parse_value(key, value)?,
+            "cosmology.preset" => {
+                let name = unquote(value);
+                if name.eq_ignore_ascii_case("none") {
+                    self.cosmology.preset = None;
+                } else {
+                    self.cosmology.apply_preset(name)?;
+                }
+            }
+            "cosmology.curvature" => self.cosmology.curvature = unquote(value).to_string(),
+            "cosmology.omega_m" => self.cosmology.omega_m = parse_value(key, value)?,
+            "cosmology.omega_b" => self.cosmology.omega_b = parse_value(key, value)?,
+            "cosmology.omega_lambda" => self.cosmology.omega_lambda = parse_value(key, value)?,
+            "cosmology.omega_r" => self.cosmology.omega_r = parse_value(key, value)?,
+            "cosmology.h0" => self.cosmology.h0 = parse_value(key, value)?,
+            "cosmology.sigma_8" => self.cosmology.sigma_8 = parse_value(key, value)?,
+            "cosmology.n_s" => self.cosmology.n_s = parse_value(key, value)?,
+            "cosmology.a_s" => self.cosmology.a_s = parse_value(key, value)?,
+            "cosmology.t_cmb" => self.cosmology.t_cmb = parse_value(key, value)?,
+            "epochs.inflation_end_epsilon" => {
+                self.epochs.inflation_end_epsilon = parse_value(key, value)?
+            }
+            "epochs.qcd_temperature_mev" => self.epochs.qcd_temperature_mev = parse_value(key, value)?,
+            "epochs.bbn_start_temperature_mev" => {
+                self.epochs.bbn_start_temperature_mev = parse_value(key, value)?
+            }
+            "epochs.bbn_end_temperature_mev" => {
+                self.epochs.bbn_end_temperature_mev = parse_value(key, value)?
+            }
+            "epochs.recombination_ionization" => {
+                self.epochs.recombination_ionization = parse_value(key, value)?
+            }
+            "epochs.star_formation_redshift" => {
+                self.epochs.star_formation_redshift = parse_value(key, value)?
+            }
+            "epochs.reionization_redshift" => {
+                self.epochs.reionization_redshift = parse_value(key, value)?
+            }
+            _ => {
+                return Err(ConfigError::UnknownKey {
+                    key: key.to_string(),
+                    location: None,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies a `key=value` override such as `particle.initial_count=500000`
+    ///
+    /// This is the format accepted by the `--set` command-line option.
+    /// See [`Config::set_value`] for the supported keys.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the override was applied
+    /// * `Err(ConfigError)` if the assignment is malformed or the key/value is invalid
+    pub fn apply_override(&mut self, assignment: &str) -> Result<(), ConfigError> {
+        let (key, value) =
+            assignment
+                .split_once('=')
+                .ok_or_else(|| ConfigError::MalformedOverride {
+                    assignment: assignment.to_string(),
+                })?;
+        self.set_value(key.trim(), value)
+    }
+
+    /// Loads configuration from an explicit TOML file path
+    ///
+    /// Unlike [`Config::load`], this does not search other locations and does not
+    /// fall back to defaults: a missing or unparsable file, or an unknown key, is
+    /// reported as an error. Keys absent from the file take their default values.
+    /// The result is not validated; call [`Config::validate`] afterwards.
+    ///
+    /// # Returns
+    /// * `Ok(Config)` with the parsed configuration
+    /// * `Err(ConfigError)` if the file is missing, cannot be parsed, or contains
+    ///   unknown keys or invalid values (with line and column)
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        ConfigLoader::new()
+            .required_file(ConfigLayer::Local, path.as_ref())
+            .load()
+            .map(|layered| layered.config)
+    }
+
+    /// Loads configuration by merging every standard layer
+    ///
+    /// Values are merged key by key, with later layers overriding earlier ones:
+    /// 1. `/etc/genesis/config.toml` - System-wide configuration
+    /// 2. `~/.config/genesis/config.toml` - User-specific configuration
+    /// 3. `./genesis.toml` - Workspace-local configuration
+    /// 4. `GENESIS_*` environment variables (e.g. `GENESIS_PARTICLE_INITIAL_COUNT`)
+    ///
+    /// Missing files are skipped. If any layer fails to parse, or the merged
+    /// configuration fails validation, a warning is printed and default values
+    /// are returned. Use [`Config::try_load`] to receive these errors instead.
+    #[must_use]
+    pub fn load() -> Self {
+        match Self::try_load() {
+            Ok(config) => config,
+            Err(error) => {
+                eprintln!("Warning: {}", error);
+                eprintln!("Falling back to default configuration.");
+                Self::default()
+            }
+        }
+    }
+
+    /// Loads and validates configuration from every standard layer
+    ///
+    /// Merges the same layers as [`Config::load`], but returns any failure rather
+    /// than falling back to defaults. Validation errors carry the file, line and
+    /// column (or environment variable) that supplied the offending value. Use
+    /// [`ConfigLoader`] directly to see which layer supplied each value.
+    ///
+    /// # Returns
+    /// * `Ok(Config)` with the merged, validated configuration
+    /// * `Err(ConfigError)` with every read, parse and validation failure
+    pub fn try_load() -> Result<Self, ConfigError> {
+        let layered = ConfigLoader::standard().load()?;
+        layered.validate()?;
+        Ok(layered.config)
+    }
+}
+
+/// Parses a raw override value into the type of the target configuration field
+fn parse_value<T>(key: &str, value: &str) -> Result<T, ConfigError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.parse::<T>().map_err(|e| ConfigError::InvalidValue {
+        key: key.to_string(),
+        value: value.to_string(),
+        reason: e.to_string(),
+        location: None,
+    })
+}
+
+/// Strips a single pair of surrounding double quotes from a string value
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeconfig_validate_valid() {
+        let config = TimeConfig {
+            time_acceleration_min: 1.0,
+            time_acceleration_max: 1e12,
+            playback_speed: 1.0,
+            epoch_transition_decades: 0.5,
+            mode: TimeMode::Linear,
+            decades_per_second: 1.0,
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_timeconfig_validate_min_zero() {
+        let config = TimeConfig {
+            time_acceleration_min: 0.0,
+            time_acceleration_max: 1e12,
+            playback_speed: 1.0,
+            epoch_transition_decades: 0.5,
+            mode: TimeMode::Linear,
+            decades_per_second: 1.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be positive"));
+    }
+
+    #[test]
+    fn test_timeconfig_validate_max_zero() {
+        let config = TimeConfig {
+            time_acceleration_min: 1.0,
+            time_acceleration_max: 0.0,
+            playback_speed: 1.0,
+            epoch_transition_decades: 0.5,
+            mode: TimeMode::Linear,
+            decades_per_second: 1.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be positive"));
+    }
+
+    #[test]
+    fn test_timeconfig_validate_min_greater_than_max() {
+        let config = TimeConfig {
+            time_acceleration_min: 1000.0,
+            time_acceleration_max: 100.0,
+            playback_speed: 1.0,
+            epoch_transition_decades: 0.5,
+            mode: TimeMode::Linear,
+            decades_per_second: 1.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be less than"));
+    }
+
+    #[test]
+    fn test_timeconfig_validate_min_equals_max() {
+        let config = TimeConfig {
+            time_acceleration_min: 100.0,
+            time_acceleration_max: 100.0,
+            playback_speed: 1.0,
+            epoch_transition_decades: 0.5,
+            mode: TimeMode::Linear,
+            decades_per_second: 1.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be less than"));
+    }
+
+    #[test]
+    fn test_timeconfig_validate_negative_min() {
+        let config = TimeConfig {
+            time_acceleration_min: -1.0,
+            time_acceleration_max: 1e12,
+            playback_speed: 1.0,
+            epoch_transition_decades: 0.5,
+            mode: TimeMode::Linear,
+            decades_per_second: 1.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be positive"));
+    }
+
+    #[test]
+    fn test_timeconfig_validate_negative_max() {
+        let config = TimeConfig {
+            time_acceleration_min: 1.0,
+            time_acceleration_max: -1.0,
+            playback_speed: 1.0,
+            epoch_transition_decades: 0.5,
+            mode: TimeMode::Linear,
+            decades_per_second: 1.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be positive"));
+    }
+
+    #[test]
+    fn test_timeconfig_validate_playback_speed_out_of_range() {
+        let config = TimeConfig {
+            playback_speed: 1e13,
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err();
+        assert_eq!(error.field(), Some("time.playback_speed"));
+        assert!(error.to_string().contains("must be between"));
+    }
+
+    #[test]
+    fn test_timeconfig_validate_negative_transition_window() {
+        let config = TimeConfig {
+            epoch_transition_decades: -1.0,
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err();
+        assert_eq!(error.field(), Some("time.epoch_transition_decades"));
+    }
+
+    #[test]
+    fn test_physicsconfig_validate_timestep() {
+        let config = PhysicsConfig {
+            timestep_hz: 0.0,
+            substeps: 0,
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err();
+        assert!(matches!(error, ConfigError::Multiple(ref errors) if errors.len() == 2));
+        assert!(PhysicsConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_time_mode() {
+        let mut config = Config::default();
+        config.set_value("time.mode", "\"logarithmic\"").unwrap();
+        assert_eq!(config.time.mode, TimeMode::Logarithmic);
+        assert_eq!(config.get_value("time.mode").as_deref(), Some("logarithmic"));
+
+        let error = config.set_value("time.mode", "sideways").unwrap_err();
+        assert!(matches!(error, ConfigError::InvalidValue { .. }));
+    }
+
+    #[test]
+    fn test_timeconfig_default_is_valid() {
+        let config = TimeConfig::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_particleconfig_validate_valid() {
+        let config = ParticleConfig {
+            initial_count: 100_000,
+            max_count: 1_000_000,
+            base_size: 2.0,
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_particleconfig_validate_initial_count_zero() {
+        let config = ParticleConfig {
+            initial_count: 0,
+            max_count: 1_000_000,
+            base_size: 2.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("initial_count must be positive"));
+    }
+
+    #[test]
+    fn test_particleconfig_validate_max_count_zero() {
+        let config = ParticleConfig {
+            initial_count: 100_000,
+            max_count: 0,
+            base_size: 2.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("max_count must be positive"));
+    }
+
+    #[test]
+    fn test_particleconfig_validate_initial_exceeds_max() {
+        let config = ParticleConfig {
+            initial_count: 1_000_000,
+            max_count: 100_000,
+            base_size: 2.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("cannot exceed max_count"));
+    }
+
+    #[test]
+    fn test_particleconfig_validate_base_size_zero() {
+        let config = ParticleConfig {
+            initial_count: 100_000,
+            max_count: 1_000_000,
+            base_size: 0.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("base_size must be positive"));
+    }
+
+    #[test]
+    fn test_particleconfig_validate_base_size_negative() {
+        let config = ParticleConfig {
+            initial_count: 100_000,
+            max_count: 1_000_000,
+            base_size: -1.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("base_size must be positive"));
+    }
+
+    #[test]
+    fn test_particleconfig_default_is_valid() {
+        let config = ParticleConfig::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_cameraconfig_validate_valid_free() {
+        let config = CameraConfig {
+            initial_mode: "free".to_string(),
+            orbit_distance: 100.0,
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_cameraconfig_validate_valid_orbit() {
+        let config = CameraConfig {
+            initial_mode: "orbit".to_string(),
+            orbit_distance: 100.0,
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_cameraconfig_validate_invalid_mode() {
+        let config = CameraConfig {
+            initial_mode: "invalid".to_string(),
+            orbit_distance: 100.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("initial_mode must be \"free\" or \"orbit\""));
+    }
+
+    #[test]
+    fn test_cameraconfig_validate_orbit_distance_zero() {
+        let config = CameraConfig {
+            initial_mode: "orbit".to_string(),
+            orbit_distance: 0.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("orbit_distance must be positive"));
+    }
+
+    #[test]
+    fn test_cameraconfig_validate_orbit_distance_negative() {
+        let config = CameraConfig {
+            initial_mode: "orbit".to_string(),
+            orbit_distance: -50.0,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("orbit_distance must be positive"));
+    }
+
+    #[test]
+    fn test_cameraconfig_default_is_valid() {
+        let config = CameraConfig::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_windowconfig_validate_valid() {
+        let config = WindowConfig {
+            width: 1920,
+            height: 1080,
+            title: "Test Window".to_string(),
+            vsync: true,
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_windowconfig_validate_width_zero() {
+        let config = WindowConfig {
+            width: 0,
+            height: 1080,
+            title: "Test Window".to_string(),
+            vsync: true,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("width must be positive"));
+    }
+
+    #[test]
+    fn test_windowconfig_validate_width_exceeds_max() {
+        let config = WindowConfig {
+            width: 4000,
+            height: 1080,
+            title: "Test Window".to_string(),
+            vsync: true,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("width (4000) exceeds maximum of 3840"));
+    }
+
+    #[test]
+    fn test_windowconfig_validate_height_zero() {
+        let config = WindowConfig {
+            width: 1920,
+            height: 0,
+            title: "Test Window".to_string(),
+            vsync: true,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("height must be positive"));
+    }
+
+    #[test]
+    fn test_windowconfig_validate_height_exceeds_max() {
+        let config = WindowConfig {
+            width: 1920,
+            height: 3000,
+            title: "Test Window".to_string(),
+            vsync: true,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("height (3000) exceeds maximum of 2160"));
+    }
+
+    #[test]
+    fn test_windowconfig_validate_empty_title() {
+        let config = WindowConfig {
+            width: 1920,
+            height: 1080,
+            title: "".to_string(),
+            vsync: true,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("title cannot be empty"));
+    }
+
+    #[test]
+    fn test_windowconfig_validate_title_exceeds_max_length() {
+        let long_title = "a".repeat(201);
+        let config = WindowConfig {
+            width: 1920,
+            height: 1080,
+            title: long_title,
+            vsync: true,
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("title length (201) exceeds maximum of 200"));
+    }
+
+    #[test]
+    fn test_windowconfig_default_is_valid() {
+        let config = WindowConfig::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_displayconfig_validate_valid() {
+        let config = DisplayConfig {
+            show_fps: true,
+            show_particle_count: true,
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_displayconfig_validate_all_false() {
+        let config = DisplayConfig {
+            show_fps: false,
+            show_particle_count: false,
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_displayconfig_default_is_valid() {
+        let config = DisplayConfig::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_validate_valid() {
+        let config = Config::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_validate_invalid_timeconfig_min_zero() {
+        let config = Config {
+            time: TimeConfig {
+                time_acceleration_min: 0.0,
+                time_acceleration_max: 1e12,
+                playback_speed: 1.0,
+                epoch_transition_decades: 0.5,
+                mode: TimeMode::Linear,
+                decades_per_second: 1.0,
+            },
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().field(), Some("time.time_acceleration_min"));
+    }
+
+    #[test]
+    fn test_config_validate_invalid_particleconfig_initial_count_zero() {
+        let config = Config {
+            particle: ParticleConfig {
+                initial_count: 0,
+                max_count: 1_000_000,
+                base_size: 2.0,
+            },
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().field(), Some("particle.initial_count"));
+    }
+
+    #[test]
+    fn test_config_validate_invalid_cameraconfig_mode() {
+        let config = Config {
+            camera: CameraConfig {
+                initial_mode: "invalid".to_string(),
+                orbit_distance: 100.0,
+            },
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().field(), Some("camera.initial_mode"));
+    }
+
+    #[test]
+    fn test_config_validate_invalid_windowconfig_width_zero() {
+        let config = Config {
+            window: WindowConfig {
+                width: 0,
+                height: 1080,
+                title: "Test Window".to_string(),
+                vsync: true,
+            },
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().field(), Some("window.width"));
+    }
+
+    #[test]
+    fn test_config_validate_collects_all_failures() {
+        let config = Config {
+            particle: ParticleConfig {
+                initial_count: 0,
+                max_count: 1_000_000,
+                base_size: -1.0,
+            },
+            window: WindowConfig {
+                width: 4000,
+                height: 1080,
+                title: String::new(),
+                vsync: true,
+            },
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err();
+        let fields: Vec<_> = error.errors().iter().filter_map(|e| e.field()).collect();
+
+        assert_eq!(
+            fields,
+            vec![
+                "particle.initial_count",
+                "particle.base_size",
+                "window.width",
+                "window.title"
+            ]
+        );
+        assert!(error.to_string().starts_with("4 configuration errors:"));
+    }
+
+    #[test]
+    fn test_config_keys_round_trip_through_get_and_set() {
+        let source = Config::default();
+        let mut target = Config::default();
+        target.physics.seed = Some(9);
+
+        for key in Config::KEYS {
+            let value = source.get_value(key).expect("every key should be readable");
+            target
+                .set_value(key, &value)
+                .unwrap_or_else(|e| panic!("{} should accept its own value: {}", key, e));
+        }
+
+        assert_eq!(target.physics.seed, None);
+        assert_eq!(target.get_value("window.title"), source.get_value("window.title"));
+        assert_eq!(target.get_value("unknown.key"), None);
+    }
+
+    #[test]
+    fn test_config_apply_override_sets_typed_values() {
+        let mut config = Config::default();
+        config.apply_override("particle.initial_count=500000").unwrap();
+        config.apply_override("camera.initial_mode=free").unwrap();
+        config.apply_override("window.title=\"Demo Run\"").unwrap();
+        config.apply_override("window.vsync = false").unwrap();
+        config.apply_override("physics.seed=42").unwrap();
+
+        assert_eq!(config.particle.initial_count, 500_000);
+        assert_eq!(config.camera.initial_mode, "free");
+        assert_eq!(config.window.title, "Demo Run");
+        assert!(!config.window.vsync);
+        assert_eq!(config.physics.seed, Some(42));
+    }
+
+    #[test]
+    fn test_config_apply_override_unknown_key() {
+        let mut config = Config::default();
+        let result = config.apply_override("particle.count=10");
+        assert!(result.unwrap_err().to_string().contains("Unknown configuration key 'particle.count'"));
+    }
+
+    #[test]
+    fn test_config_apply_override_invalid_value() {
+        let mut config = Config::default();
+        let result = config.apply_override("particle.initial_count=lots");
+        assert!(result.unwrap_err().to_string().contains("particle.initial_count"));
+        assert_eq!(config.particle.initial_count, 100_000);
+    }
+
+    #[test]
+    fn test_config_apply_override_missing_equals() {
+        let mut config = Config::default();
+        let result = config.apply_override("particle.initial_count");
+        let error = result.unwrap_err();
+        assert!(matches!(error, ConfigError::MalformedOverride { .. }));
+        assert!(error.to_string().contains("expected the form"));
+    }
+
+    #[test]
+    fn test_config_load_from_missing_file() {
+        let result = Config::load_from("does_not_exist_genesis.toml");
+        assert!(result.unwrap_err().to_string().contains("does not exist"));
+    }
+
+    #[test]
+    fn test_config_load_from_partial_file() {
+        use std::fs;
+
+        let test_path = std::env::temp_dir().join("genesis_load_from_partial.toml");
+        fs::write(&test_path, "[camera]\ninitial_mode = \"free\"\norbit_distance = 25.0\n")
+            .expect("Failed to write test config file");
+
+        let config = Config::load_from(&test_path).expect("partial config should parse");
+        let _ = fs::remove_file(&test_path);
+
+        assert_eq!(config.camera.initial_mode, "free");
+        assert_eq!(config.camera.orbit_distance, 25.0);
+        assert_eq!(config.particle.initial_count, 100_000);
+    }
+
+    #[test]
+    fn test_config_load_with_invalid_config_file() {
+        use std::fs;
+        use std::path::PathBuf;
+
+        // Create a temporary config file with invalid camera mode
+        let test_path = PathBuf::from("test_genesis_invalid.toml");
+        let test_content = r#"
+[window]
+width = 1280
+height = 720
+title = "Test Window"
+vsync = true
+
+[particle]
+initial_count = 100000
+max_count = 1000000
+base_size = 2.0
+
+[camera]
+initial_mode = "invalid_mode"
+orbit_distance = 100.0
+
+[time]
+time_acceleration_min = 1.0
+time_acceleration_max = 1000000000000.0
+
+[display]
+show_fps = true
+show_particle_count = true
+"#;
+        fs::write(&test_path, test_content).expect("Failed to write test config file");
+
+        // Temporarily move genesis.toml out of the way if it exists
+        let backup_path = PathBuf::from("genesis.toml.bak");
+        if PathBuf::from("genesis.toml").exists() {
+            fs::rename("genesis.toml", &backup_path).expect("Failed to backup genesis.toml");
+        }
+
+        // Create a symbolic link from genesis.toml to the test file
+        #[cfg(unix)]
+        std::os::unix::fs::symlink("test_genesis_invalid.toml", "genesis.toml")
+            .expect("Failed to create symlink");
+
+        #[cfg(windows)]
+        std::os::windows::fs::symlink_file("test_genesis_invalid.toml", "genesis.toml")
+            .expect("Failed to create symlink");
+
+        // Load the config - it should fail validation and return defaults
+        let config = Config::load();
+
+        // Verify we got default values
+        assert_eq!(config.camera.initial_mode, "orbit");
+        assert_eq!(config.camera.orbit_distance, 100.0);
+
+        // Clean up
+        let _ = fs::remove_file("genesis.toml");
+        let _ = fs::remove_file(&test_path);
+
+        // Restore original genesis.toml if it existed
+        if backup_path.exists() {
+            let _ = fs::rename(&backup_path, "genesis.toml");
+        }
+    }
+}
@@ -0,0 +1,323 @@
+//! Writing configuration back to TOML
+//!
+//! [`Config::to_toml_string`] renders the effective configuration in `genesis.toml`
+//! layout, stamped with the current `schema_version`, and [`Config::save`] writes it
+//! to disk. When the target file already exists, values are updated in place so that
+//! its comments and key order survive the save; keys the configuration no longer sets
+//! (such as an unset `physics.seed`) are removed. Loading a saved file yields a
+//! configuration equal to the one saved.
+//!
+//! [`LayeredConfig::save_local`] writes only the keys that belong in the local file,
+//! so saving at runtime does not bake values from the system or user files, the
+//! environment or the command line into `genesis.toml`.
+
+use std::fs;
+use std::path::Path;
+
+use toml_edit::{DocumentMut, Item, TableLike, Value};
+
+use super::migrate::{self, SCHEMA_VERSION, SCHEMA_VERSION_KEY};
+use super::{Config, ConfigError, ConfigLayer, LayeredConfig};
+
+impl Config {
+    /// Renders the configuration as a `genesis.toml` document
+    ///
+    /// # Returns
+    /// * `Ok(String)` with the schema version followed by one table per section
+    /// * `Err(ConfigError::Serialize)` if a value cannot be represented in TOML
+    pub fn to_toml_string(&self) -> Result<String, ConfigError> {
+        let body = toml::to_string(self).map_err(|e| ConfigError::Serialize {
+            message: e.to_string(),
+        })?;
+        Ok(format!("{} = {}\n\n{}", SCHEMA_VERSION_KEY, SCHEMA_VERSION, body))
+    }
+
+    /// Writes the configuration to a TOML file
+    ///
+    /// If `path` already contains a parsable TOML document, its values are updated
+    /// in place, keeping comments and layout; otherwise the file is (re)written from
+    /// scratch. The configuration is not validated before saving.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the file was written
+    /// * `Err(ConfigError)` if serialization or writing fails
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        let path = path.as_ref();
+        let rendered = self.to_toml_string()?;
+        let content = fs::read_to_string(path)
+            .ok()
+            .and_then(|existing| update_document(&existing, &rendered))
+            .unwrap_or(rendered);
+
+        fs::write(path, content).map_err(|e| ConfigError::Write {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Writes only the given keys of the configuration to a TOML file
+    ///
+    /// Other keys in an existing file, and its comments and layout, are kept as they
+    /// are; a file written with an older `schema_version` is migrated first. A listed
+    /// key the configuration leaves unset (such as an unset `physics.seed`) is removed.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the file was written
+    /// * `Err(ConfigError)` if serialization or writing fails, or the existing file
+    ///   was written by a newer version
+    pub fn save_keys<'a>(
+        &self,
+        path: impl AsRef<Path>,
+        keys: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), ConfigError> {
+        let path = path.as_ref();
+        let fresh: DocumentMut =
+            self.to_toml_string()?
+                .parse()
+                .map_err(|e: toml_edit::TomlError| ConfigError::Serialize {
+                    message: e.to_string(),
+                })?;
+
+        let mut document = match fs::read_to_string(path)
+            .ok()
+            .and_then(|existing| existing.parse::<DocumentMut>().ok())
+        {
+            Some(mut document) => {
+                let origin = path.display().to_string();
+                let version = document
+                    .get(SCHEMA_VERSION_KEY)
+                    .and_then(Item::as_integer)
+                    .and_then(|v| u32::try_from(v).ok())
+                    .unwrap_or(0);
+                migrate::check_version(version, &origin)?;
+                migrate::apply_migrations(&mut document, version, &origin);
+                document
+            }
+            None => DocumentMut::new(),
+        };
+        document[SCHEMA_VERSION_KEY] = toml_edit::value(i64::from(SCHEMA_VERSION));
+
+        for key in keys {
+            let Some((section, field)) = key.split_once('.') else {
+                continue;
+            };
+            let value = fresh
+                .get(section)
+                .and_then(|table| table.get(field))
+                .and_then(Item::as_value);
+            let target = document
+                .entry(section)
+                .or_insert_with(toml_edit::table)
+                .as_table_like_mut()
+                .ok_or_else(|| ConfigError::Write {
+                    path: path.to_path_buf(),
+                    message: format!("[{}] is not a table", section),
+                })?;
+            match value {
+                Some(value) => replace_value(target, field, value),
+                None => {
+                    target.remove(field);
+                }
+            }
+        }
+
+        fs::write(path, document.to_string()).map_err(|e| ConfigError::Write {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+}
+
+impl LayeredConfig {
+    /// Writes the keys of `current` that belong in the local file
+    ///
+    /// `current` is this configuration as changed while running. A key is written if
+    /// its loaded value came from the local file or the built-in defaults, or if it
+    /// was changed since loading. Values supplied only by the system or user files,
+    /// the environment or the command line are left out (see [`Config::save_keys`]).
+    ///
+    /// # Returns
+    /// * `Ok(())` if the file was written
+    /// * `Err(ConfigError)` if serialization or writing fails
+    pub fn save_local(&self, current: &Config, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        let keys = Config::KEYS.iter().copied().filter(|key| {
+            let local = self.sources.get(key).is_none_or(|source| {
+                matches!(source.layer, ConfigLayer::Default | ConfigLayer::Local)
+            });
+            local || current.get_value(key) != self.config.get_value(key)
+        });
+        current.save_keys(path, keys)
+    }
+}
+
+/// Replaces a value in a table, keeping the comments around an existing value
+fn replace_value(table: &mut dyn TableLike, key: &str, value: &Value) {
+    match table.get_mut(key).and_then(Item::as_value_mut) {
+        Some(slot) => {
+            let decor = slot.decor().clone();
+            *slot = value.clone();
+            *slot.decor_mut() = decor;
+        }
+        None => {
+            table.insert(key, Item::Value(value.clone()));
+        }
+    }
+}
+
+/// Updates the values of an existing TOML document from freshly rendered TOML
+///
+/// Returns `None` if the existing document cannot be parsed or uses a layout
+/// (such as inline section tables) that cannot be updated in place.
+fn update_document(existing: &str, rendered: &str) -> Option<String> {
+    let mut document: DocumentMut = existing.parse().ok()?;
+    let fresh: DocumentMut = rendered.parse().ok()?;
+
+    for (section, item) in fresh.iter() {
+        if let Some(value) = item.as_value() {
+            document[section] = Item::Value(value.clone());
+            continue;
+        }
+        let values = item.as_table()?;
+        let target = document
+            .entry(section)
+            .or_insert_with(toml_edit::table)
+            .as_table_mut()?;
+
+        let stale: Vec<String> = target
+            .iter()
+            .map(|(key, _)| key.to_string())
+            .filter(|key| !values.contains_key(key))
+            .collect();
+        for key in stale {
+            target.remove(&key);
+        }
+
+        for (key, value) in values.iter() {
+            replace_value(target, key, value.as_value()?);
+        }
+    }
+
+    Some(document.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(name)
+    }
+
+    #[test]
+    fn test_to_toml_string_has_every_section() {
+        let rendered = Config::default().to_toml_string().unwrap();
+        for section in [
+            "[time]",
+            "[particle]",
+            "[camera]",
+            "[window]",
+            "[display]",
+            "[physics]",
+            "[cosmology]",
+            "[epochs]",
+        ] {
+            assert!(rendered.contains(section), "missing {} in:\n{}", section, rendered);
+        }
+        assert!(!rendered.contains("seed"), "an unset seed should be omitted");
+        assert!(rendered.starts_with(&format!("schema_version = {}\n", SCHEMA_VERSION)));
+    }
+
+    #[test]
+    fn test_save_round_trips_losslessly() {
+        let path = temp_path("genesis_save_round_trip.toml");
+        let mut config = Config::default();
+        config.camera.orbit_distance = 37.25;
+        config.camera.initial_mode = "free".to_string();
+        config.time.playback_speed = 1e6;
+        config.particle.base_size = 0.1;
+        config.window.title = "Genesis \"demo\" run".to_string();
+        config.physics.seed = Some(42);
+        config.cosmology.apply_preset("high_baryon").unwrap();
+        config.cosmology.omega_b = 0.12;
+
+        config.save(&path).unwrap();
+        let loaded = Config::load_from(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded.unwrap(), config);
+    }
+
+    #[test]
+    fn test_save_preserves_comments_and_updates_values() {
+        let path = temp_path("genesis_save_comments.toml");
+        fs::write(
+            &path,
+            "# Shared demo preset\n[camera]\n# How far out to start\norbit_distance = 100.0 # units\ninitial_mode = \"orbit\"\n\n[physics]\nspectral_index = 0.96\nseed = 7\n",
+        )
+        .unwrap();
+
+        let mut config = Config::load_from(&path).unwrap();
+        config.camera.orbit_distance = 250.0;
+        config.physics.seed = None;
+        config.save(&path).unwrap();
+
+        let saved = fs::read_to_string(&path).unwrap();
+        let reloaded = Config::load_from(&path);
+        let _ = fs::remove_file(&path);
+
+        let header = format!(
+            "schema_version = {}\n# Shared demo preset\n[camera]\n# How far out to start\n",
+            SCHEMA_VERSION
+        );
+        assert!(saved.starts_with(&header), "got:\n{}", saved);
+        assert!(saved.contains("orbit_distance = 250.0 # units"), "got:\n{}", saved);
+        assert!(!saved.contains("seed"), "unset seed should be removed:\n{}", saved);
+        assert!(saved.contains("[window]"), "missing sections should be added:\n{}", saved);
+        assert!(saved.contains("n_s = 0.96"), "spectral_index should migrate to n_s:\n{}", saved);
+        assert_eq!(reloaded.unwrap(), config);
+    }
+
+    #[test]
+    fn test_save_local_leaves_out_values_from_other_layers() {
+        use crate::config::ConfigLoader;
+
+        let local = temp_path("genesis_save_local.toml");
+        let user = temp_path("genesis_save_local_user.toml");
+        fs::write(&local, "[camera]\n# Start far out\norbit_distance = 100.0\n").unwrap();
+        fs::write(&user, "[window]\ntitle = \"Shared\"\n").unwrap();
+
+        let layered = ConfigLoader::new()
+            .file(ConfigLayer::User, &user)
+            .file(ConfigLayer::Local, &local)
+            .env([("GENESIS_TIME_PLAYBACK_SPEED", "5000"), ("GENESIS_PARTICLE_BASE_SIZE", "0.5")])
+            .overrides(["particle.initial_count=123"])
+            .load()
+            .unwrap();
+        let mut current = layered.config.clone();
+        current.camera.orbit_distance = 250.0;
+        current.time.playback_speed = 1e6;
+
+        layered.save_local(&current, &local).unwrap();
+        let saved = fs::read_to_string(&local).unwrap();
+        let reloaded = Config::load_from(&local).unwrap();
+        let _ = fs::remove_file(&local);
+        let _ = fs::remove_file(&user);
+
+        assert!(saved.contains("# Start far out\norbit_distance = 250.0"), "got:\n{}", saved);
+        assert!(!saved.contains("Shared"), "user file value was saved:\n{}", saved);
+        assert!(!saved.contains("initial_count"), "--set value was saved:\n{}", saved);
+        assert!(!saved.contains("base_size"), "environment value was saved:\n{}", saved);
+        assert_eq!(reloaded.time.playback_speed, 1e6, "runtime change should be saved");
+        assert_eq!(reloaded.camera.orbit_distance, 250.0);
+        assert_eq!(reloaded.window.title, Config::default().window.title);
+        assert_eq!(reloaded.particle.initial_count, Config::default().particle.initial_count);
+        assert!(saved.contains("timestep_hz"), "default values should be saved:\n{}", saved);
+    }
+
+    #[test]
+    fn test_save_to_missing_directory_fails() {
+        let path = temp_path("genesis_missing_dir").join("nested").join("genesis.toml");
+        let error = Config::default().save(&path).unwrap_err();
+        assert!(matches!(error, ConfigError::Write { .. }));
+    }
+}
@@ -0,0 +1,556 @@
+//! Epoch registry and activation
+//!
+//! [`EpochManager`] keeps the registered epochs ordered by start time and tracks
+//! which one contains the current cosmic time. Exactly one epoch is active at a
+//! time (none if the clock lies outside every registered range); only the systems
+//! of the active epoch run. Changes of the active epoch are announced with
+//! [`EpochExited`] and [`EpochEntered`] events.
+
+use std::fmt;
+
+use bevy::prelude::{
+    App, EventWriter, FixedUpdate, IntoSystemSetConfigs, Plugin, PostUpdate, PreUpdate, Res,
+    ResMut, Resource, Update,
+};
+
+use super::plugin::{in_epoch, CameraPreset, EpochPanel, EpochPlugin, EpochSystems};
+use super::EpochTransition;
+use crate::events::{EpochEntered, EpochExited};
+use crate::time::SimulationClock;
+
+/// What the manager records about a registered epoch
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpochInfo {
+    /// Unique display name
+    pub name: &'static str,
+    /// Start of the epoch in cosmic years (inclusive)
+    pub start_year: f64,
+    /// End of the epoch in cosmic years (exclusive)
+    pub end_year: f64,
+    /// Camera placement suggested for the epoch
+    pub camera_preset: Option<CameraPreset>,
+    /// UI panels shown while the epoch is active
+    pub ui_panels: Vec<EpochPanel>,
+}
+
+impl EpochInfo {
+    /// Captures the declaration of an epoch plugin
+    pub fn from_plugin(epoch: &dyn EpochPlugin) -> Self {
+        Self {
+            name: epoch.name(),
+            start_year: epoch.start_year(),
+            end_year: epoch.end_year(),
+            camera_preset: epoch.camera_preset(),
+            ui_panels: epoch.ui_panels(),
+        }
+    }
+
+    /// Returns true if `years` lies within `[start_year, end_year)`
+    pub fn contains(&self, years: f64) -> bool {
+        years >= self.start_year && years < self.end_year
+    }
+}
+
+/// Error raised when an epoch cannot be registered
+#[derive(Debug, Clone, PartialEq)]
+pub enum EpochError {
+    /// Another epoch with the same name is already registered
+    DuplicateName {
+        /// Name of the epoch
+        name: &'static str,
+    },
+    /// The epoch ends before it starts, or its bounds are not numbers
+    InvalidRange {
+        /// Name of the epoch
+        name: &'static str,
+        /// Declared start in years
+        start_year: f64,
+        /// Declared end in years
+        end_year: f64,
+    },
+    /// The epoch's time range overlaps a registered epoch
+    Overlap {
+        /// Name of the epoch being registered
+        name: &'static str,
+        /// Name of the registered epoch it overlaps
+        other: &'static str,
+    },
+    /// No epoch with this name is registered
+    UnknownEpoch {
+        /// Name that was looked up
+        name: String,
+    },
+}
+
+impl fmt::Display for EpochError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EpochError::DuplicateName { name } => {
+                write!(f, "epoch '{}' is already registered", name)
+            }
+            EpochError::InvalidRange {
+                name,
+                start_year,
+                end_year,
+            } => write!(
+                f,
+                "epoch '{}' has an invalid time range [{:e}, {:e}) years",
+                name, start_year, end_year
+            ),
+            EpochError::Overlap { name, other } => {
+                write!(f, "epoch '{}' overlaps the time range of epoch '{}'", name, other)
+            }
+            EpochError::UnknownEpoch { name } => write!(f, "no epoch named '{}' is registered", name),
+        }
+    }
+}
+
+impl std::error::Error for EpochError {}
+
+/// Registry of epochs and the currently active one
+#[derive(Resource, Debug, Clone, Default)]
+pub struct EpochManager {
+    /// Registered epochs ordered by start time
+    epochs: Vec<EpochInfo>,
+    /// Index of the active epoch in `epochs`
+    active: Option<usize>,
+}
+
+impl EpochManager {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an epoch, keeping the registry ordered by start time
+    ///
+    /// # Returns
+    /// * `Ok(())` if the epoch was added
+    /// * `Err(EpochError)` if its name is taken, its range is invalid, or it overlaps
+    ///   a registered epoch
+    pub fn register(&mut self, info: EpochInfo) -> Result<(), EpochError> {
+        if self.epochs.iter().any(|epoch| epoch.name == info.name) {
+            return Err(EpochError::DuplicateName { name: info.name });
+        }
+        if info.start_year.is_nan() || info.end_year.is_nan() || info.start_year >= info.end_year {
+            return Err(EpochError::InvalidRange {
+                name: info.name,
+                start_year: info.start_year,
+                end_year: info.end_year,
+            });
+        }
+        if let Some(other) = self
+            .epochs
+            .iter()
+            .find(|epoch| info.start_year < epoch.end_year && epoch.start_year < info.end_year)
+        {
+            return Err(EpochError::Overlap {
+                name: info.name,
+                other: other.name,
+            });
+        }
+
+        let active = self.active().map(|epoch| epoch.name);
+        let index = self.epochs.partition_point(|epoch| epoch.start_year < info.start_year);
+        self.epochs.insert(index, info);
+        self.active = active.and_then(|name| self.position(name));
+        Ok(())
+    }
+
+    /// Moves registered epochs to new time ranges
+    ///
+    /// All ranges are replaced together, so contiguous epochs can shift their shared
+    /// boundaries without overlapping in between. The active epoch is kept; the next
+    /// [`update`](Self::update) activates the epoch containing the current time.
+    ///
+    /// # Arguments
+    /// * `ranges` - `(name, start_year, end_year)` of every epoch to move
+    ///
+    /// # Returns
+    /// * `Ok(())` if the epochs were moved
+    /// * `Err(EpochError)` if a name is not registered, a range is invalid, or the new
+    ///   ranges overlap; the registry is then left unchanged
+    pub fn reschedule(&mut self, ranges: &[(&str, f64, f64)]) -> Result<(), EpochError> {
+        let mut epochs = self.epochs.clone();
+        for &(name, start_year, end_year) in ranges {
+            let epoch = epochs
+                .iter_mut()
+                .find(|epoch| epoch.name == name)
+                .ok_or_else(|| EpochError::UnknownEpoch { name: name.to_string() })?;
+            if start_year.is_nan() || end_year.is_nan() || start_year >= end_year {
+                return Err(EpochError::InvalidRange {
+                    name: epoch.name,
+                    start_year,
+                    end_year,
+                });
+            }
+            epoch.start_year = start_year;
+            epoch.end_year = end_year;
+        }
+
+        epochs.sort_by(|a, b| a.start_year.total_cmp(&b.start_year));
+        if let Some(pair) = epochs.windows(2).find(|pair| pair[1].start_year < pair[0].end_year) {
+            return Err(EpochError::Overlap {
+                name: pair[1].name,
+                other: pair[0].name,
+            });
+        }
+
+        let active = self.active().map(|epoch| epoch.name);
+        self.epochs = epochs;
+        self.active = active.and_then(|name| self.position(name));
+        Ok(())
+    }
+
+    /// Registered epochs ordered by start time
+    pub fn epochs(&self) -> &[EpochInfo] {
+        &self.epochs
+    }
+
+    /// Looks up a registered epoch by name
+    pub fn get(&self, name: &str) -> Option<&EpochInfo> {
+        self.position(name).map(|index| &self.epochs[index])
+    }
+
+    /// Returns the epoch whose time range contains `years`, if any
+    pub fn epoch_at(&self, years: f64) -> Option<&EpochInfo> {
+        self.epochs.iter().find(|epoch| epoch.contains(years))
+    }
+
+    /// The currently active epoch
+    pub fn active(&self) -> Option<&EpochInfo> {
+        self.active.map(|index| &self.epochs[index])
+    }
+
+    /// Returns true if the named epoch is the active one
+    pub fn is_active(&self, name: &str) -> bool {
+        self.active().is_some_and(|epoch| epoch.name == name)
+    }
+
+    /// The registered epoch immediately before the active one
+    pub fn previous(&self) -> Option<&EpochInfo> {
+        let index = self.active?.checked_sub(1)?;
+        self.epochs.get(index)
+    }
+
+    /// The registered epoch immediately after the active one
+    pub fn next(&self) -> Option<&EpochInfo> {
+        self.epochs.get(self.active? + 1)
+    }
+
+    /// Activates the epoch containing `years`
+    ///
+    /// # Returns
+    /// `true` if the active epoch changed
+    pub fn update(&mut self, years: f64) -> bool {
+        let active = self.epochs.iter().position(|epoch| epoch.contains(years));
+        let changed = active != self.active;
+        self.active = active;
+        changed
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.epochs.iter().position(|epoch| epoch.name == name)
+    }
+}
+
+/// Plugin that owns the [`EpochManager`] and activates epochs as cosmic time advances
+///
+/// Added automatically by [`AppEpochExt::add_epoch`].
+pub struct EpochManagerPlugin;
+
+impl Plugin for EpochManagerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EpochManager>()
+            .init_resource::<EpochTransition>()
+            .add_event::<EpochEntered>()
+            .add_event::<EpochExited>()
+            .add_systems(PreUpdate, update_active_epoch);
+    }
+}
+
+/// Activates the epoch containing the current cosmic time
+///
+/// Runs in `PreUpdate` so that every epoch system of a frame sees the same active epoch.
+/// A pending seek already counts, since the fixed steps of the frame continue from its
+/// target. Sends [`EpochExited`] then [`EpochEntered`] when the active epoch changes and
+/// recomputes the [`EpochTransition`] blend.
+pub fn update_active_epoch(
+    clock: Option<Res<SimulationClock>>,
+    mut manager: ResMut<EpochManager>,
+    mut transition: ResMut<EpochTransition>,
+    mut exited: EventWriter<EpochExited>,
+    mut entered: EventWriter<EpochEntered>,
+) {
+    let Some(clock) = clock else {
+        return;
+    };
+    let years = clock.seek_target().unwrap_or(clock.years());
+    let previous = manager.active().map(|epoch| epoch.name);
+    if manager.update(years) {
+        if let Some(name) = previous {
+            exited.send(EpochExited {
+                name,
+                years,
+            });
+        }
+        if let Some(epoch) = manager.active() {
+            entered.send(EpochEntered {
+                name: epoch.name,
+                years,
+            });
+        }
+    }
+    transition.update(&manager, years);
+}
+
+/// Extension methods for registering epochs on an [`App`]
+pub trait AppEpochExt {
+    /// Registers an epoch with the [`EpochManager`] and builds its systems
+    ///
+    /// The epoch's [`EpochSystems`] set is gated in the `Update`, `PostUpdate` and
+    /// `FixedUpdate` schedules so that it only runs while the epoch is active.
+    ///
+    /// # Panics
+    /// If the epoch cannot be registered (see [`EpochError`]).
+    fn add_epoch<E: EpochPlugin>(&mut self, epoch: E) -> &mut Self;
+}
+
+impl AppEpochExt for App {
+    fn add_epoch<E: EpochPlugin>(&mut self, epoch: E) -> &mut Self {
+        if !self.is_plugin_added::<EpochManagerPlugin>() {
+            self.add_plugins(EpochManagerPlugin);
+        }
+
+        let name = epoch.name();
+        if let Err(error) = self
+            .world_mut()
+            .resource_mut::<EpochManager>()
+            .register(EpochInfo::from_plugin(&epoch))
+        {
+            panic!("{}", error);
+        }
+
+        self.configure_sets(Update, EpochSystems(name).run_if(in_epoch(name)))
+            .configure_sets(PostUpdate, EpochSystems(name).run_if(in_epoch(name)))
+            .configure_sets(FixedUpdate, EpochSystems(name).run_if(in_epoch(name)));
+        epoch.build(self);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::prelude::{Events, IntoSystemConfigs, ResMut as SystemResMut};
+
+    fn info(name: &'static str, start_year: f64, end_year: f64) -> EpochInfo {
+        EpochInfo {
+            name,
+            start_year,
+            end_year,
+            camera_preset: None,
+            ui_panels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_register_orders_by_start_and_rejects_overlap() {
+        let mut manager = EpochManager::new();
+        manager.register(info("Recombination", 3.8e5, 1e8)).unwrap();
+        manager.register(info("Inflation", 1e-44, 1e-32)).unwrap();
+        manager.register(info("Plasma", 1e-32, 3.8e5)).unwrap();
+
+        let names: Vec<_> = manager.epochs().iter().map(|epoch| epoch.name).collect();
+        assert_eq!(names, vec!["Inflation", "Plasma", "Recombination"]);
+
+        assert_eq!(
+            manager.register(info("Dark Ages", 1e6, 2e8)),
+            Err(EpochError::Overlap {
+                name: "Dark Ages",
+                other: "Recombination"
+            })
+        );
+        assert!(matches!(
+            manager.register(info("Plasma", 1e9, 1e10)),
+            Err(EpochError::DuplicateName { .. })
+        ));
+        assert!(matches!(
+            manager.register(info("Backwards", 1e10, 1e9)),
+            Err(EpochError::InvalidRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reschedule_moves_shared_boundaries() {
+        let mut manager = EpochManager::new();
+        manager.register(info("Inflation", 0.0, 1.0)).unwrap();
+        manager.register(info("Plasma", 1.0, 10.0)).unwrap();
+        manager.register(info("Structure", 10.0, f64::INFINITY)).unwrap();
+        manager.update(5.0);
+
+        // Moving the later boundary first would overlap if applied one at a time
+        manager
+            .reschedule(&[("Plasma", 1.0, 20.0), ("Structure", 20.0, f64::INFINITY)])
+            .unwrap();
+        assert_eq!(manager.get("Plasma").map(|epoch| epoch.end_year), Some(20.0));
+        assert!(manager.is_active("Plasma"));
+        assert!(!manager.update(15.0), "15 years is still in the plasma");
+
+        assert_eq!(
+            manager.reschedule(&[("Plasma", 1.0, 30.0)]),
+            Err(EpochError::Overlap {
+                name: "Structure",
+                other: "Plasma"
+            })
+        );
+        assert!(matches!(
+            manager.reschedule(&[("Recombination", 1.0, 2.0)]),
+            Err(EpochError::UnknownEpoch { .. })
+        ));
+        assert_eq!(manager.get("Plasma").map(|epoch| epoch.end_year), Some(20.0));
+    }
+
+    #[test]
+    fn test_update_activates_one_epoch_with_neighbours() {
+        let mut manager = EpochManager::new();
+        manager.register(info("Inflation", 0.0, 1.0)).unwrap();
+        manager.register(info("Plasma", 1.0, 10.0)).unwrap();
+        manager.register(info("Structure", 10.0, f64::INFINITY)).unwrap();
+
+        assert!(manager.update(1.0), "boundary belongs to the later epoch");
+        assert!(manager.is_active("Plasma"));
+        assert!(!manager.is_active("Inflation"));
+        assert_eq!(manager.previous().map(|epoch| epoch.name), Some("Inflation"));
+        assert_eq!(manager.next().map(|epoch| epoch.name), Some("Structure"));
+        assert!(!manager.update(5.0), "same epoch should not count as a change");
+
+        manager.update(1e10);
+        assert_eq!(manager.active().map(|epoch| epoch.name), Some("Structure"));
+        assert_eq!(manager.next(), None);
+
+        manager.update(-1.0);
+        assert_eq!(manager.active(), None);
+        assert_eq!(manager.previous(), None);
+    }
+
+    /// Moves the clock immediately (the test apps do not tick it)
+    fn seek(app: &mut App, years: f64) {
+        let mut clock = app.world_mut().resource_mut::<SimulationClock>();
+        clock.seek_to(years);
+        clock.tick(0.0);
+    }
+
+    #[derive(Resource, Default)]
+    struct Ticks {
+        early: u32,
+        late: u32,
+    }
+
+    struct EarlyEpoch;
+
+    impl EpochPlugin for EarlyEpoch {
+        fn name(&self) -> &'static str {
+            "Early"
+        }
+        fn start_year(&self) -> f64 {
+            0.0
+        }
+        fn end_year(&self) -> f64 {
+            1.0
+        }
+        fn build(&self, app: &mut App) {
+            app.add_systems(
+                Update,
+                (|mut ticks: SystemResMut<Ticks>| ticks.early += 1).in_set(EpochSystems(self.name())),
+            );
+        }
+    }
+
+    struct LateEpoch;
+
+    impl EpochPlugin for LateEpoch {
+        fn name(&self) -> &'static str {
+            "Late"
+        }
+        fn start_year(&self) -> f64 {
+            1.0
+        }
+        fn end_year(&self) -> f64 {
+            f64::INFINITY
+        }
+        fn build(&self, app: &mut App) {
+            app.add_systems(
+                Update,
+                (|mut ticks: SystemResMut<Ticks>| ticks.late += 1).in_set(EpochSystems(self.name())),
+            );
+        }
+    }
+
+    #[test]
+    fn test_only_active_epoch_systems_run() {
+        let mut app = App::new();
+        app.init_resource::<Ticks>()
+            .insert_resource(SimulationClock::new())
+            .add_epoch(LateEpoch)
+            .add_epoch(EarlyEpoch);
+
+        app.update();
+        seek(&mut app, 2.0);
+        app.update();
+        app.update();
+
+        let ticks = app.world().resource::<Ticks>();
+        assert_eq!((ticks.early, ticks.late), (1, 2));
+    }
+
+    #[test]
+    fn test_epoch_change_sends_exit_then_enter() {
+        let mut app = App::new();
+        app.init_resource::<Ticks>()
+            .insert_resource(SimulationClock::new())
+            .add_epoch(EarlyEpoch)
+            .add_epoch(LateEpoch);
+
+        app.update();
+        // Scrub straight past the boundary
+        seek(&mut app, 5.0);
+        app.update();
+
+        let entered: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Events<EpochEntered>>()
+            .drain()
+            .map(|event| event.name)
+            .collect();
+        let exited: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Events<EpochExited>>()
+            .drain()
+            .map(|event| event.name)
+            .collect();
+        assert_eq!(entered, vec!["Early", "Late"]);
+        assert_eq!(exited, vec!["Early"]);
+        assert_eq!(app.world().resource::<EpochTransition>().to, Some("Late"));
+    }
+
+    #[test]
+    #[should_panic(expected = "overlaps")]
+    fn test_add_overlapping_epoch_panics() {
+        App::new().add_epoch(EarlyEpoch).add_epoch(EarlyEpochTwin);
+    }
+
+    struct EarlyEpochTwin;
+
+    impl EpochPlugin for EarlyEpochTwin {
+        fn name(&self) -> &'static str {
+            "Early twin"
+        }
+        fn start_year(&self) -> f64 {
+            0.5
+        }
+        fn end_year(&self) -> f64 {
+            2.0
+        }
+    }
+}
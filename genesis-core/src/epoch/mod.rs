@@ -1,8 +1,25 @@
-//! Epoch markers for cosmic evolution stages
+//! Epochs of cosmic evolution
 //!
-//! Defines epoch markers representing different phases of cosmological evolution.
-//! In Phase 1, only the Singularity epoch exists.
+//! Each phase of cosmological evolution is an [`EpochPlugin`]: it declares its name,
+//! time range, systems, camera preset and UI panels, and is registered with
+//! [`AppEpochExt::add_epoch`]. The [`EpochManager`] resource activates exactly one
+//! epoch at a time from the current cosmic time and exposes the active epoch and its
+//! neighbours, so new epochs can be added as plugins without touching existing ones.
+//! Crossing a boundary sends [`EpochExited`](crate::events::EpochExited) and
+//! [`EpochEntered`](crate::events::EpochEntered) events, and [`EpochTransition`]
+//! exposes a 0–1 blend factor for crossfading between neighbouring epochs.
+//!
+//! This crate provides the Singularity epoch; the epochs from inflation onwards are
+//! registered by the cosmology plugin of genesis-physics.
 
+pub mod manager;
+pub mod plugin;
 pub mod singularity;
+pub mod transition;
 
+pub use manager::{
+    update_active_epoch, AppEpochExt, EpochError, EpochInfo, EpochManager, EpochManagerPlugin,
+};
+pub use plugin::{in_epoch, CameraPreset, CameraPresetMode, EpochPanel, EpochPlugin, EpochSystems};
 pub use singularity::SingularityEpoch;
+pub use transition::EpochTransition;
@@ -0,0 +1,110 @@
+//! The EpochPlugin trait
+//!
+//! An epoch is a self-contained phase of cosmic evolution. Implementing
+//! [`EpochPlugin`] lets an epoch declare its name, its time range, the systems that
+//! drive its physics, the camera preset it is best viewed from and the UI panels
+//! it contributes. Epochs are registered with [`AppEpochExt::add_epoch`](super::AppEpochExt::add_epoch),
+//! which records them in the [`EpochManager`](super::EpochManager) and gates their
+//! systems so that only the active epoch's physics runs. The camera of genesis-render
+//! moves to an epoch's preset when the epoch is entered, and genesis-ui draws the
+//! active epoch's panels.
+
+use bevy::prelude::{App, Res, SystemSet, Vec3};
+
+use super::EpochManager;
+
+/// How the camera should frame an epoch when it becomes active
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraPresetMode {
+    /// Orbit around `target` at `distance`
+    Orbit,
+    /// Free-flight camera placed `distance` away from `target`
+    FreeFlight,
+}
+
+/// Camera placement suggested by an epoch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraPreset {
+    /// Camera control mode
+    pub mode: CameraPresetMode,
+    /// Distance from the target in world units
+    pub distance: f32,
+    /// Point the camera looks at
+    pub target: Vec3,
+}
+
+impl CameraPreset {
+    /// Creates an orbit preset around the origin
+    pub fn orbit(distance: f32) -> Self {
+        Self {
+            mode: CameraPresetMode::Orbit,
+            distance,
+            target: Vec3::ZERO,
+        }
+    }
+}
+
+/// A UI panel contributed by an epoch
+///
+/// Panels are described by identifier so that the UI crate decides how to draw
+/// them; the panel is shown while its epoch is active. Identifiers without a renderer
+/// registered in genesis-ui are not shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochPanel {
+    /// Stable identifier the UI uses to look up the panel's renderer
+    pub id: &'static str,
+    /// Human-readable panel title
+    pub title: &'static str,
+}
+
+/// System set holding the systems of one epoch
+///
+/// [`AppEpochExt::add_epoch`](super::AppEpochExt::add_epoch) configures this set in
+/// the `Update`, `PostUpdate` and `FixedUpdate` schedules to run only while the epoch
+/// is active. Epochs add their systems to it from [`EpochPlugin::build`]:
+///
+/// ```ignore
+/// app.add_systems(Update, spawn_particles.in_set(EpochSystems(self.name())));
+/// ```
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EpochSystems(pub &'static str);
+
+/// A phase of cosmic evolution that can be dropped into the app as a plugin
+///
+/// Time ranges are half-open, `[start_year, end_year)`, in cosmic years; ranges of
+/// registered epochs must not overlap. The final epoch can use `f64::INFINITY` as
+/// its end.
+pub trait EpochPlugin: Send + Sync + 'static {
+    /// Unique display name of the epoch (e.g. "Singularity")
+    fn name(&self) -> &'static str;
+
+    /// Cosmic time in years at which the epoch begins
+    fn start_year(&self) -> f64;
+
+    /// Cosmic time in years at which the epoch ends (exclusive)
+    fn end_year(&self) -> f64;
+
+    /// Registers the epoch's systems and resources
+    ///
+    /// Systems should be added to [`EpochSystems`] with this epoch's name so that
+    /// they only run while the epoch is active.
+    fn build(&self, _app: &mut App) {}
+
+    /// Camera placement to switch to when the epoch becomes active
+    fn camera_preset(&self) -> Option<CameraPreset> {
+        None
+    }
+
+    /// UI panels shown while the epoch is active
+    fn ui_panels(&self) -> Vec<EpochPanel> {
+        Vec::new()
+    }
+}
+
+/// Run condition that is true while the named epoch is active
+///
+/// # Arguments
+/// * `name` - Name of the epoch as returned by [`EpochPlugin::name`]
+pub fn in_epoch(name: &'static str) -> impl FnMut(Option<Res<EpochManager>>) -> bool + Clone {
+    move |manager: Option<Res<EpochManager>>| manager.is_some_and(|manager| manager.is_active(name))
+}
@@ -1,10 +1,10 @@
 //! The Singularity/Planck Boundary epoch
 //!
 //! This epoch represents the earliest phase of cosmic evolution, from the Big Bang
-//! (t = 0) to approximately 10⁻³² seconds (the Planck time boundary).
+//! (t = 0) until inflation begins at [`INFLATION_START_YEARS`] (~10⁻³⁶ seconds).
 //!
 //! According to the PRD, this is Phase 1 (The Singularity) where:
-//! - Time duration: t < 10⁻³²s (before the Planck boundary)
+//! - Time duration: t < 10⁻³⁶s (before inflation)
 //! - Characteristics: Particles spawn at the origin with outward velocity
 //! - Physical regime: Quantum gravity effects dominate
 //!
@@ -14,33 +14,36 @@
 //!
 //! # Implementation Status
 //!
-//! This is currently just a **marker struct**. It does NOT implement an EpochPlugin trait
-//! because such a trait does NOT exist in the codebase (see genesis-core/src/epoch/mod.rs).
-//!
-//! When the epoch management system is implemented, this struct would need to:
-//! 1. Implement an EpochPlugin trait (with methods like name(), start_year(), end_year(), build(), camera_config())
-//! 2. Be registered with the EpochManager (which also doesn't exist yet)
-//! 3. Provide systems that run during this epoch's time range
+//! The epoch is registered through the [`EpochPlugin`] trait with its time range and an
+//! orbit camera preset. Particle spawning for this phase still lives in genesis-render
+//! and is not yet gated by [`EpochSystems`](super::EpochSystems).
 
-use crate::time::seconds_to_years;
+use super::{CameraPreset, EpochPlugin};
+use crate::time::INFLATION_START_YEARS;
 
 /// The Singularity epoch marker
 ///
 /// Represents the first epoch of cosmic evolution, spanning from time zero
-/// to the Planck boundary (approximately 10⁻³² seconds).
-///
-/// This is a marker struct used as a type identifier for the Singularity epoch.
+/// to the start of inflation, where the inflation epoch takes over.
 ///
-/// **TODO**: When EpochPlugin trait is implemented, this struct will need to implement it
-/// to define epoch time ranges, camera configuration, and build systems.
+/// Register it with [`AppEpochExt::add_epoch`](super::AppEpochExt::add_epoch).
 pub struct SingularityEpoch;
 
-impl SingularityEpoch {
-    /// The Planck boundary time in seconds (10⁻³² seconds)
-    pub const PLANCK_BOUNDARY_SECONDS: f64 = 1e-32;
+impl EpochPlugin for SingularityEpoch {
+    fn name(&self) -> &'static str {
+        "Singularity"
+    }
+
+    fn start_year(&self) -> f64 {
+        0.0
+    }
+
+    fn end_year(&self) -> f64 {
+        INFLATION_START_YEARS
+    }
 
-    /// The Planck boundary time converted to cosmic years
-    pub fn planck_boundary_years() -> f64 {
-        seconds_to_years(Self::PLANCK_BOUNDARY_SECONDS)
+    fn camera_preset(&self) -> Option<CameraPreset> {
+        // Close orbit to watch particles stream outward from the origin
+        Some(CameraPreset::orbit(100.0))
     }
 }
@@ -0,0 +1,150 @@
+//! Crossfade progress between adjacent epochs
+//!
+//! Epoch boundaries span dozens of orders of magnitude in cosmic time, so the
+//! crossfade window is measured in decades (powers of ten) of cosmic time and is
+//! centred on the boundary. [`EpochTransition`] is recomputed from the current time
+//! alone every frame, so a crossfade looks the same whether the boundary is crossed
+//! during playback or by scrubbing the timeline.
+
+use bevy::prelude::Resource;
+
+use super::EpochManager;
+
+/// Blend state between the epochs either side of the nearest boundary
+///
+/// While cosmic time lies inside the window around a boundary, `from` is the earlier
+/// epoch, `to` the later one and `blend` rises from 0 (entirely `from`) to 1
+/// (entirely `to`) across the window, reaching 0.5 at the boundary itself. Outside
+/// every window, `from` and `to` are both the active epoch and `blend` is 1.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct EpochTransition {
+    /// Width of the crossfade window in decades of cosmic time (0 disables blending)
+    pub window_decades: f64,
+    /// Epoch being faded out
+    pub from: Option<&'static str>,
+    /// Epoch being faded in
+    pub to: Option<&'static str>,
+    /// Progress from `from` to `to` in `[0, 1]`
+    pub blend: f64,
+}
+
+impl Default for EpochTransition {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl EpochTransition {
+    /// Creates a transition state with the given window width in decades
+    pub fn new(window_decades: f64) -> Self {
+        Self {
+            window_decades,
+            from: None,
+            to: None,
+            blend: 1.0,
+        }
+    }
+
+    /// Returns true while cosmic time is inside a crossfade window
+    pub fn is_blending(&self) -> bool {
+        self.from != self.to
+    }
+
+    /// Recomputes the blend state for a cosmic time
+    ///
+    /// # Arguments
+    /// * `manager` - Registered epochs
+    /// * `years` - Current cosmic time in years
+    pub fn update(&mut self, manager: &EpochManager, years: f64) {
+        let half_window = self.window_decades / 2.0;
+        let log_years = years.log10();
+
+        // Boundaries at t = 0 (or before) have no logarithmic neighbourhood
+        let boundary = manager.epochs().windows(2).find_map(|pair| {
+            let start = pair[1].start_year;
+            let offset = log_years - start.log10();
+            (start > 0.0 && half_window > 0.0 && offset.abs() < half_window)
+                .then(|| (pair[0].name, pair[1].name, offset))
+        });
+
+        match boundary {
+            Some((from, to, offset)) => {
+                self.from = Some(from);
+                self.to = Some(to);
+                self.blend = ((offset + half_window) / self.window_decades).clamp(0.0, 1.0);
+            }
+            None => {
+                let active = manager.epoch_at(years).map(|epoch| epoch.name);
+                self.from = active;
+                self.to = active;
+                self.blend = 1.0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::epoch::EpochInfo;
+
+    fn manager() -> EpochManager {
+        let mut manager = EpochManager::new();
+        for (name, start_year, end_year) in [
+            ("Singularity", 0.0, 1e-40),
+            ("Inflation", 1e-40, 1e-32),
+            ("Plasma", 1e-32, f64::INFINITY),
+        ] {
+            manager
+                .register(EpochInfo {
+                    name,
+                    start_year,
+                    end_year,
+                    camera_preset: None,
+                    ui_panels: Vec::new(),
+                })
+                .unwrap();
+        }
+        manager
+    }
+
+    #[test]
+    fn test_blend_rises_across_the_window() {
+        let manager = manager();
+        let mut transition = EpochTransition::new(2.0);
+
+        transition.update(&manager, 10f64.powf(-32.9));
+        assert_eq!((transition.from, transition.to), (Some("Inflation"), Some("Plasma")));
+        assert!((transition.blend - 0.05).abs() < 1e-9, "near window start, got {}", transition.blend);
+
+        transition.update(&manager, 1e-32);
+        assert!((transition.blend - 0.5).abs() < 1e-9, "boundary, got {}", transition.blend);
+
+        transition.update(&manager, 10f64.powf(-31.5));
+        assert!((transition.blend - 0.75).abs() < 1e-9, "got {}", transition.blend);
+        assert!(transition.is_blending());
+    }
+
+    #[test]
+    fn test_outside_window_is_fully_active_epoch() {
+        let manager = manager();
+        let mut transition = EpochTransition::new(1.0);
+
+        transition.update(&manager, 1e-20);
+        assert_eq!((transition.from, transition.to), (Some("Plasma"), Some("Plasma")));
+        assert_eq!(transition.blend, 1.0);
+        assert!(!transition.is_blending());
+
+        transition.update(&manager, 0.0);
+        assert_eq!(transition.to, Some("Singularity"));
+    }
+
+    #[test]
+    fn test_zero_window_disables_blending() {
+        let manager = manager();
+        let mut transition = EpochTransition::new(0.0);
+        transition.update(&manager, 1e-32);
+        assert!(!transition.is_blending());
+        assert_eq!(transition.to, Some("Plasma"));
+    }
+}
@@ -37,3 +37,62 @@ pub struct ScrubbingEvent {
     /// `true` when the user is actively scrubbing the timeline, `false` when scrubbing ends
     pub is_scrubbing: bool,
 }
+
+/// Event emitted when an epoch becomes the active epoch
+///
+/// Sent by the [`EpochManager`](crate::epoch::EpochManager) whenever the active epoch
+/// changes, whether cosmic time advanced during playback or jumped during timeline
+/// scrubbing. A jump across several epochs enters only the epoch that ends up active.
+/// The matching [`EpochExited`] event for the previous epoch is sent first.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct EpochEntered {
+    /// Name of the epoch that became active
+    pub name: &'static str,
+    /// Cosmic time in years at which the change was detected
+    pub years: f64,
+}
+
+/// Event emitted when an epoch stops being the active epoch
+///
+/// See [`EpochEntered`] for when epoch changes are detected.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct EpochExited {
+    /// Name of the epoch that was active
+    pub name: &'static str,
+    /// Cosmic time in years at which the change was detected
+    pub years: f64,
+}
+
+/// Request to change playback of the [`SimulationClock`](crate::time::SimulationClock)
+///
+/// This is the single entry point for playback control: the timeline UI, keyboard
+/// shortcuts, scripts and remote-control layers all send these events instead of
+/// mutating the clock directly. Commands are applied in order at the start of the
+/// next frame, before the clock ticks.
+///
+/// # Usage
+///
+/// ```no_run
+/// use bevy::prelude::EventWriter;
+/// use genesis_core::SimulationCommand;
+///
+/// fn jump_to_recombination(mut commands: EventWriter<SimulationCommand>) {
+///     commands.send(SimulationCommand::SeekTo(380_000.0));
+///     commands.send(SimulationCommand::Play);
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub enum SimulationCommand {
+    /// Resume cosmic time
+    Play,
+    /// Pause cosmic time
+    Pause,
+    /// Jump back to the Big Bang and pause
+    Reset,
+    /// Jump to a cosmic time in years (clamped to t ≥ 0)
+    SeekTo(f64),
+    /// Set the playback speed factor (clamped to 1x–10¹²x)
+    SetSpeed(f64),
+    /// Pause, then advance by this many fixed physics steps (one per `FixedUpdate` tick)
+    StepTicks(u32),
+}
@@ -6,40 +6,59 @@
 //!
 //! # Modules
 //!
-//! - [`config`] - Configuration structures for Phase 1 parameters with TOML deserialization support
-//! - [`epoch`] - Epoch markers representing different phases of cosmological evolution (currently: Singularity)
+//! - [`cli`] - Command-line interface for the `genesis` binary (config overrides, subcommands)
+//! - [`config`] - Configuration structures for Phase 1 parameters with layered TOML/environment loading
+//! - [`epoch`] - `EpochPlugin` trait and `EpochManager` registry for the phases of cosmological evolution (currently: Singularity)
 //! - [`events`] - Event types for inter-system communication (e.g., timeline scrubbing notifications)
-//! - [`physics`] - Particle physics data structures (simulation-level Particle for physics calculations)
-//! - [`time`] - Cosmic time management with f64 precision accumulator and time integration systems
+//! - [`physics`] - Structure-of-arrays `ParticleStore` owning the state of every simulation particle
+//! - [`session`] - Versioned session files capturing config, clock, background, particles and camera for save/resume
+//! - [`units`] - Newtype physical quantities (`Seconds`, `Years`, `GeVInv`, `Kelvin`, `GeV`, `Mpc`) with unit conversions
+//! - [`time`] - The authoritative `SimulationClock` (time, pause, speed, seeks) and its time integration plugin
 //!
 //! # Public Exports
 //!
 //! This crate re-exports commonly-used types and plugins:
-//! - `Config`, `CameraConfig`, `ParticleConfig`, `TimeConfig`, `WindowConfig` - Configuration types
+//! - `Cli`, `Command` - Command-line arguments and subcommands
+//! - `Config`, `CameraConfig`, `CosmologyConfig`, `EpochConfig`, `ParticleConfig`, `TimeConfig`, `WindowConfig` - Configuration types
+//! - `CosmologyPreset` - Named cosmological parameter sets (Planck 2018, Einstein–de Sitter, ...)
+//! - `ConfigLoader`, `ConfigLayer`, `LayeredConfig` - Layered configuration loading with value sources
+//! - `ConfigError` - Typed configuration error with field paths and source locations
 //! - `ScrubbingEvent` - Event for timeline scrubbing notifications
-//! - `SingularityEpoch` - Epoch marker for the Singularity phase
+//! - `SimulationCommand` - Playback requests (play, pause, reset, seek, speed, fixed-step stepping) applied by the clock
+//! - `EpochEntered`, `EpochExited`, `EpochTransition` - Epoch change events and crossfade progress
+//! - `EpochPlugin`, `EpochManager`, `AppEpochExt` - Epoch declaration, activation and registration
+//! - `SingularityEpoch` - Epoch plugin for the Singularity phase
 //! - `TimeIntegrationPlugin` - Bevy plugin for cosmic time accumulation
+//! - `ParticleStore`, `ParticleId`, `Species` - Simulation particle state
+//! - `Session`, `SessionError` - Saved simulation sessions and their load/save errors
+//! - `FixedTimestep`, `SimulationSet` - Fixed physics timestep and the ordered `FixedUpdate` sets (clock, then physics)
 //!
 //! # Two-Level Particle Architecture
 //!
-//! This crate provides **simulation-level Particle** (in [`physics::Particle`](physics/struct.Particle.html))
-//! that uses plain Rust types for efficient physics calculations. The rendering-level Particle
-//! (in `genesis-render::particle::Particle`) uses Bevy ECS components and is attached to
-//! entities for GPU rendering.
+//! This crate provides the **simulation-level** [`ParticleStore`](physics::ParticleStore)
+//! resource, a structure of arrays that physics systems update (in parallel). The
+//! rendering-level Particle (in `genesis-render::particle::Particle`) is a Bevy component
+//! holding only color and size; rendering reads positions from the store.
 
+pub mod cli;
 pub mod config;
 pub mod epoch;
 pub mod events;
 pub mod physics;
+pub mod session;
 pub mod time;
+pub mod units;
 
+pub use cli::{Cli, Command};
 pub use config::{
-    CameraConfig, Config, ParticleConfig,
-    TimeConfig, WindowConfig,
+    CameraConfig, Config, ConfigError, ConfigLayer, ConfigLoader, CosmologyConfig,
+    CosmologyPreset, EpochConfig, LayeredConfig, ParticleConfig, TimeConfig, WindowConfig,
 };
-pub use epoch::SingularityEpoch;
-pub use events::ScrubbingEvent;
-pub use time::TimeIntegrationPlugin;
+pub use epoch::{AppEpochExt, EpochManager, EpochPlugin, EpochTransition, SingularityEpoch};
+pub use events::{EpochEntered, EpochExited, ScrubbingEvent, SimulationCommand};
+pub use physics::{ParticleId, ParticleStore, Species};
+pub use session::{Session, SessionError};
+pub use time::{FixedTimestep, SimulationSet, TimeIntegrationPlugin};
 
 /// Version of the core library
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -1,64 +1,384 @@
 //! Particle physics data structures
 //!
-//! Defines basic particle data structure for simulation state.
-//! Physics calculations and cosmological evolution systems are not yet implemented.
+//! Defines the [`ParticleStore`] resource that owns the state of every simulation
+//! particle.
 //!
 //! # Two-Level Particle Architecture
 //!
-//! This crate defines the **simulation-level Particle** that stores core physics data:
-//! - Uses native Rust types ([f32; 3] for position/velocity/color, f32 for size)
-//! - Designed for physics calculations in future cosmological simulation stages
+//! This crate defines the **simulation-level** particle state as a structure of
+//! arrays: one column per quantity (position, velocity, mass, species, id and
+//! internal energy), indexed by particle. Positions, velocities and masses are
+//! `f64`; internal energies are `f32`. Physics systems update the columns, in
+//! parallel with [`ParticleStore::par_for_each_mut`].
 //!
-//! The rendering-level Particle (defined in `genesis-render::particle::Particle`) uses
-//! Bevy ECS components (Vec3, Color) and is attached to entities for rendering.
+//! The rendering-level Particle (defined in `genesis-render::particle::Particle`) is
+//! a Bevy component holding only presentation data (color, size) and the index of
+//! the particle it draws. Rendering reads positions from the store; it does not own
+//! any simulation state.
 
-/// A single particle in the simulation
-///
-/// Basic particle data structure storing position, velocity, color, and size.
-/// Physics movement and interaction systems need to be implemented separately.
-///
-/// This is the **simulation-level** particle used for physics calculations.
-/// It uses plain Rust types (arrays, not Bevy types) for efficient
-/// computational processing during cosmological evolution simulations.
-///
-/// Fields:
-/// - `position`: 3D coordinates in world space
-/// - `velocity`: 3D velocity vector
-/// - `color`: RGB color values [0.0, 1.0] for each channel
-/// - `size`: Particle size in world units
+use bevy::math::DVec3;
+use bevy::prelude::Resource;
+use bevy::tasks::{ComputeTaskPool, TaskPool};
+use serde::{Deserialize, Serialize};
+
+use crate::config::ParticleConfig;
+
+/// Minimum number of particles handed to one task by [`ParticleStore::par_for_each_mut`]
+const MIN_PARALLEL_CHUNK: usize = 4096;
+
+/// Speed of the particles spawned by [`ParticleStore::from_config`]
+const INITIAL_SPEED: f64 = 0.5;
+
+/// Stable identifier of a particle, unique within a [`ParticleStore`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ParticleId(pub u64);
+
+/// Kind of matter or energy a particle represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Species {
+    /// Ordinary (baryonic) matter
+    #[default]
+    Baryon,
+    /// Cold dark matter
+    DarkMatter,
+    /// Photons and other relativistic species
+    Radiation,
+}
+
+/// Mutable view of one particle, passed to [`ParticleStore::par_for_each_mut`]
+#[derive(Debug)]
+pub struct ParticleMut<'a> {
+    /// Stable identifier
+    pub id: ParticleId,
+    /// Species
+    pub species: Species,
+    /// Mass
+    pub mass: f64,
+    /// Position
+    pub position: &'a mut DVec3,
+    /// Velocity
+    pub velocity: &'a mut DVec3,
+    /// Internal (thermal) energy per unit mass
+    pub internal_energy: &'a mut f32,
+}
+
+/// Structure-of-arrays storage for all simulation particles
 ///
-/// # Note
+/// Every column holds one entry per particle, so particle `i` is made up of the
+/// `i`-th entry of each column. Particles keep their index for as long as they are
+/// in the store; [`ParticleId`]s stay unique even after [`ParticleStore::clear`].
 ///
-/// This is distinct from [`genesis_render::particle::Particle`](../genesis_render/particle/struct.Particle.html),
-/// which is the **rendering-level** component attached to Bevy entities for GPU rendering.
-pub struct Particle {
-    /// Position in 3D space [x, y, z]
-    pub position: [f32; 3],
-    /// Velocity vector [vx, vy, vz]
-    pub velocity: [f32; 3],
-    /// RGB color values [r, g, b], each in range [0.0, 1.0]
-    pub color: [f32; 3],
-    /// Size in world units
-    pub size: f32,
+/// The store also keeps each particle's position and velocity at t = 0, which
+/// [`ParticleStore::rewind_to`] uses to place particles at an arbitrary time during
+/// timeline scrubbing.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParticleStore {
+    positions: Vec<DVec3>,
+    velocities: Vec<DVec3>,
+    initial_positions: Vec<DVec3>,
+    initial_velocities: Vec<DVec3>,
+    masses: Vec<f64>,
+    species: Vec<Species>,
+    ids: Vec<ParticleId>,
+    internal_energies: Vec<f32>,
+    next_id: u64,
 }
 
-impl Particle {
-    /// Creates a new Particle with the specified parameters.
+impl ParticleStore {
+    /// Creates an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty store with room for `capacity` particles
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            positions: Vec::with_capacity(capacity),
+            velocities: Vec::with_capacity(capacity),
+            initial_positions: Vec::with_capacity(capacity),
+            initial_velocities: Vec::with_capacity(capacity),
+            masses: Vec::with_capacity(capacity),
+            species: Vec::with_capacity(capacity),
+            ids: Vec::with_capacity(capacity),
+            internal_energies: Vec::with_capacity(capacity),
+            next_id: 0,
+        }
+    }
+
+    /// Creates the initial particles for a run
+    ///
+    /// Spawns `config.initial_count` baryons of unit mass at the origin, moving
+    /// radially outward in deterministic pseudo-random directions.
+    pub fn from_config(config: &ParticleConfig) -> Self {
+        let mut store = Self::with_capacity(config.initial_count);
+
+        for i in 0..config.initial_count {
+            // Simple deterministic pseudo-random distribution using the index
+            let fi = i as f64;
+            let direction = DVec3::new(
+                ((fi * 123.456).fract() - 0.5) * 2.0,
+                ((fi * 789.012).fract() - 0.5) * 2.0,
+                ((fi * 345.678).fract() - 0.5) * 2.0,
+            )
+            .normalize_or(DVec3::X);
+
+            store.push(DVec3::ZERO, direction * INITIAL_SPEED, 1.0, Species::Baryon);
+        }
+
+        store
+    }
+
+    /// Number of particles
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns true if there are no particles
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Adds a particle with zero internal energy
     ///
-    /// # Arguments
-    /// * `position` - 3D position in world space [x, y, z]
-    /// * `velocity` - 3D velocity vector [vx, vy, vz]
-    /// * `color` - RGB color values [r, g, b], each in range [0.0, 1.0]
-    /// * `size` - Particle size in world units
+    /// The position and velocity are also recorded as the particle's state at t = 0.
     ///
     /// # Returns
-    /// A new Particle instance with the specified properties
-    pub fn new(position: [f32; 3], velocity: [f32; 3], color: [f32; 3], size: f32) -> Self {
-        Self {
-            position,
-            velocity,
-            color,
-            size,
+    /// The new particle's identifier
+    pub fn push(&mut self, position: DVec3, velocity: DVec3, mass: f64, species: Species) -> ParticleId {
+        let id = ParticleId(self.next_id);
+        self.next_id += 1;
+
+        self.positions.push(position);
+        self.velocities.push(velocity);
+        self.initial_positions.push(position);
+        self.initial_velocities.push(velocity);
+        self.masses.push(mass);
+        self.species.push(species);
+        self.ids.push(id);
+        self.internal_energies.push(0.0);
+        id
+    }
+
+    /// Removes all particles
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.velocities.clear();
+        self.initial_positions.clear();
+        self.initial_velocities.clear();
+        self.masses.clear();
+        self.species.clear();
+        self.ids.clear();
+        self.internal_energies.clear();
+    }
+
+    /// Positions, one per particle
+    pub fn positions(&self) -> &[DVec3] {
+        &self.positions
+    }
+
+    /// Mutable positions, one per particle
+    pub fn positions_mut(&mut self) -> &mut [DVec3] {
+        &mut self.positions
+    }
+
+    /// Velocities, one per particle
+    pub fn velocities(&self) -> &[DVec3] {
+        &self.velocities
+    }
+
+    /// Mutable velocities, one per particle
+    pub fn velocities_mut(&mut self) -> &mut [DVec3] {
+        &mut self.velocities
+    }
+
+    /// Positions at t = 0, one per particle
+    pub fn initial_positions(&self) -> &[DVec3] {
+        &self.initial_positions
+    }
+
+    /// Velocities at t = 0, one per particle
+    pub fn initial_velocities(&self) -> &[DVec3] {
+        &self.initial_velocities
+    }
+
+    /// Masses, one per particle
+    pub fn masses(&self) -> &[f64] {
+        &self.masses
+    }
+
+    /// Species, one per particle
+    pub fn species(&self) -> &[Species] {
+        &self.species
+    }
+
+    /// Identifiers, one per particle
+    pub fn ids(&self) -> &[ParticleId] {
+        &self.ids
+    }
+
+    /// Internal energies per unit mass, one per particle
+    pub fn internal_energies(&self) -> &[f32] {
+        &self.internal_energies
+    }
+
+    /// Mutable internal energies per unit mass, one per particle
+    pub fn internal_energies_mut(&mut self) -> &mut [f32] {
+        &mut self.internal_energies
+    }
+
+    /// Index of the particle with identifier `id`, if it is in the store
+    pub fn index_of(&self, id: ParticleId) -> Option<usize> {
+        self.ids.binary_search(&id).ok()
+    }
+
+    /// Places every particle where its t = 0 state puts it after `elapsed`
+    ///
+    /// position = (initial_position + initial_velocity × elapsed) × scale
+    pub fn rewind_to(&mut self, elapsed: f64, scale: f64) {
+        let initial = self.initial_positions.iter().zip(&self.initial_velocities);
+        for (position, (p0, v0)) in self.positions.iter_mut().zip(initial) {
+            *position = (*p0 + *v0 * elapsed) * scale;
+        }
+    }
+
+    /// Calls `f` for every particle, splitting the store into chunks processed in
+    /// parallel on the [`ComputeTaskPool`]
+    ///
+    /// Each call only sees its own particle, so `f` must not depend on the order
+    /// in which particles are visited.
+    pub fn par_for_each_mut(&mut self, f: impl Fn(ParticleMut<'_>) + Send + Sync) {
+        let len = self.len();
+        if len == 0 {
+            return;
         }
+
+        let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+        let chunk_size = len.div_ceil(pool.thread_num().max(1)).max(MIN_PARALLEL_CHUNK);
+        let f = &f;
+
+        let mutable = self
+            .positions
+            .chunks_mut(chunk_size)
+            .zip(self.velocities.chunks_mut(chunk_size))
+            .zip(self.internal_energies.chunks_mut(chunk_size));
+        let shared = self
+            .masses
+            .chunks(chunk_size)
+            .zip(self.species.chunks(chunk_size))
+            .zip(self.ids.chunks(chunk_size));
+
+        pool.scope(|scope| {
+            for (((positions, velocities), energies), ((masses, species), ids)) in mutable.zip(shared) {
+                scope.spawn(async move {
+                    let columns = positions.iter_mut().zip(velocities).zip(energies);
+                    for (i, ((position, velocity), internal_energy)) in columns.enumerate() {
+                        f(ParticleMut {
+                            id: ids[i],
+                            species: species[i],
+                            mass: masses[i],
+                            position,
+                            velocity,
+                            internal_energy,
+                        });
+                    }
+                });
+            }
+        });
+    }
+
+    /// Checks that all columns hold one entry per particle and that identifiers
+    /// are unique and increasing
+    ///
+    /// # Returns
+    /// * `Ok(())` if the store is consistent
+    /// * `Err(String)` naming the first inconsistent column
+    pub fn validate(&self) -> Result<(), String> {
+        let len = self.len();
+        let lengths = [
+            ("velocities", self.velocities.len()),
+            ("initial_positions", self.initial_positions.len()),
+            ("initial_velocities", self.initial_velocities.len()),
+            ("masses", self.masses.len()),
+            ("species", self.species.len()),
+            ("ids", self.ids.len()),
+            ("internal_energies", self.internal_energies.len()),
+        ];
+        if let Some((name, found)) = lengths.iter().find(|(_, found)| *found != len) {
+            return Err(format!(
+                "particle column '{}' has {} entries, expected {}",
+                name, found, len
+            ));
+        }
+
+        let ordered = self.ids.windows(2).all(|pair| pair[0] < pair[1]);
+        let below_next = self.ids.last().is_none_or(|id| id.0 < self.next_id);
+        if !(ordered && below_next) {
+            return Err("particle ids are not unique and increasing".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_config_spawns_radial_burst() {
+        let config = ParticleConfig {
+            initial_count: 100,
+            ..Default::default()
+        };
+        let store = ParticleStore::from_config(&config);
+
+        assert_eq!(store.len(), 100);
+        assert!(store.validate().is_ok());
+        assert!(store.positions().iter().all(|p| *p == DVec3::ZERO));
+        assert!(store
+            .velocities()
+            .iter()
+            .all(|v| (v.length() - INITIAL_SPEED).abs() < 1e-12));
+        assert_eq!(store.ids()[99], ParticleId(99));
+        assert_eq!(store.index_of(ParticleId(42)), Some(42));
+    }
+
+    #[test]
+    fn test_ids_stay_unique_after_clear() {
+        let mut store = ParticleStore::new();
+        store.push(DVec3::ZERO, DVec3::X, 1.0, Species::DarkMatter);
+        store.clear();
+        let id = store.push(DVec3::ZERO, DVec3::X, 1.0, Species::Radiation);
+
+        assert_eq!(id, ParticleId(1));
+        assert_eq!(store.index_of(ParticleId(0)), None);
+        assert_eq!(store.species(), &[Species::Radiation]);
+    }
+
+    #[test]
+    fn test_par_for_each_mut_visits_every_particle_once() {
+        let mut store = ParticleStore::with_capacity(3 * MIN_PARALLEL_CHUNK);
+        for i in 0..3 * MIN_PARALLEL_CHUNK {
+            store.push(DVec3::splat(i as f64), DVec3::X, 2.0, Species::Baryon);
+        }
+
+        store.par_for_each_mut(|particle| {
+            *particle.position += *particle.velocity * particle.mass;
+            *particle.internal_energy += particle.id.0 as f32;
+        });
+
+        for (i, position) in store.positions().iter().enumerate() {
+            assert_eq!(*position, DVec3::new(i as f64 + 2.0, i as f64, i as f64));
+            assert_eq!(store.internal_energies()[i], i as f32);
+        }
+    }
+
+    #[test]
+    fn test_rewind_to_uses_initial_state() {
+        let mut store = ParticleStore::new();
+        store.push(DVec3::Y, DVec3::X, 1.0, Species::Baryon);
+        store.positions_mut()[0] = DVec3::splat(100.0);
+
+        store.rewind_to(3.0, 2.0);
+        assert_eq!(store.positions()[0], DVec3::new(6.0, 2.0, 0.0));
     }
 }
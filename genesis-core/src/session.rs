@@ -0,0 +1,740 @@
+//! Saving and restoring complete simulation sessions
+//!
+//! A [`Session`] captures everything needed to resume a run exactly where it was
+//! left: the effective configuration (including the RNG seed in `physics.seed`),
+//! the simulation clock, the cosmological background, the inflaton field, the
+//! [`ParticleStore`] and the camera pose. Each crate converts its own resources to
+//! and from these plain data types, so this module has no dependency on physics
+//! or rendering code.
+//!
+//! # File Format
+//!
+//! | Bytes | Content                                      |
+//! |-------|----------------------------------------------|
+//! | 0..8  | [`SESSION_MAGIC`]                            |
+//! | 8..12 | Format version, little-endian `u32`          |
+//! | 12..  | `bincode`-encoded [`Session`] for that version |
+//!
+//! Files written by older versions are migrated on load; files written by a newer
+//! version are rejected with [`SessionError::UnsupportedVersion`].
+//!
+//! | Version | Change                                                          |
+//! |---------|-----------------------------------------------------------------|
+//! | 1       | Initial format, with render-level particle arrays               |
+//! | 2       | Particles saved as a [`ParticleStore`]; colors and sizes dropped |
+//! | 3       | Config gains the `[epochs]` section                             |
+//! | 4       | `[epochs]` gains `bbn_start_temperature_mev`                    |
+//! | 5       | Hadron epoch after the Quark-Gluon Plasma; later epochs move up |
+//!
+//! # Example
+//!
+//! ```no_run
+//! use genesis_core::session::Session;
+//! use genesis_core::time::SimulationClock;
+//! use genesis_core::Config;
+//!
+//! let mut session = Session::new(Config::default());
+//! session.clock = SimulationClock::new().state();
+//! session.save("genesis.session").unwrap();
+//!
+//! let restored = Session::load("genesis.session").unwrap();
+//! assert_eq!(restored, session);
+//! ```
+
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use bevy::math::Vec3;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{
+    CameraConfig, Config, CosmologyConfig, DisplayConfig, EpochConfig, ParticleConfig,
+    PhysicsConfig, TimeConfig, WindowConfig,
+};
+use crate::physics::{ParticleStore, Species};
+use crate::time::TimeMode;
+
+/// Bytes identifying a session file
+pub const SESSION_MAGIC: [u8; 8] = *b"GENESIS\0";
+
+/// Session format version written by this build
+pub const SESSION_VERSION: u32 = 5;
+
+/// Index of the Hadron epoch, inserted in version 5 where older sessions had
+/// Nucleosynthesis
+const HADRON_EPOCH_INDEX: u8 = 3;
+
+/// Default file name used by the save and load actions
+pub const DEFAULT_SESSION_PATH: &str = "genesis.session";
+
+/// Snapshot of a whole simulation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    /// Effective configuration, including the RNG seed
+    pub config: Config,
+    /// Simulation clock
+    pub clock: ClockState,
+    /// Cosmological background (scale factor, Hubble rate, densities)
+    pub background: BackgroundState,
+    /// Inflaton field
+    pub inflaton: InflatonState,
+    /// Camera pose and controller angles
+    pub camera: CameraPose,
+    /// Simulation particles
+    pub particles: ParticleStore,
+}
+
+impl Session {
+    /// Creates a session for `config` with default state for everything else
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            clock: ClockState::default(),
+            background: BackgroundState::default(),
+            inflaton: InflatonState::default(),
+            camera: CameraPose::default(),
+            particles: ParticleStore::default(),
+        }
+    }
+
+    /// Encodes the session in the current file format
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SessionError> {
+        let mut bytes = Vec::new();
+        self.write_to(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Decodes a session from bytes written by [`Session::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SessionError> {
+        Self::read_from(bytes)
+    }
+
+    /// Writes the session to a file, replacing it if it exists
+    ///
+    /// # Returns
+    /// * `Ok(())` if the file was written
+    /// * `Err(SessionError)` if encoding or writing fails
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SessionError> {
+        let path = path.as_ref();
+        let io_error = |e: std::io::Error| SessionError::Io {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        };
+
+        let mut writer = BufWriter::new(File::create(path).map_err(io_error)?);
+        self.write_to(&mut writer)?;
+        writer.flush().map_err(io_error)
+    }
+
+    /// Reads a session from a file
+    ///
+    /// # Returns
+    /// * `Ok(Session)` if the file is a valid session of this or an older version
+    /// * `Err(SessionError)` if the file cannot be read, is not a session, was
+    ///   written by a newer version or fails validation
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SessionError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| SessionError::Io {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        Self::read_from(BufReader::new(file))
+    }
+
+    fn write_to(&self, mut writer: impl Write) -> Result<(), SessionError> {
+        let corrupt = |e: std::io::Error| SessionError::Corrupt(e.to_string());
+        writer.write_all(&SESSION_MAGIC).map_err(corrupt)?;
+        writer
+            .write_all(&SESSION_VERSION.to_le_bytes())
+            .map_err(corrupt)?;
+        bincode::serialize_into(writer, self).map_err(|e| SessionError::Corrupt(e.to_string()))
+    }
+
+    fn read_from(mut reader: impl Read) -> Result<Self, SessionError> {
+        let mut magic = [0u8; 8];
+        reader
+            .read_exact(&mut magic)
+            .map_err(|_| SessionError::NotASession)?;
+        if magic != SESSION_MAGIC {
+            return Err(SessionError::NotASession);
+        }
+
+        let mut version = [0u8; 4];
+        reader
+            .read_exact(&mut version)
+            .map_err(|e| SessionError::Corrupt(e.to_string()))?;
+
+        let version = u32::from_le_bytes(version);
+        let mut session: Session = match version {
+            1 => bincode::deserialize_from::<_, SessionV1>(reader)
+                .map_err(|e| SessionError::Corrupt(e.to_string()))?
+                .into(),
+            2 => bincode::deserialize_from::<_, SessionV2>(reader)
+                .map_err(|e| SessionError::Corrupt(e.to_string()))?
+                .into(),
+            3 => bincode::deserialize_from::<_, SessionV3>(reader)
+                .map_err(|e| SessionError::Corrupt(e.to_string()))?
+                .into(),
+            4 | 5 => bincode::deserialize_from(reader)
+                .map_err(|e| SessionError::Corrupt(e.to_string()))?,
+            found => return Err(SessionError::UnsupportedVersion(found)),
+        };
+        if version < 5 && session.background.epoch >= HADRON_EPOCH_INDEX {
+            session.background.epoch += 1;
+        }
+
+        session.validate()?;
+        Ok(session)
+    }
+
+    /// Checks the decoded session for internal consistency
+    fn validate(&self) -> Result<(), SessionError> {
+        self.config
+            .validate()
+            .map_err(|e| SessionError::Invalid(format!("config: {}", e)))?;
+        self.particles
+            .validate()
+            .map_err(SessionError::Invalid)
+    }
+}
+
+/// Layout of a version 1 session
+#[derive(Serialize, Deserialize)]
+struct SessionV1 {
+    config: ConfigV2,
+    clock: ClockState,
+    background: BackgroundState,
+    inflaton: InflatonState,
+    camera: CameraPose,
+    particles: ParticleArraysV1,
+}
+
+impl From<SessionV1> for Session {
+    fn from(old: SessionV1) -> Self {
+        Self {
+            config: old.config.into(),
+            clock: old.clock,
+            background: old.background,
+            inflaton: old.inflaton,
+            camera: old.camera,
+            particles: old.particles.into_store(),
+        }
+    }
+}
+
+/// Layout of a version 2 session
+#[derive(Serialize, Deserialize)]
+struct SessionV2 {
+    config: ConfigV2,
+    clock: ClockState,
+    background: BackgroundState,
+    inflaton: InflatonState,
+    camera: CameraPose,
+    particles: ParticleStore,
+}
+
+impl From<SessionV2> for Session {
+    fn from(old: SessionV2) -> Self {
+        Self {
+            config: old.config.into(),
+            clock: old.clock,
+            background: old.background,
+            inflaton: old.inflaton,
+            camera: old.camera,
+            particles: old.particles,
+        }
+    }
+}
+
+/// Layout of [`Config`] in version 1 and 2 sessions, before the `[epochs]` section
+#[derive(Serialize, Deserialize)]
+struct ConfigV2 {
+    time: TimeConfig,
+    particle: ParticleConfig,
+    camera: CameraConfig,
+    window: WindowConfig,
+    display: DisplayConfig,
+    physics: PhysicsConfig,
+    cosmology: CosmologyConfig,
+}
+
+impl From<ConfigV2> for Config {
+    fn from(old: ConfigV2) -> Self {
+        Self {
+            time: old.time,
+            particle: old.particle,
+            camera: old.camera,
+            window: old.window,
+            display: old.display,
+            physics: old.physics,
+            cosmology: old.cosmology,
+            epochs: Default::default(),
+        }
+    }
+}
+
+/// Layout of a version 3 session
+#[derive(Serialize, Deserialize)]
+struct SessionV3 {
+    config: ConfigV3,
+    clock: ClockState,
+    background: BackgroundState,
+    inflaton: InflatonState,
+    camera: CameraPose,
+    particles: ParticleStore,
+}
+
+impl From<SessionV3> for Session {
+    fn from(old: SessionV3) -> Self {
+        Self {
+            config: old.config.into(),
+            clock: old.clock,
+            background: old.background,
+            inflaton: old.inflaton,
+            camera: old.camera,
+            particles: old.particles,
+        }
+    }
+}
+
+/// Layout of [`Config`] in version 3 sessions
+#[derive(Serialize, Deserialize)]
+struct ConfigV3 {
+    time: TimeConfig,
+    particle: ParticleConfig,
+    camera: CameraConfig,
+    window: WindowConfig,
+    display: DisplayConfig,
+    physics: PhysicsConfig,
+    cosmology: CosmologyConfig,
+    epochs: EpochConfigV3,
+}
+
+impl From<ConfigV3> for Config {
+    fn from(old: ConfigV3) -> Self {
+        Self {
+            time: old.time,
+            particle: old.particle,
+            camera: old.camera,
+            window: old.window,
+            display: old.display,
+            physics: old.physics,
+            cosmology: old.cosmology,
+            epochs: old.epochs.into(),
+        }
+    }
+}
+
+/// Layout of [`EpochConfig`] in version 3 sessions, before the start of BBN
+#[derive(Serialize, Deserialize)]
+struct EpochConfigV3 {
+    inflation_end_epsilon: f64,
+    qcd_temperature_mev: f64,
+    bbn_end_temperature_mev: f64,
+    recombination_ionization: f64,
+    star_formation_redshift: f64,
+    reionization_redshift: f64,
+}
+
+impl From<EpochConfigV3> for EpochConfig {
+    fn from(old: EpochConfigV3) -> Self {
+        Self {
+            inflation_end_epsilon: old.inflation_end_epsilon,
+            qcd_temperature_mev: old.qcd_temperature_mev,
+            bbn_end_temperature_mev: old.bbn_end_temperature_mev,
+            recombination_ionization: old.recombination_ionization,
+            star_formation_redshift: old.star_formation_redshift,
+            reionization_redshift: old.reionization_redshift,
+            ..Default::default()
+        }
+    }
+}
+
+/// Simulation clock state
+///
+/// See [`SimulationClock::state`](crate::time::SimulationClock::state) and
+/// [`SimulationClock::from_state`](crate::time::SimulationClock::from_state).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClockState {
+    /// Cosmic time in years
+    pub years: f64,
+    /// Linear playback speed
+    pub speed: f64,
+    /// Stepping mode
+    pub mode: TimeMode,
+    /// Decades per second in logarithmic mode
+    pub decades_per_second: f64,
+    /// Whether playback is paused
+    pub paused: bool,
+}
+
+impl Default for ClockState {
+    fn default() -> Self {
+        crate::time::SimulationClock::new().state()
+    }
+}
+
+/// Cosmological background state
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundState {
+    /// Scale factor a
+    pub scale_factor: f64,
+    /// Scale factor before the most recent fixed step
+    pub previous_scale_factor: f64,
+    /// Time derivative ȧ in GeV
+    pub scale_factor_derivative: f64,
+    /// Integrated cosmic time in GeV⁻¹
+    pub time_gev_inv: f64,
+    /// Index of the current cosmic epoch, in chronological order
+    pub epoch: u8,
+    /// Temperature in Kelvin
+    pub temperature_k: f64,
+    /// Initial temperature in Kelvin
+    pub initial_temperature_k: f64,
+    /// Hubble parameter H in GeV
+    pub hubble_gev: f64,
+    /// Total energy density in GeV⁴
+    pub total_density: f64,
+    /// Matter density in GeV⁴
+    pub matter_density: f64,
+    /// Radiation density in GeV⁴
+    pub radiation_density: f64,
+    /// Dark energy density in GeV⁴
+    pub dark_energy_density: f64,
+    /// Inflaton energy density in GeV⁴
+    pub inflaton_density: f64,
+    /// Spatial curvature k (-1, 0 or +1)
+    pub curvature: i8,
+}
+
+impl Default for BackgroundState {
+    fn default() -> Self {
+        Self {
+            scale_factor: 1.0,
+            previous_scale_factor: 1.0,
+            scale_factor_derivative: 0.0,
+            time_gev_inv: 0.0,
+            epoch: 0,
+            temperature_k: 1e27,
+            initial_temperature_k: 1e27,
+            hubble_gev: 0.0,
+            total_density: 0.0,
+            matter_density: 0.0,
+            radiation_density: 0.0,
+            dark_energy_density: 0.0,
+            inflaton_density: 0.0,
+            curvature: 0,
+        }
+    }
+}
+
+/// Inflaton field state
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct InflatonState {
+    /// Field value φ
+    pub phi: f64,
+    /// Potential V(φ)
+    pub potential: f64,
+    /// dV/dφ
+    pub potential_first_derivative: f64,
+    /// d²V/dφ²
+    pub potential_second_derivative: f64,
+    /// First slow-roll parameter ε
+    pub epsilon: f64,
+    /// Second slow-roll parameter η
+    pub eta: f64,
+}
+
+/// Camera pose and controller angles
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CameraPose {
+    /// Whether the orbit camera (rather than free flight) is active
+    pub orbit: bool,
+    /// World-space position
+    pub translation: [f32; 3],
+    /// Orientation quaternion (x, y, z, w)
+    pub rotation: [f32; 4],
+    /// Point the orbit camera rotates around
+    pub orbit_target: [f32; 3],
+    /// Orbit camera distance from its target
+    pub orbit_distance: f32,
+    /// Orbit camera yaw in radians
+    pub orbit_yaw: f32,
+    /// Orbit camera pitch in radians
+    pub orbit_pitch: f32,
+    /// Free-flight camera yaw in radians
+    pub free_yaw: f32,
+    /// Free-flight camera pitch in radians
+    pub free_pitch: f32,
+}
+
+impl Default for CameraPose {
+    fn default() -> Self {
+        Self {
+            orbit: false,
+            translation: [0.0; 3],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            orbit_target: [0.0; 3],
+            orbit_distance: 0.0,
+            orbit_yaw: 0.0,
+            orbit_pitch: 0.0,
+            free_yaw: 0.0,
+            free_pitch: 0.0,
+        }
+    }
+}
+
+/// Render-level particle arrays saved by version 1 sessions
+#[derive(Serialize, Deserialize)]
+struct ParticleArraysV1 {
+    positions: Vec<[f32; 3]>,
+    velocities: Vec<[f32; 3]>,
+    initial_positions: Vec<[f32; 3]>,
+    initial_velocities: Vec<[f32; 3]>,
+    colors: Vec<[f32; 4]>,
+    sizes: Vec<f32>,
+}
+
+impl ParticleArraysV1 {
+    /// Converts to a store of unit-mass baryons, keeping the current and initial
+    /// state of each particle
+    ///
+    /// Entries beyond the shortest kinematic array are dropped.
+    fn into_store(self) -> ParticleStore {
+        let to_f64 = |v: [f32; 3]| Vec3::from_array(v).as_dvec3();
+        let count = self
+            .positions
+            .len()
+            .min(self.velocities.len())
+            .min(self.initial_positions.len())
+            .min(self.initial_velocities.len());
+
+        let mut store = ParticleStore::with_capacity(count);
+        for i in 0..count {
+            store.push(
+                to_f64(self.initial_positions[i]),
+                to_f64(self.initial_velocities[i]),
+                1.0,
+                Species::Baryon,
+            );
+        }
+        for i in 0..count {
+            store.positions_mut()[i] = to_f64(self.positions[i]);
+            store.velocities_mut()[i] = to_f64(self.velocities[i]);
+        }
+        store
+    }
+}
+
+/// Error produced while saving or loading a session
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionError {
+    /// The file could not be opened, created or written
+    Io {
+        /// Path of the file
+        path: PathBuf,
+        /// Underlying I/O error message
+        message: String,
+    },
+    /// The data does not start with [`SESSION_MAGIC`]
+    NotASession,
+    /// The file was written by a newer, unsupported format version
+    UnsupportedVersion(u32),
+    /// The payload could not be encoded or decoded
+    Corrupt(String),
+    /// The payload decoded but is inconsistent
+    Invalid(String),
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionError::Io { path, message } => {
+                write!(f, "cannot access session file {}: {}", path.display(), message)
+            }
+            SessionError::NotASession => write!(f, "not a genesis session file"),
+            SessionError::UnsupportedVersion(found) => write!(
+                f,
+                "session format version {} is newer than the supported version {}",
+                found, SESSION_VERSION
+            ),
+            SessionError::Corrupt(message) => write!(f, "corrupt session data: {}", message),
+            SessionError::Invalid(message) => write!(f, "invalid session: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::math::DVec3;
+
+    fn sample_session() -> Session {
+        let mut config = Config::default();
+        config.physics.seed = Some(42);
+
+        let mut session = Session::new(config);
+        session.clock.years = 3.8e5;
+        session.clock.paused = true;
+        session.background.scale_factor = 9.1e-4;
+        session.inflaton.phi = 15.0;
+        session.camera.orbit = true;
+        session.camera.translation = [1.0, 2.0, 3.0];
+        session.particles.push(DVec3::ZERO, DVec3::new(0.5, 0.0, 0.0), 1.0, Species::Baryon);
+        session.particles.push(DVec3::X, DVec3::new(0.0, 0.5, 0.0), 5.0, Species::DarkMatter);
+        session.particles.positions_mut()[0] = DVec3::new(0.25, 0.0, 0.0);
+        session
+    }
+
+    #[test]
+    fn test_session_round_trip() {
+        let session = sample_session();
+        let bytes = session.to_bytes().unwrap();
+        assert_eq!(&bytes[..8], &SESSION_MAGIC);
+        assert_eq!(Session::from_bytes(&bytes).unwrap(), session);
+    }
+
+    #[test]
+    fn test_session_rejects_foreign_and_newer_files() {
+        assert_eq!(Session::from_bytes(b"schema_version = 1"), Err(SessionError::NotASession));
+
+        let mut bytes = sample_session().to_bytes().unwrap();
+        bytes[8..12].copy_from_slice(&(SESSION_VERSION + 1).to_le_bytes());
+        assert_eq!(
+            Session::from_bytes(&bytes),
+            Err(SessionError::UnsupportedVersion(SESSION_VERSION + 1))
+        );
+    }
+
+    #[test]
+    fn test_session_rejects_invalid_config() {
+        let mut session = sample_session();
+        session.config.particle.base_size = -1.0;
+        let bytes = session.to_bytes().unwrap();
+        assert!(matches!(Session::from_bytes(&bytes), Err(SessionError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_version_1_session_is_migrated() {
+        let current = sample_session();
+        let old = SessionV1 {
+            config: config_v2(&current.config),
+            clock: current.clock,
+            background: current.background,
+            inflaton: current.inflaton,
+            camera: current.camera,
+            particles: ParticleArraysV1 {
+                positions: vec![[2.0, 0.0, 0.0]],
+                velocities: vec![[1.0, 0.0, 0.0]],
+                initial_positions: vec![[0.0; 3]],
+                initial_velocities: vec![[1.0, 0.0, 0.0]],
+                colors: vec![[1.0; 4]],
+                sizes: vec![0.5],
+            },
+        };
+        let mut bytes = SESSION_MAGIC.to_vec();
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend(bincode::serialize(&old).unwrap());
+
+        let migrated = Session::from_bytes(&bytes).unwrap();
+        assert_eq!(migrated.clock, current.clock);
+        assert_eq!(migrated.particles.len(), 1);
+        assert_eq!(migrated.particles.positions()[0], DVec3::new(2.0, 0.0, 0.0));
+        assert_eq!(migrated.particles.initial_positions()[0], DVec3::ZERO);
+        assert_eq!(migrated.particles.species()[0], Species::Baryon);
+    }
+
+    #[test]
+    fn test_version_2_session_gets_default_epochs() {
+        let mut current = sample_session();
+        current.config.epochs.star_formation_redshift = 20.0;
+        let old = SessionV2 {
+            config: config_v2(&current.config),
+            clock: current.clock,
+            background: current.background,
+            inflaton: current.inflaton,
+            camera: current.camera,
+            particles: current.particles.clone(),
+        };
+        let mut bytes = SESSION_MAGIC.to_vec();
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        bytes.extend(bincode::serialize(&old).unwrap());
+
+        let migrated = Session::from_bytes(&bytes).unwrap();
+        assert_eq!(migrated.config.physics.seed, Some(42));
+        assert_eq!(migrated.config.epochs, EpochConfig::default());
+        assert_eq!(migrated.particles, current.particles);
+    }
+
+    #[test]
+    fn test_version_3_session_gets_default_bbn_start() {
+        let mut current = sample_session();
+        current.config.epochs.qcd_temperature_mev = 170.0;
+        let epochs = &current.config.epochs;
+        let old = SessionV3 {
+            config: ConfigV3 {
+                time: current.config.time.clone(),
+                particle: current.config.particle.clone(),
+                camera: current.config.camera.clone(),
+                window: current.config.window.clone(),
+                display: current.config.display.clone(),
+                physics: current.config.physics.clone(),
+                cosmology: current.config.cosmology.clone(),
+                epochs: EpochConfigV3 {
+                    inflation_end_epsilon: epochs.inflation_end_epsilon,
+                    qcd_temperature_mev: epochs.qcd_temperature_mev,
+                    bbn_end_temperature_mev: epochs.bbn_end_temperature_mev,
+                    recombination_ionization: epochs.recombination_ionization,
+                    star_formation_redshift: epochs.star_formation_redshift,
+                    reionization_redshift: epochs.reionization_redshift,
+                },
+            },
+            clock: current.clock,
+            background: current.background,
+            inflaton: current.inflaton,
+            camera: current.camera,
+            particles: current.particles.clone(),
+        };
+        let mut bytes = SESSION_MAGIC.to_vec();
+        bytes.extend_from_slice(&3u32.to_le_bytes());
+        bytes.extend(bincode::serialize(&old).unwrap());
+
+        let migrated = Session::from_bytes(&bytes).unwrap();
+        assert_eq!(migrated, current);
+    }
+
+    #[test]
+    fn test_version_4_session_skips_hadron_epoch() {
+        let mut current = sample_session();
+        current.background.epoch = HADRON_EPOCH_INDEX;
+        let mut bytes = SESSION_MAGIC.to_vec();
+        bytes.extend_from_slice(&4u32.to_le_bytes());
+        bytes.extend(bincode::serialize(&current).unwrap());
+
+        // Nucleosynthesis moved up by one
+        let migrated = Session::from_bytes(&bytes).unwrap();
+        assert_eq!(migrated.background.epoch, HADRON_EPOCH_INDEX + 1);
+
+        current.background.epoch = HADRON_EPOCH_INDEX - 1;
+        let mut bytes = SESSION_MAGIC.to_vec();
+        bytes.extend_from_slice(&4u32.to_le_bytes());
+        bytes.extend(bincode::serialize(&current).unwrap());
+        assert_eq!(Session::from_bytes(&bytes).unwrap(), current);
+    }
+
+    fn config_v2(config: &Config) -> ConfigV2 {
+        ConfigV2 {
+            time: config.time.clone(),
+            particle: config.particle.clone(),
+            camera: config.camera.clone(),
+            window: config.window.clone(),
+            display: config.display.clone(),
+            physics: config.physics.clone(),
+            cosmology: config.cosmology.clone(),
+        }
+    }
+}
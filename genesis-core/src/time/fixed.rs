@@ -0,0 +1,87 @@
+//! Fixed-timestep scheduling for deterministic physics
+//!
+//! The simulation clock and every physics system run in Bevy's `FixedUpdate`
+//! schedule, which Bevy's fixed-time accumulator runs zero or more times per frame
+//! so that each run covers exactly one [`FixedTimestep`]. Physics results therefore
+//! depend only on the seed, the inputs and the number of elapsed steps, not on the
+//! frame rate or vsync. Two runs with the same inputs produce bit-identical state.
+//!
+//! Within a fixed step, physics systems split the step into
+//! [`FixedTimestep::substeps`] equal integration substeps. Rendering runs once per
+//! frame, between two fixed steps, and interpolates between the previous and current
+//! physics state with [`interpolation_alpha`].
+
+use std::time::Duration;
+
+use bevy::prelude::{Fixed, Res, ResMut, Resource, SystemSet, Time};
+
+use crate::config::PhysicsConfig;
+
+/// Ordered system sets of the `FixedUpdate` schedule
+///
+/// [`SimulationSet::Clock`] advances the [`SimulationClock`](super::SimulationClock)
+/// by one fixed step; [`SimulationSet::Physics`] then integrates that step.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimulationSet {
+    /// Ticks the simulation clock
+    Clock,
+    /// Integrates physics over the step taken by the clock
+    Physics,
+}
+
+/// Length and subdivision of the fixed physics step
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct FixedTimestep {
+    /// Fixed steps per second of real time
+    pub hz: f64,
+    /// Integration substeps per fixed step
+    pub substeps: u32,
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::from_config(&PhysicsConfig::default())
+    }
+}
+
+impl FixedTimestep {
+    /// Creates the timestep from the `[physics]` config section
+    pub fn from_config(config: &PhysicsConfig) -> Self {
+        Self {
+            hz: config.timestep_hz,
+            substeps: config.substeps,
+        }
+    }
+
+    /// Real time covered by one fixed step
+    ///
+    /// Rounded to whole nanoseconds, which is the resolution of Bevy's accumulator.
+    pub fn step(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.hz)
+    }
+
+    /// Number of integration substeps per fixed step (at least 1)
+    pub fn substeps(&self) -> u32 {
+        self.substeps.max(1)
+    }
+}
+
+/// Fraction of a fixed step accumulated since the last one, in `[0, 1)`
+///
+/// Render systems blend the previous and current physics states with this factor,
+/// e.g. [`SimulationClock::interpolated_years`](super::SimulationClock::interpolated_years).
+pub fn interpolation_alpha(time: &Time<Fixed>) -> f64 {
+    time.overstep_fraction_f64()
+}
+
+/// System that applies a changed [`FixedTimestep`] to Bevy's fixed-time accumulator
+pub(crate) fn apply_fixed_timestep(
+    timestep: Res<FixedTimestep>,
+    fixed_time: Option<ResMut<Time<Fixed>>>,
+) {
+    if let Some(mut fixed_time) = fixed_time {
+        if fixed_time.timestep() != timestep.step() {
+            fixed_time.set_timestep(timestep.step());
+        }
+    }
+}
@@ -3,28 +3,41 @@
 //! Defines resource for tracking and accumulating cosmic time.
 //! Includes SECONDS_PER_YEAR constant and time scaling via acceleration factor.
 //!
-//! # Dual Time System
+//! Cosmic time can advance linearly (scaled by the acceleration factor) or
+//! logarithmically (a fixed number of decades per real second, see [`TimeMode`]).
+//! With linear stepping even a 1x acceleration jumps past the first 10⁻³² years
+//! in one frame, so logarithmic stepping is needed to actually play through
+//! inflation and the Planck boundary.
 //!
-//! The application uses two separate time resources that are synchronized:
+//! # Simulation Clock
 //!
-//! - **TimeAccumulator.years** (this module): Tracks accumulated cosmic time in years,
-//!   updated each frame via [`add_time()`] based on delta time and acceleration factor.
+//! [`SimulationClock`] is the single authoritative source of cosmic time. It owns the
+//! current time, the pause state, the playback speed and any pending seek. Other
+//! crates read it; controls such as the timeline UI only send
+//! [`SimulationCommand`] events (play, pause, reset, seek, set speed, step ticks),
+//! which [`TimeIntegrationPlugin`] applies at the start of the next frame. The
+//! physics crate therefore runs without the UI crate being loaded.
 //!
-//! - **CosmicTime.cosmic_time** (genesis-ui): Stores timeline position used by the slider UI,
-//!   updated by timeline scrubbing in [`timeline_panel_ui()`](genesis_ui::timeline::timeline_panel_ui).
-//!
-//! The [`sync_time_resources()`](genesis_ui::timeline::sync_time_resources) system synchronizes:
-//! - TimeAccumulator's paused state with PlaybackState.playing
-//! - PlaybackState.speed to TimeAccumulator.acceleration (logarithmic mapping)
-//!
-//! Timeline scrubbing in [`timeline_panel_ui()`](genesis_ui::timeline::timeline_panel_ui) updates
-//! both CosmicTime.cosmic_time and TimeAccumulator.years, ensuring the two resources remain
-//! synchronized when the user scrubs the timeline.
+//! The clock ticks on the fixed physics timestep rather than once per frame; see
+//! [`fixed`] for the schedule and render interpolation.
+
+pub mod fixed;
 
-use bevy::prelude::{Commands, Plugin, Res, ResMut, Resource, Startup, Update};
+use std::fmt;
+use std::str::FromStr;
+
+use bevy::prelude::{
+    EventReader, First, FixedUpdate, IntoSystemConfigs, IntoSystemSetConfigs, Plugin, Res, ResMut,
+    Resource,
+};
 use bevy::time::Time;
+use serde::{Deserialize, Serialize};
+
+use crate::events::{ScrubbingEvent, SimulationCommand};
+use crate::session::ClockState;
+use crate::units::{Seconds, Years};
 
-use crate::events::ScrubbingEvent;
+pub use fixed::{interpolation_alpha, FixedTimestep, SimulationSet};
 
 /// Number of seconds in a cosmic year (365.25 days)
 pub const SECONDS_PER_YEAR: f64 = 31_557_600.0;
@@ -56,6 +69,54 @@ pub const INFLATION_END_YEARS: f64 = 1e-32;
 /// Planck epoch time (~10⁻³⁶ years / 10⁻²⁸s)
 pub const PLANCK_EPOCH_YEARS: f64 = 1e-36;
 
+/// Earliest cosmic time reached by logarithmic stepping (~10⁻⁴⁴ years).
+///
+/// log(t) is undefined at t = 0, so [`TimeMode::Logarithmic`] starts from here.
+pub const LOG_TIME_FLOOR_YEARS: f64 = INFLATION_START_YEARS;
+
+/// How real time is mapped onto cosmic time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeMode {
+    /// Cosmic time advances by `delta_seconds × acceleration` (uniform in t)
+    #[default]
+    Linear,
+    /// log₁₀(t) advances by `delta_seconds × decades_per_second` (uniform in log t),
+    /// so every era from 10⁻⁴⁴ years to the present gets a similar share of frames
+    Logarithmic,
+}
+
+impl TimeMode {
+    /// Returns the name used in configuration files
+    pub fn name(self) -> &'static str {
+        match self {
+            TimeMode::Linear => "linear",
+            TimeMode::Logarithmic => "logarithmic",
+        }
+    }
+}
+
+impl fmt::Display for TimeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for TimeMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().trim_matches('"').to_ascii_lowercase().as_str() {
+            "linear" => Ok(TimeMode::Linear),
+            "logarithmic" | "log" => Ok(TimeMode::Logarithmic),
+            other => Err(format!(
+                "expected \"linear\" or \"logarithmic\", got \"{}\"",
+                other
+            )),
+        }
+    }
+}
+
 /// Converts seconds to cosmic years.
 ///
 /// # Arguments
@@ -66,8 +127,10 @@ pub const PLANCK_EPOCH_YEARS: f64 = 1e-36;
 ///
 /// # Formula
 /// `years = seconds / SECONDS_PER_YEAR`
+///
+/// Prefer the typed conversion `Years::from(Seconds(..))` from [`crate::units`].
 pub fn seconds_to_years(seconds: f64) -> f64 {
-    seconds / SECONDS_PER_YEAR
+    Years::from(Seconds(seconds)).value()
 }
 
 /// Converts minutes to cosmic years.
@@ -81,67 +144,136 @@ pub fn seconds_to_years(seconds: f64) -> f64 {
 /// # Formula
 /// `years = (minutes * SECONDS_PER_MINUTE) / SECONDS_PER_YEAR`
 pub fn minutes_to_years(minutes: f64) -> f64 {
-    (minutes * SECONDS_PER_MINUTE) / SECONDS_PER_YEAR
+    seconds_to_years(minutes * SECONDS_PER_MINUTE)
 }
 
-#[derive(Resource)]
-pub struct TimeAccumulator {
-    /// Accumulated cosmic time in years (f64 for precision)
-    pub years: f64,
-    /// Time acceleration factor (1x to 10^12x)
-    pub acceleration: f64,
-    /// Pause state - when true, cosmic time does not accumulate
+/// Minimum playback speed (real time)
+pub const MIN_SPEED: f64 = 1.0;
+
+/// Maximum playback speed (10¹² × real time)
+pub const MAX_SPEED: f64 = 1e12;
+
+/// The authoritative simulation clock
+///
+/// Holds cosmic time in years (f64 for precision) together with the pause state,
+/// playback speed and stepping mode. Seeks are queued with [`SimulationClock::seek_to`]
+/// and take effect at the next [`SimulationClock::tick`], so every system of a frame
+/// sees the same time. The time before the latest tick is kept for render
+/// interpolation.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SimulationClock {
+    /// Current cosmic time in years
+    years: f64,
+    /// Cosmic time in years before the most recent tick
+    previous_years: f64,
+    /// Playback speed (1x to 10^12x), used in [`TimeMode::Linear`]
+    speed: f64,
+    /// How real time maps onto cosmic time
+    mode: TimeMode,
+    /// Decades of cosmic time per real second, used in [`TimeMode::Logarithmic`]
+    decades_per_second: f64,
+    /// Cosmic years advanced by the most recent tick (0 after a seek or while paused)
+    delta_years: f64,
+    /// Pending seek target in years
+    seek_target: Option<f64>,
+    /// Pause state - when true, cosmic time does not advance
     paused: bool,
+    /// Fixed steps still to be advanced while paused
+    pending_steps: u32,
 }
 
-impl TimeAccumulator {
+impl SimulationClock {
+    /// Creates a running clock at t = 0 with 1x linear playback
     pub fn new() -> Self {
         Self {
             years: 0.0,
-            acceleration: 1.0,
+            previous_years: 0.0,
+            speed: MIN_SPEED,
+            mode: TimeMode::Linear,
+            decades_per_second: 1.0,
+            delta_years: 0.0,
+            seek_target: None,
             paused: false,
+            pending_steps: 0,
         }
     }
 
-    pub fn reset(&mut self) {
-        self.years = 0.0;
-        self.paused = false;
+    /// Current cosmic time in years
+    pub fn years(&self) -> f64 {
+        self.years
     }
 
-    pub fn set_acceleration(&mut self, accel: f64) {
-        self.acceleration = accel.clamp(1.0, 1e12);
-    }
-
-    /// Adds elapsed real time to the cosmic time accumulator.
+    /// Cosmic time blended between the previous and current tick
     ///
     /// # Arguments
-    /// * `delta_seconds` - Elapsed real time in seconds since the last frame
-    ///
-    /// The elapsed time is converted to cosmic years using the current
-    /// acceleration factor. Higher acceleration values result in faster
-    /// passage of cosmic time.
-    ///
-    /// Note: Time accumulation is skipped when `paused` is true.
-    pub fn add_time(&mut self, delta_seconds: f64) {
-        if self.paused {
-            return;
-        }
-        let delta_years = delta_seconds * self.acceleration / SECONDS_PER_YEAR;
-        self.years += delta_years;
+    /// * `alpha` - Progress towards the next tick in `[0, 1]`, see [`interpolation_alpha`]
+    pub fn interpolated_years(&self, alpha: f64) -> f64 {
+        self.previous_years + (self.years - self.previous_years) * alpha
     }
 
-    /// Pauses cosmic time accumulation.
+    /// Playback speed factor
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Stepping mode
+    pub fn mode(&self) -> TimeMode {
+        self.mode
+    }
+
+    /// Decades of cosmic time per real second in logarithmic mode
+    pub fn decades_per_second(&self) -> f64 {
+        self.decades_per_second
+    }
+
+    /// Cosmic time advanced by the most recent tick
     ///
-    /// When paused, the `add_time` method will not accumulate time.
-    pub fn pause(&mut self) {
-        self.paused = true;
+    /// Zero while paused and on the tick that applies a seek, so integrators never
+    /// try to integrate across a jump.
+    pub fn delta(&self) -> Years {
+        Years(self.delta_years)
+    }
+
+    /// Seek target waiting to be applied, if any
+    pub fn seek_target(&self) -> Option<f64> {
+        self.seek_target
     }
 
-    /// Resumes cosmic time accumulation.
+    /// Returns whether cosmic time is currently paused.
     ///
-    /// When resumed, the `add_time` method will accumulate time normally.
-    pub fn resume(&mut self) {
+    /// # Returns
+    /// `true` if paused, `false` if running
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Fixed steps still to be advanced by [`SimulationClock::step_ticks`]
+    pub fn pending_steps(&self) -> u32 {
+        self.pending_steps
+    }
+
+    /// Sets the playback speed, clamped to [`MIN_SPEED`]..=[`MAX_SPEED`]
+    pub fn set_speed(&mut self, speed: f64) {
+        self.speed = speed.clamp(MIN_SPEED, MAX_SPEED);
+    }
+
+    /// Sets the stepping mode and the logarithmic rate in decades per second
+    pub fn set_mode(&mut self, mode: TimeMode, decades_per_second: f64) {
+        self.mode = mode;
+        self.decades_per_second = decades_per_second;
+    }
+
+    /// Resumes cosmic time, cancelling any remaining fixed steps.
+    pub fn play(&mut self) {
         self.paused = false;
+        self.pending_steps = 0;
+    }
+
+    /// Pauses cosmic time.
+    ///
+    /// When paused, [`SimulationClock::tick`] only applies pending seeks.
+    pub fn pause(&mut self) {
+        self.paused = true;
     }
 
     /// Toggles the pause state.
@@ -151,47 +283,313 @@ impl TimeAccumulator {
         self.paused = !self.paused;
     }
 
-    /// Returns whether cosmic time accumulation is currently paused.
+    /// Requests a jump to `years` (clamped to t ≥ 0) at the next tick
+    pub fn seek_to(&mut self, years: f64) {
+        self.seek_target = Some(years.max(0.0));
+    }
+
+    /// Requests a jump back to the Big Bang and pauses the clock
+    pub fn reset(&mut self) {
+        self.seek_to(0.0);
+        self.paused = true;
+        self.pending_steps = 0;
+    }
+
+    /// Pauses the clock and advances it by `ticks` more fixed steps
     ///
-    /// # Returns
-    /// `true` if paused, `false` if running
-    pub fn is_paused(&self) -> bool {
-        self.paused
+    /// Each following tick advances as if running, until the steps are used up. The
+    /// clock ticks once per `FixedUpdate`, so a rendered frame may advance by zero or
+    /// several steps. Calling this again while stepping adds to the remaining steps.
+    pub fn step_ticks(&mut self, ticks: u32) {
+        self.paused = true;
+        self.pending_steps = self.pending_steps.saturating_add(ticks);
+    }
+
+    /// Applies a playback command
+    pub fn apply(&mut self, command: SimulationCommand) {
+        match command {
+            SimulationCommand::Play => self.play(),
+            SimulationCommand::Pause => self.pause(),
+            SimulationCommand::Reset => self.reset(),
+            SimulationCommand::SeekTo(years) => self.seek_to(years),
+            SimulationCommand::SetSpeed(speed) => self.set_speed(speed),
+            SimulationCommand::StepTicks(ticks) => self.step_ticks(ticks),
+        }
+    }
+
+    /// Snapshot of the clock for a saved session
+    ///
+    /// Pending seeks and fixed steps are transient requests and are not captured.
+    pub fn state(&self) -> ClockState {
+        ClockState {
+            years: self.years,
+            speed: self.speed,
+            mode: self.mode,
+            decades_per_second: self.decades_per_second,
+            paused: self.paused,
+        }
+    }
+
+    /// Recreates a clock from a saved session snapshot
+    pub fn from_state(state: &ClockState) -> Self {
+        let mut clock = Self::new();
+        clock.years = state.years.max(0.0);
+        clock.previous_years = clock.years;
+        clock.set_speed(state.speed);
+        clock.set_mode(state.mode, state.decades_per_second);
+        clock.paused = state.paused;
+        clock
+    }
+
+    /// Advances the clock by a step of real time.
+    ///
+    /// # Arguments
+    /// * `delta_seconds` - Elapsed real time in seconds (one fixed step in the app)
+    ///
+    /// A pending seek is applied instead of advancing. Otherwise, in
+    /// [`TimeMode::Linear`] the elapsed time is converted to cosmic years using the
+    /// playback speed. Higher speeds result in faster passage of cosmic time.
+    ///
+    /// In [`TimeMode::Logarithmic`] the elapsed time advances log₁₀ of cosmic time by
+    /// `decades_per_second` per real second, starting from [`LOG_TIME_FLOOR_YEARS`].
+    ///
+    /// Note: Time does not advance when the clock is paused, except for fixed steps
+    /// requested with [`SimulationClock::step_ticks`].
+    pub fn tick(&mut self, delta_seconds: f64) {
+        self.delta_years = 0.0;
+        self.previous_years = self.years;
+        if let Some(target) = self.seek_target.take() {
+            // Never interpolate across a jump
+            self.years = target;
+            self.previous_years = target;
+            return;
+        }
+        if self.paused {
+            if self.pending_steps == 0 {
+                return;
+            }
+            self.pending_steps -= 1;
+        }
+        let previous = self.years;
+        match self.mode {
+            TimeMode::Linear => {
+                self.years += Years::from(Seconds(delta_seconds * self.speed)).value();
+            }
+            TimeMode::Logarithmic => {
+                let log_years = self.years.max(LOG_TIME_FLOOR_YEARS).log10()
+                    + delta_seconds * self.decades_per_second;
+                self.years = 10f64.powf(log_years);
+            }
+        }
+        self.delta_years = self.years - previous;
     }
 }
 
-impl Default for TimeAccumulator {
+impl Default for SimulationClock {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// Plugin for integrating cosmic time accumulation with Bevy's time system.
+/// Plugin for integrating cosmic time with Bevy's time system.
 ///
-/// Initializes the `TimeAccumulator` resource and adds a system that updates
-/// the cosmic time each frame based on Bevy's delta time.
+/// Initializes the [`SimulationClock`] and [`FixedTimestep`] resources (unless the
+/// application inserted them already) and registers [`SimulationCommand`]. At the
+/// start of every frame it applies the pending commands; the clock itself ticks once
+/// per fixed step in [`SimulationSet::Clock`], before [`SimulationSet::Physics`].
 pub struct TimeIntegrationPlugin;
 
 impl Plugin for TimeIntegrationPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        // Initialize the TimeAccumulator resource with default values
-        app.add_systems(Startup, initialize_time_accumulator);
+        app.init_resource::<SimulationClock>();
+        app.init_resource::<FixedTimestep>();
+        app.add_event::<SimulationCommand>();
 
-        // Add update system to accumulate cosmic time each frame
-        app.add_systems(Update, update_cosmic_time);
+        // Apply playback commands and the physics timestep before the fixed steps run
+        app.add_systems(First, (apply_simulation_commands, fixed::apply_fixed_timestep));
+
+        // Advance the clock by one fixed step before any physics system reads it
+        app.configure_sets(FixedUpdate, (SimulationSet::Clock, SimulationSet::Physics).chain());
+        app.add_systems(FixedUpdate, update_cosmic_time.in_set(SimulationSet::Clock));
 
         // Register timeline scrubbing event
         app.add_event::<ScrubbingEvent>();
     }
 }
 
-/// Startup system that initializes the TimeAccumulator resource.
-fn initialize_time_accumulator(mut commands: Commands) {
-    commands.insert_resource(TimeAccumulator::default());
+/// System that applies every [`SimulationCommand`] sent since the last frame, in order.
+fn apply_simulation_commands(
+    mut commands: EventReader<SimulationCommand>,
+    mut clock: ResMut<SimulationClock>,
+) {
+    for command in commands.read() {
+        clock.apply(*command);
+    }
 }
 
-/// Update system that reads Bevy's delta time and adds it to the cosmic time accumulator.
-fn update_cosmic_time(time: Res<Time>, mut time_accumulator: ResMut<TimeAccumulator>) {
-    let delta_seconds = time.delta_secs() as f64;
-    time_accumulator.add_time(delta_seconds);
+/// System that reads Bevy's delta time and ticks the simulation clock.
+///
+/// Runs in `FixedUpdate`, where `Time` is the fixed clock and the delta is always one
+/// [`FixedTimestep`].
+fn update_cosmic_time(time: Res<Time>, mut clock: ResMut<SimulationClock>) {
+    clock.tick(time.delta_secs() as f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_step_uses_speed() {
+        let mut clock = SimulationClock::new();
+        clock.set_speed(SECONDS_PER_YEAR);
+        clock.tick(2.0);
+        assert!((clock.years() - 2.0).abs() < 1e-12);
+        assert!((clock.delta().value() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_logarithmic_step_visits_every_decade() {
+        let mut clock = SimulationClock::new();
+        clock.set_mode(TimeMode::Logarithmic, 1.0);
+
+        // 60 frames per second for one second advances one decade from the floor
+        for _ in 0..60 {
+            clock.tick(1.0 / 60.0);
+            assert!(clock.years() < INFLATION_END_YEARS, "stepped past inflation");
+        }
+        let decades = (clock.years() / LOG_TIME_FLOOR_YEARS).log10();
+        assert!((decades - 1.0).abs() < 1e-9, "got {} decades", decades);
+
+        clock.pause();
+        clock.tick(1.0);
+        assert_eq!(clock.delta(), Years::ZERO);
+    }
+
+    #[test]
+    fn test_seek_applies_on_next_tick_without_delta() {
+        let mut clock = SimulationClock::new();
+        clock.set_speed(1e6);
+        clock.seek_to(3.8e5);
+        assert_eq!(clock.years(), 0.0, "seek should wait for the next tick");
+
+        clock.tick(1.0);
+        assert_eq!(clock.years(), 3.8e5);
+        assert_eq!(clock.delta(), Years::ZERO);
+        assert_eq!(clock.seek_target(), None);
+
+        clock.reset();
+        clock.tick(1.0);
+        assert_eq!(clock.years(), 0.0);
+        assert!(clock.is_paused());
+    }
+
+    #[test]
+    fn test_step_ticks_advances_while_paused() {
+        let mut clock = SimulationClock::new();
+        clock.set_speed(SECONDS_PER_YEAR);
+        clock.step_ticks(2);
+        assert!(clock.is_paused());
+
+        for _ in 0..3 {
+            clock.tick(1.0);
+        }
+        assert!((clock.years() - 2.0).abs() < 1e-12);
+        assert_eq!(clock.delta(), Years::ZERO);
+        assert_eq!(clock.pending_steps(), 0);
+    }
+
+    #[test]
+    fn test_clock_state_round_trip() {
+        let mut clock = SimulationClock::new();
+        clock.set_mode(TimeMode::Logarithmic, 2.5);
+        clock.set_speed(1e6);
+        clock.seek_to(3.8e5);
+        clock.tick(0.0);
+        clock.pause();
+
+        let restored = SimulationClock::from_state(&clock.state());
+        assert_eq!(restored.state(), clock.state());
+        assert_eq!(restored.years(), 3.8e5);
+        assert_eq!(restored.interpolated_years(0.5), 3.8e5);
+        assert!(restored.is_paused());
+    }
+
+    #[test]
+    fn test_commands_are_applied_at_frame_start() {
+        let mut app = bevy::prelude::App::new();
+        app.add_plugins(TimeIntegrationPlugin);
+
+        app.world_mut().send_event(SimulationCommand::SetSpeed(5e6));
+        app.world_mut().send_event(SimulationCommand::SeekTo(3.8e5));
+        app.world_mut().send_event(SimulationCommand::Pause);
+        app.update();
+
+        let clock = app.world().resource::<SimulationClock>();
+        assert_eq!(clock.seek_target(), Some(3.8e5));
+        assert_eq!(clock.speed(), 5e6);
+        assert!(clock.is_paused());
+
+        app.world_mut().send_event(SimulationCommand::Reset);
+        app.update();
+        assert_eq!(app.world().resource::<SimulationClock>().seek_target(), Some(0.0));
+    }
+
+    /// Runs one second of real time split into frames of `frame_seconds`
+    fn run_one_second(frame_seconds: f64) -> SimulationClock {
+        use bevy::time::{TimePlugin, TimeUpdateStrategy};
+        use std::time::Duration;
+
+        let mut app = bevy::prelude::App::new();
+        app.add_plugins((TimePlugin, TimeIntegrationPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(frame_seconds)))
+            .insert_resource(FixedTimestep { hz: 50.0, substeps: 1 });
+        app.world_mut().send_event(SimulationCommand::SetSpeed(1e9));
+
+        // The first update only starts the clocks
+        for _ in 0..=(1.0 / frame_seconds).round() as usize {
+            app.update();
+        }
+        app.world().resource::<SimulationClock>().clone()
+    }
+
+    #[test]
+    fn test_clock_is_independent_of_frame_rate() {
+        let slow = run_one_second(1.0 / 25.0);
+        let fast = run_one_second(1.0 / 200.0);
+
+        assert!(slow.years() > 0.0);
+        assert_eq!(slow.years().to_bits(), fast.years().to_bits());
+    }
+
+    #[test]
+    fn test_interpolated_years_blends_last_tick() {
+        let mut clock = SimulationClock::new();
+        clock.set_speed(SECONDS_PER_YEAR);
+        clock.tick(1.0);
+        clock.tick(1.0);
+        assert!((clock.interpolated_years(0.25) - 1.25).abs() < 1e-12);
+
+        clock.seek_to(10.0);
+        clock.tick(1.0);
+        assert_eq!(clock.interpolated_years(0.5), 10.0);
+    }
+
+
+    #[test]
+    fn test_speed_is_clamped() {
+        let mut clock = SimulationClock::new();
+        clock.set_speed(1e15);
+        assert_eq!(clock.speed(), MAX_SPEED);
+        clock.set_speed(0.0);
+        assert_eq!(clock.speed(), MIN_SPEED);
+    }
+
+    #[test]
+    fn test_time_mode_parses_config_names() {
+        assert_eq!("logarithmic".parse::<TimeMode>(), Ok(TimeMode::Logarithmic));
+        assert_eq!("\"Linear\"".parse::<TimeMode>(), Ok(TimeMode::Linear));
+        assert!("warp".parse::<TimeMode>().is_err());
+        assert_eq!(TimeMode::Logarithmic.to_string(), "logarithmic");
+    }
 }
@@ -0,0 +1,286 @@
+//! Strongly-typed physical quantities
+//!
+//! Cosmic time is handled in seconds, years and natural units (GeV⁻¹, with
+//! ħ = c = k_B = 1) that differ by factors of up to 10³¹, so every quantity is a
+//! newtype around `f64`. Adding years to GeV⁻¹ or passing Kelvin where GeV is
+//! expected is a compile error; values change units only through the `From`
+//! conversions below.
+//!
+//! | Quantity    | Units                                     |
+//! |-------------|-------------------------------------------|
+//! | Time        | [`Seconds`], [`Years`], [`GeVInv`]        |
+//! | Temperature | [`Kelvin`], [`GeV`]                       |
+//! | Energy      | [`GeV`]                                   |
+//! | Distance    | [`Mpc`], [`GeVInv`]                       |
+//!
+//! # Examples
+//!
+//! ```
+//! use genesis_core::units::{GeVInv, Seconds, Years};
+//!
+//! let recombination = Years(380_000.0);
+//! let natural: GeVInv = recombination.into();
+//! assert!((Years::from(natural).0 - 380_000.0).abs() < 1e-6);
+//! assert_eq!(Seconds::from(Years(1.0)), Seconds(31_557_600.0));
+//! ```
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::time::SECONDS_PER_YEAR;
+
+/// Reduced Planck constant ħ in GeV·s (one GeV⁻¹ of time in seconds)
+pub const HBAR_GEV_SECONDS: f64 = 6.582_119_569e-25;
+
+/// Boltzmann constant k_B in GeV/K
+pub const BOLTZMANN_GEV_PER_KELVIN: f64 = 8.617_333_262e-14;
+
+/// ħc in GeV·m (one GeV⁻¹ of length in meters)
+pub const HBAR_C_GEV_METERS: f64 = 1.973_269_804e-16;
+
+/// Meters in one megaparsec
+pub const METERS_PER_MPC: f64 = 3.085_677_581_491_367e22;
+
+/// Defines an `f64` newtype quantity with its unit symbol and linear arithmetic
+macro_rules! quantity {
+    ($(#[$doc:meta])* $name:ident, $symbol:literal) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+        pub struct $name(pub f64);
+
+        impl $name {
+            /// Zero of this quantity
+            pub const ZERO: Self = Self(0.0);
+
+            /// Returns the value as a bare `f64` in this unit
+            pub fn value(self) -> f64 {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} {}", self.0, $symbol)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = Self;
+            fn mul(self, rhs: f64) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Mul<$name> for f64 {
+            type Output = $name;
+            fn mul(self, rhs: $name) -> $name {
+                $name(self * rhs.0)
+            }
+        }
+
+        impl Div<f64> for $name {
+            type Output = Self;
+            fn div(self, rhs: f64) -> Self {
+                Self(self.0 / rhs)
+            }
+        }
+
+        /// The ratio of two quantities in the same unit is dimensionless
+        impl Div for $name {
+            type Output = f64;
+            fn div(self, rhs: Self) -> f64 {
+                self.0 / rhs.0
+            }
+        }
+    };
+}
+
+quantity!(
+    /// Time in seconds
+    Seconds,
+    "s"
+);
+
+quantity!(
+    /// Time in years (365.25 days)
+    Years,
+    "yr"
+);
+
+quantity!(
+    /// Time or length in natural units (GeV⁻¹, ħ = c = 1)
+    GeVInv,
+    "GeV⁻¹"
+);
+
+quantity!(
+    /// Energy, or temperature, in natural units (GeV, k_B = 1)
+    GeV,
+    "GeV"
+);
+
+quantity!(
+    /// Temperature in Kelvin
+    Kelvin,
+    "K"
+);
+
+quantity!(
+    /// Distance in megaparsecs
+    Mpc,
+    "Mpc"
+);
+
+impl From<Years> for Seconds {
+    fn from(years: Years) -> Self {
+        Seconds(years.0 * SECONDS_PER_YEAR)
+    }
+}
+
+impl From<Seconds> for Years {
+    fn from(seconds: Seconds) -> Self {
+        Years(seconds.0 / SECONDS_PER_YEAR)
+    }
+}
+
+impl From<Seconds> for GeVInv {
+    fn from(seconds: Seconds) -> Self {
+        GeVInv(seconds.0 / HBAR_GEV_SECONDS)
+    }
+}
+
+impl From<GeVInv> for Seconds {
+    fn from(time: GeVInv) -> Self {
+        Seconds(time.0 * HBAR_GEV_SECONDS)
+    }
+}
+
+impl From<Years> for GeVInv {
+    fn from(years: Years) -> Self {
+        Seconds::from(years).into()
+    }
+}
+
+impl From<GeVInv> for Years {
+    fn from(time: GeVInv) -> Self {
+        Seconds::from(time).into()
+    }
+}
+
+impl From<Kelvin> for GeV {
+    fn from(temperature: Kelvin) -> Self {
+        GeV(temperature.0 * BOLTZMANN_GEV_PER_KELVIN)
+    }
+}
+
+impl From<GeV> for Kelvin {
+    fn from(temperature: GeV) -> Self {
+        Kelvin(temperature.0 / BOLTZMANN_GEV_PER_KELVIN)
+    }
+}
+
+impl From<Mpc> for GeVInv {
+    fn from(distance: Mpc) -> Self {
+        GeVInv(distance.0 * METERS_PER_MPC / HBAR_C_GEV_METERS)
+    }
+}
+
+impl From<GeVInv> for Mpc {
+    fn from(length: GeVInv) -> Self {
+        Mpc(length.0 * HBAR_C_GEV_METERS / METERS_PER_MPC)
+    }
+}
+
+/// A rate in GeV times a time in GeV⁻¹ is dimensionless (e.g. H·t)
+impl Mul<GeVInv> for GeV {
+    type Output = f64;
+    fn mul(self, rhs: GeVInv) -> f64 {
+        self.0 * rhs.0
+    }
+}
+
+/// A time in GeV⁻¹ times a rate in GeV is dimensionless
+impl Mul<GeV> for GeVInv {
+    type Output = f64;
+    fn mul(self, rhs: GeV) -> f64 {
+        self.0 * rhs.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        let relative_error = ((actual - expected) / expected).abs();
+        assert!(relative_error < 1e-12, "expected {}, got {}", expected, actual);
+    }
+
+    #[test]
+    fn test_time_conversions_round_trip() {
+        // One year is about 4.79 × 10³¹ GeV⁻¹
+        let natural = GeVInv::from(Years(1.0));
+        assert_close(natural.0, SECONDS_PER_YEAR / HBAR_GEV_SECONDS);
+        assert_close(Years::from(natural).0, 1.0);
+        assert_close(Seconds::from(GeVInv(1.0)).0, HBAR_GEV_SECONDS);
+    }
+
+    #[test]
+    fn test_temperature_conversions() {
+        // T_CMB = 2.7255 K ≈ 2.35 × 10⁻¹³ GeV
+        let cmb = GeV::from(Kelvin(2.7255));
+        assert!((cmb.0 - 2.3487e-13).abs() < 1e-16);
+        assert_close(Kelvin::from(cmb).0, 2.7255);
+    }
+
+    #[test]
+    fn test_distance_conversion() {
+        // 1 Mpc ≈ 1.564 × 10³⁸ GeV⁻¹
+        let natural = GeVInv::from(Mpc(1.0));
+        assert!((natural.0 / 1.5637e38 - 1.0).abs() < 1e-4);
+        assert_close(Mpc::from(natural).0, 1.0);
+    }
+
+    #[test]
+    fn test_arithmetic_keeps_units() {
+        let mut t = Years(1.0) + Years(2.0) * 2.0;
+        t -= Years(1.0);
+        assert_eq!(t, Years(4.0));
+        assert_eq!(t / Years(2.0), 2.0);
+        assert_eq!(GeV(1e14) * GeVInv(2e-14), 2.0);
+        assert_eq!(Kelvin(3.0).to_string(), "3 K");
+    }
+}
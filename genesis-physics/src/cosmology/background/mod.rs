@@ -0,0 +1,534 @@
+//! ΛCDM background expansion after inflation
+//!
+//! The present-day (a = 1) component densities fix the whole post-inflation history
+//! through the Friedmann equation
+//!
+//! ```text
+//! H²(a) = [ρ_r a⁻⁴ + ρ_m a⁻³ + ρ_Λ] / (3 M̄_pl²) + Ω_k H₀² a⁻²
+//! ```
+//!
+//! where matter dilutes as a⁻³, radiation as a⁻⁴ and the cosmological constant stays
+//! fixed. The curvature term Ω_k H₀² = H₀² (1 − Ω_total) is written in units of H₀
+//! so that it is commensurate with the density terms.
+//!
+//! [`BackgroundTable`] tabulates t(a) once per parameter set, so that a, H, T and z
+//! can be looked up at any cosmic time (and cosmic time at any a, z or T) without
+//! integrating up to it. Temperatures follow the [`ThermalHistory`].
+
+use bevy::prelude::*;
+use genesis_core::config::CosmologyConfig;
+use genesis_core::units::{GeVInv, Kelvin, HBAR_GEV_SECONDS};
+
+use super::{EnergyDensity, ThermalHistory, FRIEDMANN_PREFACTOR};
+
+/// Number of Simpson intervals used for the time and distance integrals
+const SIMPSON_INTERVALS: usize = 2048;
+
+/// Smallest scale factor considered when inverting t(a)
+const MIN_SCALE_FACTOR: f64 = 1e-60;
+
+/// Largest scale factor considered when inverting t(a)
+const MAX_SCALE_FACTOR: f64 = 1e30;
+
+/// Smallest scale factor in a [`BackgroundTable`], well before the end of inflation
+const TABLE_MIN_SCALE_FACTOR: f64 = 1e-32;
+
+/// Largest scale factor in a [`BackgroundTable`], deep in the Λ-dominated future
+const TABLE_MAX_SCALE_FACTOR: f64 = 1e4;
+
+/// Table nodes per decade of scale factor
+const TABLE_NODES_PER_DECADE: usize = 100;
+
+/// Simpson intervals in ln a between neighbouring table nodes
+const TABLE_SUBINTERVALS: usize = 4;
+
+/// Present-day parameters of the post-inflation background
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Background {
+    /// Component densities today (a = 1) in GeV⁴
+    pub present: EnergyDensity,
+
+    /// Curvature term Ω_k H₀² today in GeV²
+    pub curvature_term: f64,
+}
+
+impl Background {
+    /// Create the background for the `[cosmology]` config
+    ///
+    /// Each component density is ρᵢ = Ωᵢ ρ_crit, and Ω_k = 1 − Ω_total, so that
+    /// H(a = 1) reproduces the configured H₀ exactly.
+    ///
+    /// # Arguments
+    /// * `config` - Cosmological parameters (density parameters, H₀)
+    pub fn from_config(config: &CosmologyConfig) -> Self {
+        let h0 = config.h0_per_second() * HBAR_GEV_SECONDS;
+        let critical_density = h0 * h0 / FRIEDMANN_PREFACTOR;
+
+        Self {
+            present: EnergyDensity {
+                total: config.omega_total() * critical_density,
+                matter: config.omega_m * critical_density,
+                radiation: config.omega_r * critical_density,
+                dark_energy: config.omega_lambda * critical_density,
+                inflaton: 0.0,
+            },
+            curvature_term: config.omega_k() * h0 * h0,
+        }
+    }
+
+    /// Whether a(t) reaches zero at a finite time in the past
+    ///
+    /// Matter, radiation or open curvature make H diverge fast enough as a → 0; a pure
+    /// Λ background has no Big Bang to measure its age from.
+    pub fn has_big_bang(&self) -> bool {
+        self.present.matter > 0.0 || self.present.radiation > 0.0 || self.curvature_term > 0.0
+    }
+
+    /// Component densities at scale factor `a`
+    ///
+    /// # Arguments
+    /// * `a` - Scale factor (a = 1 today)
+    ///
+    /// # Returns
+    /// Matter scaled by a⁻³, radiation by a⁻⁴ and dark energy unchanged, in GeV⁴
+    pub fn density_at(&self, a: f64) -> EnergyDensity {
+        let matter = self.present.matter / (a * a * a);
+        let radiation = self.present.radiation / (a * a * a * a);
+        let dark_energy = self.present.dark_energy;
+
+        EnergyDensity {
+            total: matter + radiation + dark_energy,
+            matter,
+            radiation,
+            dark_energy,
+            inflaton: 0.0,
+        }
+    }
+
+    /// Squared Hubble parameter H²(a) from the full Friedmann equation, in GeV²
+    ///
+    /// Negative values mean that a closed background has turned around.
+    pub fn hubble_squared_at(&self, a: f64) -> f64 {
+        FRIEDMANN_PREFACTOR * self.density_at(a).total + self.curvature_term / (a * a)
+    }
+
+    /// Hubble parameter H(a) in GeV, clamped at zero
+    pub fn hubble_at(&self, a: f64) -> f64 {
+        self.hubble_squared_at(a).max(0.0).sqrt()
+    }
+
+    /// Hubble parameter today, H₀ = H(a = 1), in GeV
+    pub fn hubble_today(&self) -> f64 {
+        self.hubble_at(1.0)
+    }
+
+    /// a⁴ H²(a) in GeV², which stays finite as a → 0
+    fn a4_hubble_squared(&self, a: f64) -> f64 {
+        FRIEDMANN_PREFACTOR
+            * (self.present.radiation + self.present.matter * a + self.present.dark_energy * a * a * a * a)
+            + self.curvature_term * a * a
+    }
+
+    /// Cosmic time at which the background reaches scale factor `a`
+    ///
+    /// # Arguments
+    /// * `a` - Scale factor (a = 1 today)
+    ///
+    /// # Returns
+    /// The time since the Big Bang, or infinity when the background has none or
+    /// never reaches `a`
+    pub fn time_at(&self, a: f64) -> GeVInv {
+        if !self.has_big_bang() {
+            return GeVInv(f64::INFINITY);
+        }
+        self.time_between(0.0, a)
+    }
+
+    /// Cosmic time the background takes to expand from `a_start` to `a_end`
+    ///
+    /// Evaluates ∫ da / (a H(a)) with Simpson's rule in s = √a, which keeps the
+    /// integrand 2s³ / √(a⁴ H²) smooth as a → 0 in both the radiation and matter eras.
+    ///
+    /// # Returns
+    /// The elapsed time, or infinity when a closed background turns around first
+    pub fn time_between(&self, a_start: f64, a_end: f64) -> GeVInv {
+        let t = simpson(
+            |s| {
+                if s == 0.0 {
+                    // Vanishes with matter, radiation or curvature present
+                    return 0.0;
+                }
+                2.0 * s * s * s / self.a4_hubble_squared(s * s).max(0.0).sqrt()
+            },
+            a_start.sqrt(),
+            a_end.sqrt(),
+        );
+        GeVInv(if t.is_nan() { f64::INFINITY } else { t })
+    }
+
+    /// Conformal time η(a) = ∫₀ᵃ da' / (a'² H(a')), the comoving particle horizon
+    ///
+    /// # Returns
+    /// The conformal time since the Big Bang, or infinity when the background has
+    /// none or its horizon diverges
+    pub fn conformal_time_at(&self, a: f64) -> GeVInv {
+        if !self.has_big_bang() {
+            return GeVInv(f64::INFINITY);
+        }
+        self.conformal_time_between(0.0, a)
+    }
+
+    /// Conformal time elapsed while the background expands from `a_start` to `a_end`
+    ///
+    /// This is also the comoving distance light travels in that interval. Uses the
+    /// integrand 2s / √(a⁴ H²) in s = √a, like [`Background::time_between`].
+    pub fn conformal_time_between(&self, a_start: f64, a_end: f64) -> GeVInv {
+        let eta = simpson(
+            |s| {
+                if s == 0.0 {
+                    // Finite limit unless neither radiation nor matter dominates early
+                    return if self.present.radiation > 0.0 {
+                        0.0
+                    } else if self.present.matter > 0.0 {
+                        2.0 / (FRIEDMANN_PREFACTOR * self.present.matter).sqrt()
+                    } else {
+                        f64::INFINITY
+                    };
+                }
+                2.0 * s / self.a4_hubble_squared(s * s).max(0.0).sqrt()
+            },
+            a_start.sqrt(),
+            a_end.sqrt(),
+        );
+        GeVInv(if eta.is_nan() { f64::INFINITY } else { eta })
+    }
+
+    /// Age of the universe today, t(a = 1)
+    pub fn age(&self) -> GeVInv {
+        self.time_at(1.0)
+    }
+
+    /// Scale factor the background reaches at cosmic time `t`
+    ///
+    /// Inverts [`Background::time_at`] by bisection in ln a.
+    ///
+    /// # Arguments
+    /// * `t` - Time since the Big Bang
+    ///
+    /// # Returns
+    /// The scale factor, or `None` when the background has no Big Bang or does not
+    /// reach `t` below a = 10³⁰
+    pub fn scale_factor_at(&self, t: GeVInv) -> Option<f64> {
+        if !self.has_big_bang() || t.value() <= 0.0 {
+            return None;
+        }
+        if self.time_at(MAX_SCALE_FACTOR).value() < t.value() {
+            return None;
+        }
+
+        let (mut lo, mut hi) = (MIN_SCALE_FACTOR.ln(), MAX_SCALE_FACTOR.ln());
+        while hi - lo > 1e-15 * hi.abs().max(1.0) {
+            let mid = 0.5 * (lo + hi);
+            if self.time_at(mid.exp()).value() < t.value() {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Some((0.5 * (lo + hi)).exp())
+    }
+}
+
+/// Simpson's rule for ∫ f(s) ds over `[lo, hi]` with [`SIMPSON_INTERVALS`] intervals
+fn simpson(f: impl Fn(f64) -> f64, lo: f64, hi: f64) -> f64 {
+    let h = (hi - lo) / SIMPSON_INTERVALS as f64;
+    let mut sum = f(lo) + f(hi);
+    for i in 1..SIMPSON_INTERVALS {
+        let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+        sum += weight * f(lo + i as f64 * h);
+    }
+    sum * h / 3.0
+}
+
+/// Log-spaced interpolation table of the [`Background`] expansion history
+///
+/// Stores ln t at nodes evenly spaced in ln a, together with the exact slope
+/// d ln t / d ln a = 1 / (H t), and interpolates between nodes with cubic Hermite
+/// polynomials in either direction. Power-law eras are reproduced almost exactly;
+/// beyond the ends of the table the first and last slopes are extrapolated.
+///
+/// Built once per parameter set; [`rebuild_background_table`] keeps the resource in
+/// line with the `[cosmology]` config.
+#[derive(Debug, Clone, Resource)]
+pub struct BackgroundTable {
+    background: Background,
+    thermal: ThermalHistory,
+    ln_a: Vec<f64>,
+    ln_t: Vec<f64>,
+    slopes: Vec<f64>,
+}
+
+impl BackgroundTable {
+    /// Tabulate a background
+    ///
+    /// # Arguments
+    /// * `background` - Present-day parameters to tabulate
+    /// * `t_cmb` - Temperature today, which fixes T(a) through the [`ThermalHistory`]
+    ///
+    /// # Returns
+    /// The table, or `None` when the background has no Big Bang to measure time from
+    pub fn new(background: Background, t_cmb: Kelvin) -> Option<Self> {
+        if !background.has_big_bang() {
+            return None;
+        }
+
+        let ln_min = TABLE_MIN_SCALE_FACTOR.ln();
+        let ln_max = TABLE_MAX_SCALE_FACTOR.ln();
+        let decades = (TABLE_MAX_SCALE_FACTOR / TABLE_MIN_SCALE_FACTOR).log10();
+        let nodes = (decades * TABLE_NODES_PER_DECADE as f64).round() as usize + 1;
+        let step = (ln_max - ln_min) / (nodes - 1) as f64;
+
+        // dt / d ln a = 1 / H, accumulated node to node with Simpson's rule
+        let inverse_hubble = |ln_a: f64| 1.0 / background.hubble_at(ln_a.exp());
+        let h = step / TABLE_SUBINTERVALS as f64;
+
+        let mut ln_a = Vec::with_capacity(nodes);
+        let mut ln_t = Vec::with_capacity(nodes);
+        let mut slopes = Vec::with_capacity(nodes);
+        let mut t = background.time_at(TABLE_MIN_SCALE_FACTOR).value();
+        for i in 0..nodes {
+            let x = ln_min + i as f64 * step;
+            if i > 0 {
+                let x0 = x - step;
+                let mut sum = inverse_hubble(x0) + inverse_hubble(x);
+                for j in 1..TABLE_SUBINTERVALS {
+                    let weight = if j % 2 == 1 { 4.0 } else { 2.0 };
+                    sum += weight * inverse_hubble(x0 + j as f64 * h);
+                }
+                t += sum * h / 3.0;
+            }
+            if !t.is_finite() {
+                // A closed background has turned around; the table ends here
+                break;
+            }
+
+            ln_a.push(x);
+            ln_t.push(t.ln());
+            slopes.push(inverse_hubble(x) / t);
+        }
+
+        Some(Self {
+            background,
+            thermal: ThermalHistory::new(t_cmb),
+            ln_a,
+            ln_t,
+            slopes,
+        })
+    }
+
+    /// Tabulate the background of the `[cosmology]` config
+    pub fn from_config(config: &CosmologyConfig) -> Option<Self> {
+        Self::new(Background::from_config(config), Kelvin(config.t_cmb))
+    }
+
+    /// The tabulated background
+    pub fn background(&self) -> &Background {
+        &self.background
+    }
+
+    /// Thermal history giving the temperature at each scale factor
+    pub fn thermal(&self) -> &ThermalHistory {
+        &self.thermal
+    }
+
+    /// Scale factor a(t)
+    pub fn scale_factor_at(&self, t: GeVInv) -> f64 {
+        let ln_t = t.value().ln();
+        let i = self.ln_t.partition_point(|&node| node <= ln_t);
+        let i = i.clamp(1, self.ln_t.len() - 1) - 1;
+
+        // Inverse slopes d ln a / d ln t = H t
+        let ln_a = hermite(
+            (self.ln_t[i], self.ln_a[i], 1.0 / self.slopes[i]),
+            (self.ln_t[i + 1], self.ln_a[i + 1], 1.0 / self.slopes[i + 1]),
+            ln_t,
+        );
+        ln_a.exp()
+    }
+
+    /// Hubble parameter H(t) in GeV
+    pub fn hubble_at(&self, t: GeVInv) -> f64 {
+        self.background.hubble_at(self.scale_factor_at(t))
+    }
+
+    /// Photon temperature T(t) along the [`ThermalHistory`]
+    pub fn temperature_at(&self, t: GeVInv) -> Kelvin {
+        self.thermal.photon_temperature(self.scale_factor_at(t))
+    }
+
+    /// Redshift z(t) = 1 / a(t) − 1
+    pub fn redshift_at(&self, t: GeVInv) -> f64 {
+        1.0 / self.scale_factor_at(t) - 1.0
+    }
+
+    /// Cosmic time at which the background reaches scale factor `a`
+    pub fn time_at_scale_factor(&self, a: f64) -> GeVInv {
+        let ln_a = a.ln();
+        let i = self.ln_a.partition_point(|&node| node <= ln_a);
+        let i = i.clamp(1, self.ln_a.len() - 1) - 1;
+
+        let ln_t = hermite(
+            (self.ln_a[i], self.ln_t[i], self.slopes[i]),
+            (self.ln_a[i + 1], self.ln_t[i + 1], self.slopes[i + 1]),
+            ln_a,
+        );
+        GeVInv(ln_t.exp())
+    }
+
+    /// Cosmic time at which light is emitted that reaches us with redshift `z`
+    pub fn time_at_redshift(&self, z: f64) -> GeVInv {
+        self.time_at_scale_factor(1.0 / (1.0 + z))
+    }
+
+    /// Cosmic time at which the universe has cooled to `temperature`
+    pub fn time_at_temperature(&self, temperature: Kelvin) -> GeVInv {
+        self.time_at_scale_factor(self.thermal.scale_factor_at(temperature))
+    }
+}
+
+/// Cubic Hermite interpolation between two nodes `(x, y, dy/dx)`
+///
+/// Outside the nodes the nearer one is extended linearly along its slope.
+fn hermite(start: (f64, f64, f64), end: (f64, f64, f64), x: f64) -> f64 {
+    let (x0, y0, m0) = start;
+    let (x1, y1, m1) = end;
+    let h = x1 - x0;
+    let s = (x - x0) / h;
+
+    if !(0.0..=1.0).contains(&s) {
+        return if s < 0.0 { y0 + m0 * (x - x0) } else { y1 + m1 * (x - x1) };
+    }
+
+    let h00 = (1.0 + 2.0 * s) * (1.0 - s) * (1.0 - s);
+    let h10 = s * (1.0 - s) * (1.0 - s);
+    let h01 = s * s * (3.0 - 2.0 * s);
+    let h11 = s * s * (s - 1.0);
+    h00 * y0 + h10 * h * m0 + h01 * y1 + h11 * h * m1
+}
+
+/// System that rebuilds the [`BackgroundTable`] whenever the `[cosmology]` config changes
+///
+/// Removes the table for backgrounds without a Big Bang, such as de Sitter.
+pub fn rebuild_background_table(mut commands: Commands, config: Option<Res<CosmologyConfig>>) {
+    let Some(config) = config else {
+        return;
+    };
+    if !config.is_changed() {
+        return;
+    }
+
+    match BackgroundTable::from_config(&config) {
+        Some(table) => commands.insert_resource(table),
+        None => commands.remove_resource::<BackgroundTable>(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genesis_core::config::CosmologyPreset;
+    use genesis_core::units::Years;
+
+    #[test]
+    fn test_planck_age_is_13_8_gyr() {
+        let background = Background::from_config(&CosmologyConfig::default());
+        let age = Years::from(background.age()).value();
+        assert!((age - 13.79e9).abs() < 0.05e9, "age was {} Gyr", age / 1e9);
+    }
+
+    #[test]
+    fn test_hubble_today_reproduces_h0() {
+        let config = CosmologyConfig::default();
+        let background = Background::from_config(&config);
+        let h0 = config.h0_per_second() * HBAR_GEV_SECONDS;
+        assert!((background.hubble_today() - h0).abs() / h0 < 1e-12);
+    }
+
+    #[test]
+    fn test_densities_scale_with_a() {
+        let background = Background::from_config(&CosmologyConfig::default());
+        let density = background.density_at(0.5);
+        assert!((density.matter / background.present.matter - 8.0).abs() < 1e-12);
+        assert!((density.radiation / background.present.radiation - 16.0).abs() < 1e-12);
+        assert_eq!(density.dark_energy, background.present.dark_energy);
+    }
+
+    #[test]
+    fn test_einstein_de_sitter_age() {
+        let background = Background::from_config(&CosmologyPreset::EinsteinDeSitter.config());
+        let h0 = background.hubble_today();
+
+        // a(t) = (3H₀t / 2)^(2/3), so t(a) = 2 a^(3/2) / (3H₀)
+        for a in [1e-6_f64, 1e-3, 0.5, 1.0] {
+            let expected = 2.0 * a * a.sqrt() / (3.0 * h0);
+            assert!((background.time_at(a).value() - expected).abs() / expected < 1e-10);
+        }
+        let a = background.scale_factor_at(GeVInv(1.0 / h0)).unwrap();
+        assert!((a - 1.5_f64.powf(2.0 / 3.0)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_de_sitter_has_no_big_bang() {
+        let background = Background::from_config(&CosmologyPreset::DeSitter.config());
+        assert!(!background.has_big_bang());
+        assert!(background.age().value().is_infinite());
+        assert_eq!(background.scale_factor_at(GeVInv(1.0)), None);
+        assert!(background.hubble_at(1e-3) == background.hubble_today());
+        assert!(BackgroundTable::new(background, Kelvin(2.7255)).is_none());
+    }
+
+    #[test]
+    fn test_table_matches_einstein_de_sitter() {
+        let table = BackgroundTable::from_config(&CosmologyPreset::EinsteinDeSitter.config()).unwrap();
+        let h0 = table.background().hubble_today();
+
+        for a in [1e-40_f64, 1e-25, 1e-3, 0.37, 1.0, 1e5] {
+            let t = 2.0 * a * a.sqrt() / (3.0 * h0);
+            assert!((table.time_at_scale_factor(a).value() - t).abs() / t < 1e-9, "t({})", a);
+            assert!((table.scale_factor_at(GeVInv(t)) - a).abs() / a < 1e-9, "a(t({}))", a);
+            assert!((table.hubble_at(GeVInv(t)) * t - 2.0 / 3.0).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_table_agrees_with_background() {
+        let config = CosmologyConfig::default();
+        let table = BackgroundTable::from_config(&config).unwrap();
+        let background = table.background();
+
+        for a in [1e-20, 1e-9, 3e-4, 0.1, 1.0, 3.0] {
+            let exact = background.time_at(a).value();
+            let t = table.time_at_scale_factor(a).value();
+            assert!((t - exact).abs() / exact < 1e-6, "t({}) = {} vs {}", a, t, exact);
+        }
+        let age = Years::from(table.time_at_redshift(0.0)).value();
+        assert!((age - 13.79e9).abs() < 0.05e9);
+    }
+
+    #[test]
+    fn test_table_inverse_lookups() {
+        let config = CosmologyConfig::default();
+        let table = BackgroundTable::from_config(&config).unwrap();
+
+        // Recombination at z ≈ 1100, roughly 370 000 years after the Big Bang
+        let t = table.time_at_redshift(1100.0);
+        assert!((table.redshift_at(t) - 1100.0).abs() < 1e-6);
+        let years = Years::from(t).value();
+        assert!((years - 3.7e5).abs() < 0.3e5, "z = 1100 at {} years", years);
+
+        let t = table.time_at_temperature(Kelvin(3000.0));
+        assert!((table.temperature_at(t).value() - 3000.0).abs() < 1e-6);
+        let a = table.scale_factor_at(t);
+        assert!((a - config.t_cmb / 3000.0).abs() / a < 1e-9);
+    }
+}
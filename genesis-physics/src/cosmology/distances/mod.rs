@@ -0,0 +1,221 @@
+//! Cosmological distances and ages
+//!
+//! Distance measures along the [`Background`], as functions of the redshift z at
+//! which light is emitted and received today (a = 1):
+//!
+//! - **Comoving distance** D_C = ∫ dz / H(z), the light-travel distance in comoving units
+//! - **Transverse comoving distance** D_M, which bends D_C with the spatial curvature
+//! - **Angular diameter distance** D_A = D_M / (1 + z), relating physical size to angle
+//! - **Luminosity distance** D_L = (1 + z) D_M, relating luminosity to flux
+//!
+//! Distances are returned in [`Mpc`] and times in [`GeVInv`]; convert with the
+//! `From` impls in [`genesis_core::units`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use genesis_core::config::CosmologyConfig;
+//! use genesis_core::units::Years;
+//! use genesis_physics::cosmology::Distances;
+//!
+//! let distances = Distances::from_config(&CosmologyConfig::default());
+//! let lookback = Years::from(distances.lookback_time(1.0));
+//! assert!(lookback.value() > 7e9 && lookback.value() < 9e9);
+//! ```
+
+use genesis_core::config::CosmologyConfig;
+use genesis_core::units::{GeVInv, Mpc};
+
+use super::{Background, Curvature};
+
+/// Distance and age calculator for a background and spatial geometry
+///
+/// The geometry used for transverse distances follows the [`Curvature`], with the
+/// radius of curvature set by the background's Ω_k. A [`Curvature::Flat`] geometry
+/// ignores the small Ω_k left over from rounding the density parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distances {
+    background: Background,
+    curvature: Curvature,
+}
+
+impl Distances {
+    /// Create a calculator for a background and curvature
+    pub fn new(background: Background, curvature: Curvature) -> Self {
+        Self { background, curvature }
+    }
+
+    /// Create a calculator for the `[cosmology]` config
+    pub fn from_config(config: &CosmologyConfig) -> Self {
+        Self::new(Background::from_config(config), Curvature::from_config(config))
+    }
+
+    /// The background distances are measured along
+    pub fn background(&self) -> &Background {
+        &self.background
+    }
+
+    /// Comoving (line-of-sight) distance D_C to redshift `z`
+    pub fn comoving_distance(&self, z: f64) -> Mpc {
+        Mpc::from(self.comoving_distance_natural(z))
+    }
+
+    /// Transverse comoving distance D_M to redshift `z`
+    ///
+    /// For comoving distance χ and K = |Ω_k| H₀², D_M is sinh(√K χ)/√K in an open
+    /// geometry, sin(√K χ)/√K in a closed one and χ when flat.
+    pub fn transverse_comoving_distance(&self, z: f64) -> Mpc {
+        let chi = self.comoving_distance_natural(z).value();
+        let k = self.background.curvature_term.abs();
+        if k == 0.0 {
+            return Mpc::from(GeVInv(chi));
+        }
+
+        let sqrt_k = k.sqrt();
+        let transverse = match self.curvature {
+            Curvature::Open => (sqrt_k * chi).sinh() / sqrt_k,
+            Curvature::Closed => (sqrt_k * chi).sin() / sqrt_k,
+            Curvature::Flat => chi,
+        };
+        Mpc::from(GeVInv(transverse))
+    }
+
+    /// Angular diameter distance D_A = D_M / (1 + z)
+    pub fn angular_diameter_distance(&self, z: f64) -> Mpc {
+        Mpc(self.transverse_comoving_distance(z).value() / (1.0 + z))
+    }
+
+    /// Luminosity distance D_L = (1 + z) D_M
+    pub fn luminosity_distance(&self, z: f64) -> Mpc {
+        Mpc(self.transverse_comoving_distance(z).value() * (1.0 + z))
+    }
+
+    /// Time between the emission of light seen today at redshift `z` and today
+    pub fn lookback_time(&self, z: f64) -> GeVInv {
+        self.background.time_between(scale_factor(z), 1.0)
+    }
+
+    /// Age of the universe at redshift `z`
+    ///
+    /// # Returns
+    /// The time since the Big Bang, or infinity for a background without one
+    pub fn age_at_redshift(&self, z: f64) -> GeVInv {
+        self.background.time_at(scale_factor(z))
+    }
+
+    /// Comoving particle horizon at redshift `z`, the farthest comoving distance
+    /// light can have travelled since the Big Bang
+    ///
+    /// Multiply by a = 1/(1 + z) for the proper radius.
+    pub fn horizon_radius(&self, z: f64) -> Mpc {
+        Mpc::from(self.background.conformal_time_at(scale_factor(z)))
+    }
+
+    /// Comoving Hubble radius 1 / (a H) at redshift `z`
+    ///
+    /// Multiply by a = 1/(1 + z) for the proper radius 1 / H.
+    pub fn hubble_radius(&self, z: f64) -> Mpc {
+        let a = scale_factor(z);
+        Mpc::from(GeVInv(1.0 / (a * self.background.hubble_at(a))))
+    }
+
+    /// Comoving distance to redshift `z` in natural units
+    fn comoving_distance_natural(&self, z: f64) -> GeVInv {
+        self.background.conformal_time_between(scale_factor(z), 1.0)
+    }
+}
+
+/// Scale factor a = 1 / (1 + z) at which light is emitted with redshift `z`
+fn scale_factor(z: f64) -> f64 {
+    1.0 / (1.0 + z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmology::EnergyDensity;
+    use genesis_core::config::CosmologyPreset;
+    use genesis_core::units::Years;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        let relative_error = ((actual - expected) / expected).abs();
+        assert!(relative_error < tolerance, "expected {}, got {}", expected, actual);
+    }
+
+    #[test]
+    fn test_einstein_de_sitter_distances() {
+        let distances = Distances::from_config(&CosmologyPreset::EinsteinDeSitter.config());
+        let hubble_distance = Mpc::from(GeVInv(1.0 / distances.background().hubble_today())).value();
+
+        for z in [0.1_f64, 1.0, 3.0, 1100.0] {
+            // D_C = (2c/H₀)(1 − 1/√(1+z)), flat so D_M = D_C
+            let comoving = 2.0 * hubble_distance * (1.0 - 1.0 / (1.0 + z).sqrt());
+            assert_close(distances.comoving_distance(z).value(), comoving, 1e-9);
+            assert_close(distances.transverse_comoving_distance(z).value(), comoving, 1e-9);
+            assert_close(distances.luminosity_distance(z).value(), comoving * (1.0 + z), 1e-9);
+            assert_close(distances.angular_diameter_distance(z).value(), comoving / (1.0 + z), 1e-9);
+
+            // η = 2/(H₀ √(1+z)) and 1/(aH) = √(1+z)/H₀
+            assert_close(distances.horizon_radius(z).value(), 2.0 * hubble_distance / (1.0 + z).sqrt(), 1e-9);
+            assert_close(distances.hubble_radius(z).value(), hubble_distance / (1.0 + z).sqrt(), 1e-12);
+
+            // t₀ − t = (2/3H₀)(1 − (1+z)^(−3/2))
+            let h0 = distances.background().hubble_today();
+            let lookback = 2.0 / (3.0 * h0) * (1.0 - (1.0 + z).powf(-1.5));
+            assert_close(distances.lookback_time(z).value(), lookback, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_empty_open_universe_distances() {
+        // Milne: only curvature, H = H₀/a, a = H₀t
+        let h0 = 1e-42;
+        let background = Background {
+            present: EnergyDensity::default(),
+            curvature_term: h0 * h0,
+        };
+        let distances = Distances::new(background, Curvature::Open);
+        let hubble_distance = Mpc::from(GeVInv(1.0 / h0)).value();
+
+        let z = 2.0_f64;
+        assert_close(distances.comoving_distance(z).value(), hubble_distance * (1.0 + z).ln(), 1e-9);
+        let transverse = hubble_distance * ((1.0 + z) - 1.0 / (1.0 + z)) / 2.0;
+        assert_close(distances.transverse_comoving_distance(z).value(), transverse, 1e-9);
+        assert_close(distances.age_at_redshift(z).value(), 1.0 / (h0 * (1.0 + z)), 1e-9);
+    }
+
+    #[test]
+    fn test_closed_geometry_shrinks_transverse_distance() {
+        let mut config = CosmologyPreset::EinsteinDeSitter.config();
+        config.omega_m = 1.3;
+        config.curvature = "closed".to_string();
+        let distances = Distances::from_config(&config);
+
+        let z = 2.0;
+        let comoving = distances.comoving_distance(z).value();
+        assert!(distances.transverse_comoving_distance(z).value() < comoving);
+    }
+
+    #[test]
+    fn test_planck_distances() {
+        let distances = Distances::from_config(&CosmologyConfig::default());
+
+        assert_eq!(distances.comoving_distance(0.0).value(), 0.0);
+        assert_eq!(distances.lookback_time(0.0).value(), 0.0);
+
+        // D_C(z = 1) ≈ 3.4 Gpc, and the last-scattering surface lies ≈ 13.9 Gpc away
+        let d1 = distances.comoving_distance(1.0).value();
+        assert!((3300.0..3500.0).contains(&d1), "D_C(1) = {} Mpc", d1);
+        let d_cmb = distances.comoving_distance(1100.0).value();
+        assert!((13_700.0..14_100.0).contains(&d_cmb), "D_C(1100) = {} Mpc", d_cmb);
+
+        // c/H₀ ≈ 4451 Mpc today; the particle horizon is ≈ 14.2 Gpc
+        assert_close(distances.hubble_radius(0.0).value(), 299_792.458 / 67.36, 1e-6);
+        let horizon = distances.horizon_radius(0.0).value();
+        assert!((14_000.0..14_500.0).contains(&horizon), "horizon = {} Mpc", horizon);
+
+        let age = Years::from(distances.age_at_redshift(0.0)).value();
+        let lookback = Years::from(distances.lookback_time(1100.0)).value();
+        assert!((age - lookback) > 3e5 && (age - lookback) < 4.5e5);
+    }
+}
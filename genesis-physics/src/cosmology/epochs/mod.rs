@@ -0,0 +1,493 @@
+//! Physical detection of the current cosmic epoch
+//!
+//! Each epoch begins when the universe meets a physical criterion, and lasts until
+//! the next one begins:
+//!
+//! | Epoch              | Begins when                                               |
+//! |--------------------|-----------------------------------------------------------|
+//! | Inflation          | cosmic time passes `INFLATION_START_YEARS`                |
+//! | Quark-Gluon Plasma | inflation ends (see below)                                |
+//! | Hadron             | the temperature drops below `qcd_temperature_mev`         |
+//! | Nucleosynthesis    | the temperature drops below the start of BBN              |
+//! | Recombination      | the temperature drops below the end of BBN                |
+//! | Dark Ages          | the ionization fraction x_e drops below `recombination_ionization` |
+//! | Cosmic Dawn        | the redshift drops below `star_formation_redshift`        |
+//! | Structure          | the redshift drops below `reionization_redshift`          |
+//!
+//! Inflation ends where the background takes over at `INFLATION_END_YEARS`, or
+//! earlier when the inflaton's slow-roll ε reaches `inflation_end_epsilon`:
+//! [`detect_cosmic_epoch`] records that crossing in the [`EpochCriteria`], so the
+//! detected epoch and the epoch ranges of [`EpochCriteria::onset_time`] share one
+//! boundary.
+//!
+//! The thresholds come from the `[epochs]` config. The lepton era before the start
+//! of BBN at `bbn_start_temperature_mev` counts towards the Hadron epoch that
+//! precedes it, and the photon era after BBN counts towards Recombination.
+//!
+//! The temperature follows the [`ThermalHistory`](super::ThermalHistory) and x_e
+//! the Saha equation for hydrogen with the baryon-to-photon ratio of the
+//! `[cosmology]` config, so the time at which each threshold is crossed
+//! ([`EpochCriteria::onset_time`]) moves with the cosmological parameters.
+
+use bevy::prelude::*;
+use genesis_core::config::{CosmologyConfig, EpochConfig};
+use genesis_core::time::{SimulationClock, INFLATION_END_YEARS, INFLATION_START_YEARS};
+use genesis_core::units::{GeV, GeVInv, Kelvin, Years};
+
+use super::{BackgroundTable, CosmicEpoch, Cosmology};
+use crate::inflaton::Inflaton;
+
+/// Electron mass in GeV
+const ELECTRON_MASS_GEV: f64 = 5.109_989_50e-4;
+
+/// Hydrogen ionization energy in GeV (13.6 eV)
+const HYDROGEN_BINDING_GEV: f64 = 1.360_569_3e-8;
+
+/// Riemann ζ(3), which sets the photon number density n_γ = 2ζ(3)T³/π²
+const ZETA_3: f64 = 1.202_056_903_159_594;
+
+/// Baryon-to-photon ratio per unit Ω_b h² at T_CMB = 2.7255 K
+const ETA_PER_OMEGA_B_H2: f64 = 2.738e-8;
+
+/// CMB temperature the baryon-to-photon ratio above is quoted at
+const ETA_REFERENCE_T_CMB: f64 = 2.7255;
+
+/// Range searched for the temperature at which x_e reaches a threshold, in Kelvin
+const RECOMBINATION_SEARCH_KELVIN: (f64, f64) = (100.0, 1e5);
+
+/// Baryon-to-photon ratio η = n_b / n_γ of the `[cosmology]` config
+///
+/// η ≈ 2.738 × 10⁻⁸ Ω_b h², scaled by T_CMB⁻³ since n_γ ∝ T_CMB³.
+pub fn baryon_to_photon_ratio(config: &CosmologyConfig) -> f64 {
+    let h = config.little_h();
+    ETA_PER_OMEGA_B_H2 * config.omega_b * h * h * (ETA_REFERENCE_T_CMB / config.t_cmb).powi(3)
+}
+
+/// Equilibrium ionization fraction x_e of hydrogen from the Saha equation
+///
+/// ```text
+/// x_e² / (1 − x_e) = (1 / n_b) (m_e T / 2π)^(3/2) e^(−B/T)
+/// ```
+///
+/// with n_b = η · 2ζ(3)T³/π² and B = 13.6 eV. Helium is neglected, and plasma
+/// hotter than the electron mass, where the equation no longer holds, is taken to
+/// be fully ionized.
+///
+/// # Arguments
+/// * `temperature` - Photon temperature
+/// * `eta` - Baryon-to-photon ratio, see [`baryon_to_photon_ratio`]
+///
+/// # Returns
+/// x_e between 0 (neutral) and 1 (fully ionized)
+pub fn saha_ionization_fraction(temperature: Kelvin, eta: f64) -> f64 {
+    let t = GeV::from(temperature).value();
+    if t <= 0.0 {
+        return 0.0;
+    }
+    if eta <= 0.0 || t >= ELECTRON_MASS_GEV {
+        return 1.0;
+    }
+
+    let pi = std::f64::consts::PI;
+    let photons = 2.0 * ZETA_3 / (pi * pi);
+    let s = (ELECTRON_MASS_GEV / (2.0 * pi * t)).powf(1.5) * (-HYDROGEN_BINDING_GEV / t).exp()
+        / (eta * photons);
+
+    // Positive root of x² + s·x − s = 0, written to stay accurate for large s
+    2.0 / (1.0 + (1.0 + 4.0 / s).sqrt())
+}
+
+/// Thresholds between cosmic epochs for one set of cosmological parameters
+///
+/// [`rebuild_epoch_criteria`] keeps the resource in step with the `[epochs]` and
+/// `[cosmology]` configs.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct EpochCriteria {
+    thresholds: EpochConfig,
+    eta: f64,
+    inflation_end: GeVInv,
+}
+
+impl EpochCriteria {
+    /// Create the criteria for the `[epochs]` thresholds in the `[cosmology]` config
+    pub fn from_config(epochs: &EpochConfig, cosmology: &CosmologyConfig) -> Self {
+        Self {
+            thresholds: epochs.clone(),
+            eta: baryon_to_photon_ratio(cosmology),
+            inflation_end: GeVInv::from(Years(INFLATION_END_YEARS)),
+        }
+    }
+
+    /// The `[epochs]` thresholds
+    pub fn thresholds(&self) -> &EpochConfig {
+        &self.thresholds
+    }
+
+    /// Baryon-to-photon ratio used for the ionization fraction
+    pub fn baryon_to_photon_ratio(&self) -> f64 {
+        self.eta
+    }
+
+    /// Hydrogen ionization fraction at `temperature`
+    pub fn ionization_fraction(&self, temperature: Kelvin) -> f64 {
+        saha_ionization_fraction(temperature, self.eta)
+    }
+
+    /// Cosmic time at which inflation ends
+    pub fn inflation_end(&self) -> GeVInv {
+        self.inflation_end
+    }
+
+    /// Records that inflation ended at `time`, when the slow-roll ε was seen to
+    /// reach `inflation_end_epsilon`
+    ///
+    /// # Returns
+    /// `true` if this moved the end of inflation; a time outside the inflationary
+    /// window leaves it unchanged
+    pub fn end_inflation(&mut self, time: GeVInv) -> bool {
+        if time <= GeVInv::from(Years(INFLATION_START_YEARS)) || time >= self.inflation_end {
+            return false;
+        }
+        self.inflation_end = time;
+        true
+    }
+
+    /// Temperature of the QCD transition
+    pub fn qcd_temperature(&self) -> Kelvin {
+        Kelvin::from(GeV(self.thresholds.qcd_temperature_mev * 1e-3))
+    }
+
+    /// Temperature at which nucleosynthesis begins
+    pub fn bbn_start_temperature(&self) -> Kelvin {
+        Kelvin::from(GeV(self.thresholds.bbn_start_temperature_mev * 1e-3))
+    }
+
+    /// Temperature at which nucleosynthesis has finished
+    pub fn bbn_end_temperature(&self) -> Kelvin {
+        Kelvin::from(GeV(self.thresholds.bbn_end_temperature_mev * 1e-3))
+    }
+
+    /// Temperature at which the ionization fraction drops to `recombination_ionization`
+    pub fn recombination_temperature(&self) -> Kelvin {
+        let target = self.thresholds.recombination_ionization;
+        let (mut lo, mut hi) = (
+            RECOMBINATION_SEARCH_KELVIN.0.ln(),
+            RECOMBINATION_SEARCH_KELVIN.1.ln(),
+        );
+        // x_e rises with temperature
+        for _ in 0..100 {
+            let mid = 0.5 * (lo + hi);
+            if self.ionization_fraction(Kelvin(mid.exp())) < target {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Kelvin((0.5 * (lo + hi)).exp())
+    }
+
+    /// Epoch of the universe in a given state
+    ///
+    /// Inflation lasts until [`EpochCriteria::inflation_end`].
+    ///
+    /// # Arguments
+    /// * `time` - Cosmic time since the Big Bang
+    /// * `temperature` - Photon temperature
+    /// * `redshift` - Redshift z = 1/a − 1
+    pub fn epoch_at(&self, time: GeVInv, temperature: Kelvin, redshift: f64) -> CosmicEpoch {
+        if time < GeVInv::from(Years(INFLATION_START_YEARS)) {
+            return CosmicEpoch::Planck;
+        }
+        if time < self.inflation_end {
+            return CosmicEpoch::Inflation;
+        }
+
+        if temperature >= self.qcd_temperature() {
+            CosmicEpoch::QuarkGluonPlasma
+        } else if temperature >= self.bbn_start_temperature() {
+            CosmicEpoch::Hadron
+        } else if temperature >= self.bbn_end_temperature() {
+            CosmicEpoch::Nucleosynthesis
+        } else if self.ionization_fraction(temperature) >= self.thresholds.recombination_ionization {
+            CosmicEpoch::Recombination
+        } else if redshift >= self.thresholds.star_formation_redshift {
+            CosmicEpoch::DarkAges
+        } else if redshift >= self.thresholds.reionization_redshift {
+            CosmicEpoch::CosmicDawn
+        } else {
+            CosmicEpoch::Structure
+        }
+    }
+
+    /// Epoch of the [`Cosmology`] state
+    pub fn detect(&self, cosmology: &Cosmology) -> CosmicEpoch {
+        self.epoch_at(
+            cosmology.scale_factor.time,
+            cosmology.scale_factor.temperature.value,
+            cosmology.redshift(),
+        )
+    }
+
+    /// Cosmic time at which `epoch` begins along a tabulated background
+    pub fn onset_time(&self, epoch: CosmicEpoch, table: &BackgroundTable) -> GeVInv {
+        match epoch {
+            CosmicEpoch::Planck => GeVInv::ZERO,
+            CosmicEpoch::Inflation => GeVInv::from(Years(INFLATION_START_YEARS)),
+            CosmicEpoch::QuarkGluonPlasma => self.inflation_end,
+            CosmicEpoch::Hadron => table.time_at_temperature(self.qcd_temperature()),
+            CosmicEpoch::Nucleosynthesis => {
+                table.time_at_temperature(self.bbn_start_temperature())
+            }
+            CosmicEpoch::Recombination => table.time_at_temperature(self.bbn_end_temperature()),
+            CosmicEpoch::DarkAges => table.time_at_temperature(self.recombination_temperature()),
+            CosmicEpoch::CosmicDawn => {
+                table.time_at_redshift(self.thresholds.star_formation_redshift)
+            }
+            CosmicEpoch::Structure => table.time_at_redshift(self.thresholds.reionization_redshift),
+        }
+    }
+}
+
+/// System that rebuilds the [`EpochCriteria`] whenever the `[epochs]` or
+/// `[cosmology]` config changes
+///
+/// Missing config sections fall back to their defaults. A recorded end of inflation
+/// is kept as long as `inflation_end_epsilon` stays the same.
+pub fn rebuild_epoch_criteria(
+    mut commands: Commands,
+    criteria: Option<Res<EpochCriteria>>,
+    epochs: Option<Res<EpochConfig>>,
+    config: Option<Res<CosmologyConfig>>,
+) {
+    let changed = epochs.as_ref().is_some_and(|epochs| epochs.is_changed())
+        || config.as_ref().is_some_and(|config| config.is_changed());
+    if criteria.is_some() && !changed {
+        return;
+    }
+
+    let mut rebuilt = EpochCriteria::from_config(
+        &epochs.map(|epochs| epochs.clone()).unwrap_or_default(),
+        &config.map(|config| config.clone()).unwrap_or_default(),
+    );
+    if let Some(criteria) = criteria {
+        if criteria.thresholds.inflation_end_epsilon == rebuilt.thresholds.inflation_end_epsilon {
+            rebuilt.end_inflation(criteria.inflation_end);
+        }
+    }
+    commands.insert_resource(rebuilt);
+}
+
+/// System that sets the current [`CosmicEpoch`] from the state of the universe
+///
+/// Runs after [`update_temperature`](super::update_temperature), so the epoch
+/// matches the scale factor and temperature of the step just taken,
+/// and does nothing until [`rebuild_epoch_criteria`] has built the criteria. A
+/// slow-roll ε at or above `inflation_end_epsilon` during inflation ends it at the
+/// start of the fixed step in which it was reached (see
+/// [`EpochCriteria::end_inflation`]), which the clock has safely passed when the
+/// epoch manager next looks. An inflaton without
+/// potential energy, like the unevolved default, has no meaningful ε and never
+/// ends inflation early.
+pub fn detect_cosmic_epoch(
+    mut cosmology: ResMut<Cosmology>,
+    criteria: Option<ResMut<EpochCriteria>>,
+    inflaton: Option<Res<Inflaton>>,
+    clock: Option<Res<SimulationClock>>,
+) {
+    let Some(mut criteria) = criteria else {
+        return;
+    };
+    let slow_roll_over = inflaton
+        .filter(|inflaton| inflaton.potential > 0.0)
+        .is_some_and(|inflaton| inflaton.epsilon >= criteria.thresholds.inflation_end_epsilon);
+    if slow_roll_over {
+        let step_start = match clock {
+            Some(clock) => GeVInv::from(Years(clock.years()) - clock.delta()),
+            None => cosmology.scale_factor.time,
+        };
+        if step_start < criteria.inflation_end {
+            criteria.end_inflation(step_start);
+        }
+    }
+
+    let epoch = criteria.detect(&cosmology);
+    if cosmology.scale_factor.epoch != epoch {
+        cosmology.scale_factor.epoch = epoch;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genesis_core::config::CosmologyPreset;
+    use genesis_core::units::Seconds;
+
+    fn planck_criteria() -> EpochCriteria {
+        EpochCriteria::from_config(&EpochConfig::default(), &CosmologyConfig::default())
+    }
+
+    fn onset_years(criteria: &EpochCriteria, epoch: CosmicEpoch, config: &CosmologyConfig) -> f64 {
+        let table = BackgroundTable::from_config(config).unwrap();
+        Years::from(criteria.onset_time(epoch, &table)).value()
+    }
+
+    #[test]
+    fn test_saha_ionization_fraction() {
+        let eta = baryon_to_photon_ratio(&CosmologyConfig::default());
+        assert!((eta - 6.1e-10).abs() < 0.1e-10, "η = {}", eta);
+
+        assert!(saha_ionization_fraction(Kelvin(10_000.0), eta) > 0.999);
+        assert!(saha_ionization_fraction(Kelvin(2_000.0), eta) < 1e-3);
+
+        // Saha recombination (x_e = 1/2) happens near 3700 K, z ≈ 1370
+        let criteria = EpochCriteria::from_config(
+            &EpochConfig {
+                recombination_ionization: 0.5,
+                ..Default::default()
+            },
+            &CosmologyConfig::default(),
+        );
+        let t_rec = criteria.recombination_temperature().value();
+        assert!((3600.0..3900.0).contains(&t_rec), "T_rec = {} K", t_rec);
+        assert!((criteria.ionization_fraction(Kelvin(t_rec)) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_inflation_ends_with_slow_roll() {
+        let mut criteria = planck_criteria();
+        let table = BackgroundTable::from_config(&CosmologyConfig::default()).unwrap();
+        let during = GeVInv::from(Years(1e-36));
+        let crossing = GeVInv::from(Years(1e-34));
+        let after = GeVInv::from(Years(1e-30));
+        let hot = Kelvin(1e27);
+
+        // Without a recorded crossing the background handover ends inflation
+        assert_eq!(criteria.epoch_at(GeVInv::ZERO, hot, 1e27), CosmicEpoch::Planck);
+        assert_eq!(criteria.epoch_at(crossing, hot, 1e27), CosmicEpoch::Inflation);
+        assert_eq!(criteria.epoch_at(after, hot, 1e27), CosmicEpoch::QuarkGluonPlasma);
+        assert_eq!(
+            criteria.onset_time(CosmicEpoch::QuarkGluonPlasma, &table),
+            GeVInv::from(Years(INFLATION_END_YEARS))
+        );
+
+        assert!(criteria.end_inflation(crossing));
+        assert!(!criteria.end_inflation(after), "inflation cannot end twice");
+        assert_eq!(criteria.epoch_at(during, hot, 1e27), CosmicEpoch::Inflation);
+        assert_eq!(criteria.epoch_at(crossing, hot, 1e27), CosmicEpoch::QuarkGluonPlasma);
+        assert_eq!(criteria.onset_time(CosmicEpoch::QuarkGluonPlasma, &table), crossing);
+    }
+
+    #[test]
+    fn test_epochs_follow_thermal_history() {
+        let criteria = planck_criteria();
+        let config = CosmologyConfig::default();
+        let table = BackgroundTable::from_config(&config).unwrap();
+
+        let epoch_at = |years: f64| {
+            let t = GeVInv::from(Years(years));
+            criteria.epoch_at(t, table.temperature_at(t), table.redshift_at(t))
+        };
+        let second = Years::from(Seconds(1.0)).value();
+        assert_eq!(epoch_at(1e-12 * second), CosmicEpoch::QuarkGluonPlasma);
+        assert_eq!(epoch_at(1e-3 * second), CosmicEpoch::Hadron);
+        // Leptons before BBN still belong to the preceding era
+        assert_eq!(epoch_at(10.0 * second), CosmicEpoch::Hadron);
+        assert_eq!(epoch_at(600.0 * second), CosmicEpoch::Nucleosynthesis);
+        assert_eq!(epoch_at(1e4), CosmicEpoch::Recombination);
+        assert_eq!(epoch_at(1e7), CosmicEpoch::DarkAges);
+        assert_eq!(epoch_at(5e8), CosmicEpoch::CosmicDawn);
+        assert_eq!(epoch_at(13.7e9), CosmicEpoch::Structure);
+
+        // Onsets are in chronological order and near their textbook times
+        let onsets: Vec<f64> = CosmicEpoch::ALL
+            .iter()
+            .map(|&epoch| onset_years(&criteria, epoch, &config))
+            .collect();
+        assert!(onsets.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", onsets);
+        let dark_ages = onsets[CosmicEpoch::DarkAges.index() as usize];
+        assert!((2e5..5e5).contains(&dark_ages), "Dark Ages begin at {} yr", dark_ages);
+        let structure = onsets[CosmicEpoch::Structure.index() as usize];
+        assert!((0.8e9..1.1e9).contains(&structure), "Structure begins at {} yr", structure);
+    }
+
+    #[test]
+    fn test_criteria_rebuild_when_config_changes() {
+        let mut app = App::new();
+        app.add_systems(PreUpdate, rebuild_epoch_criteria)
+            .insert_resource(EpochConfig::default());
+        app.update();
+        assert_eq!(*app.world().resource::<EpochCriteria>(), planck_criteria());
+
+        app.world_mut().resource_mut::<EpochConfig>().star_formation_redshift = 20.0;
+        app.insert_resource(CosmologyPreset::HighBaryon.config());
+        app.update();
+        let criteria = app.world().resource::<EpochCriteria>();
+        assert_eq!(criteria.thresholds().star_formation_redshift, 20.0);
+        assert!(criteria.baryon_to_photon_ratio() > planck_criteria().baryon_to_photon_ratio());
+    }
+
+    #[test]
+    fn test_onsets_shift_with_cosmology() {
+        let planck = CosmologyConfig::default();
+        let criteria = planck_criteria();
+
+        // More baryons recombine earlier, at a higher temperature
+        let high_baryon = CosmologyPreset::HighBaryon.config();
+        let dense = EpochCriteria::from_config(&EpochConfig::default(), &high_baryon);
+        assert!(dense.recombination_temperature() > criteria.recombination_temperature());
+        assert!(
+            onset_years(&dense, CosmicEpoch::DarkAges, &high_baryon)
+                < onset_years(&criteria, CosmicEpoch::DarkAges, &planck)
+        );
+
+        // Less matter delays the first stars at a fixed redshift
+        let mut light = planck.clone();
+        light.omega_m = 0.2;
+        light.omega_b = 0.03;
+        light.omega_lambda = 1.0 - light.omega_m - light.omega_r;
+        assert!(
+            onset_years(&criteria, CosmicEpoch::CosmicDawn, &light)
+                > onset_years(&criteria, CosmicEpoch::CosmicDawn, &planck)
+        );
+
+        // Thresholds move the boundaries too
+        let late = EpochCriteria::from_config(
+            &EpochConfig {
+                star_formation_redshift: 15.0,
+                ..Default::default()
+            },
+            &planck,
+        );
+        assert!(
+            onset_years(&late, CosmicEpoch::CosmicDawn, &planck)
+                > onset_years(&criteria, CosmicEpoch::CosmicDawn, &planck)
+        );
+    }
+
+    #[test]
+    fn test_qcd_temperature_moves_hadron_onset() {
+        let config = CosmologyConfig::default();
+        let table = BackgroundTable::from_config(&config).unwrap();
+        let criteria = planck_criteria();
+        let hotter = EpochCriteria::from_config(
+            &EpochConfig {
+                qcd_temperature_mev: 250.0,
+                ..Default::default()
+            },
+            &config,
+        );
+
+        // Confinement at a higher temperature happens earlier
+        let onset = onset_years(&criteria, CosmicEpoch::Hadron, &config);
+        let earlier = onset_years(&hotter, CosmicEpoch::Hadron, &config);
+        assert!(earlier < onset, "{} should precede {}", earlier, onset);
+
+        let t = GeVInv::from(Years((earlier * onset).sqrt()));
+        let (temperature, redshift) = (table.temperature_at(t), table.redshift_at(t));
+        assert_eq!(
+            criteria.epoch_at(t, temperature, redshift),
+            CosmicEpoch::QuarkGluonPlasma
+        );
+        assert_eq!(hotter.epoch_at(t, temperature, redshift), CosmicEpoch::Hadron);
+    }
+}
@@ -0,0 +1,419 @@
+//! Cosmic epochs as [`EpochPlugin`]s
+//!
+//! Every [`CosmicEpoch`] from inflation onwards is registered with the
+//! [`EpochManager`](genesis_core::epoch::EpochManager), which gates the epoch's
+//! expansion systems so that only the active epoch steps the scale factor:
+//!
+//! | Plugin              | Epochs                       | Expansion system               |
+//! |---------------------|------------------------------|--------------------------------|
+//! | [`InflationEpoch`]  | Inflation                    | [`integrate_inflation`]        |
+//! | [`BackgroundEpoch`] | Quark-Gluon Plasma and later | [`advance_along_background`]   |
+//!
+//! The time ranges are the onsets of [`EpochCriteria::onset_time`] along a
+//! [`BackgroundTable`] (see [`cosmic_epoch_ranges`]), and
+//! [`reschedule_cosmic_epochs`] moves them whenever either is rebuilt, so that the
+//! manager's [`EpochEntered`](genesis_core::EpochEntered)/[`EpochExited`](genesis_core::EpochExited)
+//! events and [`EpochTransition`](genesis_core::EpochTransition) blend follow the
+//! configured thresholds. The Planck epoch before inflation is the Singularity
+//! epoch of genesis-core, during which the scale factor is not evolved.
+
+use bevy::prelude::*;
+use genesis_core::epoch::{EpochManager, EpochPanel, EpochPlugin, EpochSystems};
+use genesis_core::time::{FixedTimestep, SimulationClock, INFLATION_START_YEARS};
+use genesis_core::units::{GeVInv, Years};
+
+use super::{BackgroundTable, CosmicEpoch, Cosmology, EpochCriteria, ExpansionSet};
+
+/// Time range `[start_year, end_year)` of every epoch from inflation onwards
+///
+/// # Arguments
+/// * `criteria` - Thresholds between the epochs
+/// * `table` - Tabulated background on which the thresholds are crossed
+///
+/// # Returns
+/// One `(epoch, start_year, end_year)` entry per epoch in chronological order,
+/// starting exactly at `INFLATION_START_YEARS`, where the Singularity ends, with
+/// the last one lasting forever
+pub fn cosmic_epoch_ranges(
+    criteria: &EpochCriteria,
+    table: &BackgroundTable,
+) -> Vec<(CosmicEpoch, f64, f64)> {
+    let mut onsets: Vec<(CosmicEpoch, f64)> = CosmicEpoch::ALL[CosmicEpoch::Inflation.index() as usize..]
+        .iter()
+        .map(|&epoch| (epoch, Years::from(criteria.onset_time(epoch, table)).value()))
+        .collect();
+    // Undo the rounding of the trip through GeV⁻¹, so that no gap or overlap is left
+    onsets[0].1 = INFLATION_START_YEARS;
+
+    onsets
+        .iter()
+        .enumerate()
+        .map(|(i, &(epoch, start))| {
+            let end = onsets.get(i + 1).map_or(f64::INFINITY, |&(_, next)| next);
+            (epoch, start, end)
+        })
+        .collect()
+}
+
+/// System that moves the registered epochs to the ranges of the current thresholds
+///
+/// Runs in `PreUpdate` after [`EpochCriteria`] and the [`BackgroundTable`] are rebuilt
+/// and before the manager activates an epoch. Without a table (a universe without a
+/// Big Bang) the epochs keep their previous ranges.
+pub fn reschedule_cosmic_epochs(
+    manager: Option<ResMut<EpochManager>>,
+    criteria: Option<Res<EpochCriteria>>,
+    table: Option<Res<BackgroundTable>>,
+) {
+    let (Some(mut manager), Some(criteria), Some(table)) = (manager, criteria, table) else {
+        return;
+    };
+    if !criteria.is_changed() && !table.is_changed() {
+        return;
+    }
+
+    let ranges: Vec<_> = cosmic_epoch_ranges(&criteria, &table)
+        .into_iter()
+        .map(|(epoch, start_year, end_year)| (epoch.name(), start_year, end_year))
+        .collect();
+    if let Err(error) = manager.reschedule(&ranges) {
+        error!("{}", error);
+    }
+}
+
+/// Panel showing the inflaton's slow-roll parameters, contributed by [`InflationEpoch`]
+pub const SLOW_ROLL_PANEL: EpochPanel = EpochPanel {
+    id: "slow_roll",
+    title: "Slow Roll",
+};
+
+/// The inflationary epoch, during which the scale factor grows exponentially
+///
+/// Register it with [`AppEpochExt::add_epoch`](genesis_core::AppEpochExt::add_epoch).
+pub struct InflationEpoch {
+    /// Start of inflation in cosmic years
+    pub start_year: f64,
+    /// End of inflation in cosmic years
+    pub end_year: f64,
+}
+
+impl EpochPlugin for InflationEpoch {
+    fn name(&self) -> &'static str {
+        CosmicEpoch::Inflation.name()
+    }
+
+    fn ui_panels(&self) -> Vec<EpochPanel> {
+        vec![SLOW_ROLL_PANEL]
+    }
+
+    fn start_year(&self) -> f64 {
+        self.start_year
+    }
+
+    fn end_year(&self) -> f64 {
+        self.end_year
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            integrate_inflation
+                .in_set(ExpansionSet::Integrate)
+                .in_set(EpochSystems(self.name())),
+        );
+    }
+}
+
+/// An epoch after inflation, during which the scale factor follows the ΛCDM background
+///
+/// Register it with [`AppEpochExt::add_epoch`](genesis_core::AppEpochExt::add_epoch).
+pub struct BackgroundEpoch {
+    /// The epoch this plugin stands for
+    pub epoch: CosmicEpoch,
+    /// Start of the epoch in cosmic years
+    pub start_year: f64,
+    /// End of the epoch in cosmic years
+    pub end_year: f64,
+}
+
+impl EpochPlugin for BackgroundEpoch {
+    fn name(&self) -> &'static str {
+        self.epoch.name()
+    }
+
+    fn start_year(&self) -> f64 {
+        self.start_year
+    }
+
+    fn end_year(&self) -> f64 {
+        self.end_year
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            advance_along_background
+                .in_set(ExpansionSet::Integrate)
+                .in_set(EpochSystems(self.name())),
+        );
+    }
+}
+
+/// Start of each substep of the fixed step just taken, with the substep length
+///
+/// The cosmic time advanced by the clock (linear or logarithmic stepping) is split
+/// into [`FixedTimestep::substeps`] equal substeps.
+fn substeps(clock: &SimulationClock, timestep: &FixedTimestep) -> impl Iterator<Item = (Years, GeVInv)> {
+    let count = timestep.substeps();
+    let substep_years = clock.delta() / f64::from(count);
+    let start = Years(clock.years()) - clock.delta();
+    (0..count).map(move |i| (start + substep_years * f64::from(i), GeVInv::from(substep_years)))
+}
+
+/// The [`BackgroundTable`], if it was built from the background being followed
+fn matching_table<'a>(
+    table: Option<&'a BackgroundTable>,
+    cosmology: &Cosmology,
+) -> Option<&'a BackgroundTable> {
+    table.filter(|table| *table.background() == cosmology.background)
+}
+
+/// System that inflates the scale factor exponentially, a(t) = a₀e^(Ht)
+///
+/// Runs while [`InflationEpoch`] is active. The state is placed on the inflationary
+/// trajectory that ends on the background (see [`Cosmology::enter_inflation`])
+/// whenever a substep starts at a different cosmic time than the state has reached,
+/// for example when inflation begins. Substeps that start after the end of
+/// inflation are left to the next epoch, and the substep that reaches the end
+/// places the state on the background.
+pub fn integrate_inflation(
+    mut cosmology: ResMut<Cosmology>,
+    clock: Res<SimulationClock>,
+    timestep: Res<FixedTimestep>,
+    manager: Res<EpochManager>,
+    table: Option<Res<BackgroundTable>>,
+) {
+    let Some((start, end)) = manager
+        .active()
+        .map(|epoch| (GeVInv::from(Years(epoch.start_year)), GeVInv::from(Years(epoch.end_year))))
+    else {
+        return;
+    };
+    let table = matching_table(table.as_deref(), &cosmology);
+    let a_end = match table {
+        Some(table) => table.scale_factor_at(end),
+        None => cosmology.background.scale_factor_at(end).unwrap_or(1.0),
+    };
+
+    for (substep_start, dt) in substeps(&clock, &timestep) {
+        let t = GeVInv::from(substep_start);
+        if t >= end {
+            break;
+        }
+        if (cosmology.scale_factor.time - t).value().abs() > 1e-9 * t.value() {
+            cosmology.enter_inflation(t, start, a_end);
+        }
+        cosmology.integrate_scale_factor_inflation(dt, a_end);
+
+        // The end of inflation hands over to the background
+        let t = cosmology.scale_factor.time;
+        if t >= end {
+            cosmology.enter_background(t, table);
+        }
+    }
+}
+
+/// System that advances the scale factor along the ΛCDM [`Background`](super::Background)
+///
+/// Runs while any [`BackgroundEpoch`] is active, solving the full Friedmann equation
+/// with [`Cosmology::advance_background`]. The state is placed on the background
+/// whenever a substep starts at a different cosmic time than the state has reached,
+/// for example after a seek.
+pub fn advance_along_background(
+    mut cosmology: ResMut<Cosmology>,
+    clock: Res<SimulationClock>,
+    timestep: Res<FixedTimestep>,
+    table: Option<Res<BackgroundTable>>,
+) {
+    let table = matching_table(table.as_deref(), &cosmology);
+    for (start, dt) in substeps(&clock, &timestep) {
+        let t = GeVInv::from(start);
+        if (cosmology.scale_factor.time - t).value().abs() > 1e-9 * t.value() {
+            cosmology.enter_background(t, table);
+        }
+        cosmology.advance_background(dt, table);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+    use bevy::ecs::event::Events;
+    use genesis_core::config::{CosmologyConfig, EpochConfig};
+    use genesis_core::time::{TimeMode, INFLATION_END_YEARS};
+    use genesis_core::{EpochEntered, EpochExited, EpochTransition, TimeIntegrationPlugin};
+    use std::time::Duration;
+
+    use crate::cosmology::{constants, CosmologyPlugin};
+    use crate::inflaton::Inflaton;
+
+    #[test]
+    fn test_ranges_are_contiguous_from_inflation() {
+        let config = CosmologyConfig::default();
+        let criteria = EpochCriteria::from_config(&EpochConfig::default(), &config);
+        let table = BackgroundTable::from_config(&config).unwrap();
+        let ranges = cosmic_epoch_ranges(&criteria, &table);
+
+        assert_eq!(ranges.len(), CosmicEpoch::ALL.len() - 1);
+        assert_eq!(ranges[0], (CosmicEpoch::Inflation, INFLATION_START_YEARS, INFLATION_END_YEARS));
+        assert!(ranges.windows(2).all(|pair| pair[0].2 == pair[1].1));
+        assert_eq!(ranges.last().map(|range| range.2), Some(f64::INFINITY));
+    }
+
+    /// App that plays logarithmically from `years` at 10 decades per second
+    fn playing_app(years: f64) -> App {
+        let mut clock = SimulationClock::new();
+        clock.set_mode(TimeMode::Logarithmic, 10.0);
+        clock.seek_to(years);
+
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TimeIntegrationPlugin, CosmologyPlugin))
+            .insert_resource(clock)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(20)))
+            .insert_resource(FixedTimestep { hz: 50.0, substeps: 4 })
+            .insert_resource(CosmologyConfig::default())
+            .insert_resource(Cosmology::from_config(&CosmologyConfig::default()));
+        app
+    }
+
+    #[test]
+    fn test_inflation_hands_over_to_background() {
+        let mut app = playing_app(1e-33);
+        let active = |app: &App| app.world().resource::<EpochManager>().active().map(|epoch| epoch.name);
+
+        app.update();
+        assert_eq!(active(&app), Some("Inflation"));
+        while app.world().resource::<SimulationClock>().years() < 1e-31 {
+            app.update();
+        }
+        app.update();
+        assert_eq!(active(&app), Some("Quark-Gluon Plasma"));
+
+        // The background system has taken over from where inflation handed over
+        let cosmology = app.world().resource::<Cosmology>();
+        let expected = cosmology.background.scale_factor_at(cosmology.scale_factor.time).unwrap();
+        let relative = (cosmology.scale_factor.value / expected - 1.0).abs();
+        assert!(relative < 1e-4, "a is {:e} off the background", relative);
+    }
+
+    #[test]
+    fn test_inflation_stays_finite_across_its_window() {
+        let mut app = playing_app(INFLATION_START_YEARS);
+        // The first frames run no fixed step yet
+        while app.world().resource::<Cosmology>().scale_factor.time.value() == 0.0 {
+            app.update();
+        }
+
+        let end = GeVInv::from(Years(INFLATION_END_YEARS));
+        let mut scale_factors = Vec::new();
+        while app.world().resource::<Cosmology>().scale_factor.time < end {
+            let cosmology = app.world().resource::<Cosmology>();
+            let h = constants::INFLATION_HUBBLE_GEV;
+            assert_eq!(cosmology.hubble.value, h);
+            assert_eq!(cosmology.scale_factor.derivative, h * cosmology.scale_factor.value);
+            scale_factors.push(cosmology.scale_factor.value);
+            app.update();
+        }
+
+        assert!(scale_factors.iter().all(|a| a.is_finite() && *a > 0.0), "a went {:?}", scale_factors);
+        assert!(scale_factors.windows(2).all(|pair| pair[0] <= pair[1]), "a shrank during inflation");
+        let efolds = (scale_factors[scale_factors.len() - 1] / scale_factors[0]).ln();
+        assert!(efolds > 1.0 && efolds < constants::INFLATION_EFOLDS + 1e-9, "{} e-folds", efolds);
+
+        // Inflation ends on the background, which takes over from there
+        let cosmology = app.world().resource::<Cosmology>();
+        let expected = cosmology.background.scale_factor_at(cosmology.scale_factor.time).unwrap();
+        assert!((cosmology.scale_factor.value / expected - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_playback_through_inflation_sends_events_and_blends() {
+        let mut app = playing_app(1e-34);
+        let mut exited = Vec::new();
+        let mut entered = Vec::new();
+        let mut blends = Vec::new();
+
+        while app.world().resource::<SimulationClock>().years() < 1e-30 {
+            app.update();
+            let world = app.world_mut();
+            exited.extend(world.resource_mut::<Events<EpochExited>>().drain().map(|event| event.name));
+            entered.extend(world.resource_mut::<Events<EpochEntered>>().drain().map(|event| event.name));
+            let transition = world.resource::<EpochTransition>();
+            if transition.from == Some("Inflation") && transition.to == Some("Quark-Gluon Plasma") {
+                blends.push(transition.blend);
+            }
+        }
+
+        assert_eq!(exited, vec!["Inflation"]);
+        assert_eq!(entered, vec!["Inflation", "Quark-Gluon Plasma"]);
+        assert!(blends.len() >= 2, "blend seen on {} frames", blends.len());
+        assert!(blends.windows(2).all(|pair| pair[0] < pair[1]), "blend {:?} should rise", blends);
+        assert!(blends.iter().any(|&blend| blend < 0.5) && blends.iter().any(|&blend| blend > 0.5));
+    }
+
+    #[test]
+    fn test_slow_roll_end_moves_detected_and_managed_epoch_together() {
+        let mut app = playing_app(1e-40);
+        app.insert_resource(Inflaton::new(20.0));
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world().resource::<EpochManager>().is_active("Inflation"));
+        assert_eq!(app.world().resource::<Cosmology>().scale_factor.epoch, CosmicEpoch::Inflation);
+
+        // ε = 2/φ² passes 1
+        app.insert_resource(Inflaton::new(1.0));
+        app.update();
+        let crossing = app.world().resource::<EpochCriteria>().inflation_end();
+        assert!(Years::from(crossing).value() < INFLATION_END_YEARS);
+        app.update();
+
+        let world = app.world();
+        let manager = world.resource::<EpochManager>();
+        let detected = world.resource::<Cosmology>().scale_factor.epoch;
+        assert_eq!(detected, CosmicEpoch::QuarkGluonPlasma);
+        assert!(manager.is_active(detected.name()));
+
+        let criteria = world.resource::<EpochCriteria>();
+        let ranges = cosmic_epoch_ranges(criteria, world.resource::<BackgroundTable>());
+        let end = Years::from(crossing).value();
+        assert_eq!(ranges[0].2, end);
+        assert_eq!(manager.get("Inflation").map(|epoch| epoch.end_year), Some(end));
+        assert_eq!(manager.get(detected.name()).map(|epoch| epoch.start_year), Some(end));
+    }
+
+    #[test]
+    fn test_epochs_follow_configured_thresholds() {
+        let mut app = playing_app(1.0);
+        let epochs = EpochConfig {
+            bbn_start_temperature_mev: 0.05,
+            ..EpochConfig::default()
+        };
+        let config = CosmologyConfig::default();
+        app.insert_resource(epochs.clone());
+        app.update();
+
+        let criteria = EpochCriteria::from_config(&epochs, &config);
+        let table = BackgroundTable::from_config(&config).unwrap();
+        let (_, start_year, _) = cosmic_epoch_ranges(&criteria, &table)[3];
+        let manager = app.world().resource::<EpochManager>();
+        let nucleosynthesis = manager.get("Nucleosynthesis").unwrap();
+        assert_eq!(nucleosynthesis.start_year, start_year);
+        assert_eq!(manager.get("Hadron").map(|epoch| epoch.end_year), Some(start_year));
+
+        let default_criteria = EpochCriteria::from_config(&EpochConfig::default(), &config);
+        let default_start = cosmic_epoch_ranges(&default_criteria, &table)[3].1;
+        assert!(start_year > default_start, "a cooler BBN onset comes later");
+    }
+}
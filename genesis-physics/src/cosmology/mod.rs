@@ -14,6 +14,7 @@
 //!
 //! ```rust,no_run
 //! use bevy::prelude::*;
+//! use genesis_core::units::GeVInv;
 //! use genesis_physics::cosmology::{Cosmology, EnergyDensity, Curvature};
 //!
 //! fn update_cosmology(mut cosmology: ResMut<Cosmology>) {
@@ -24,7 +25,7 @@
 //!     cosmology.update_hubble();
 //!
 //!     // Integrate scale factor forward
-//!     cosmology.integrate_scale_factor_euler(1e-35);
+//!     cosmology.integrate_scale_factor_euler(GeVInv(1e-15));
 //! }
 //! ```
 //!
@@ -40,15 +41,43 @@
 //! - **a** = Scale factor
 //!
 //! In natural units (ℏ = c = 1), the equation becomes:
-//! H² = (8π/3) * M_pl² * ρ - k/a²
+//! H² = ρ / (3 M̄_pl²) - k/a²
 //!
-//! where M_pl is the reduced Planck mass ~ 2.435 × 10¹⁸ GeV.
+//! where M̄_pl = M_pl / √(8π) is the reduced Planck mass ~ 2.435 × 10¹⁸ GeV.
+//!
+//! # Units
+//!
+//! Cosmic time is tracked in natural units as [`GeVInv`] and temperatures as
+//! [`Kelvin`]; see [`genesis_core::units`] for the conversions from the clock's years.
+//!
+//! # Background
+//!
+//! After inflation the expansion follows the ΛCDM [`Background`] built from the
+//! `[cosmology]` config, normalised so that a = 1 today.
 
 use bevy::prelude::*;
-use bevy::time::Time;
 
-use crate::integrator::{rk4_step};
-use genesis_core::time::{TimeAccumulator, INFLATION_END_YEARS, SECONDS_PER_YEAR};
+use crate::integrator::Rk4;
+use genesis_core::config::{CosmologyConfig, EpochConfig};
+use genesis_core::epoch::{update_active_epoch, AppEpochExt};
+use genesis_core::session::BackgroundState;
+use genesis_core::time::{FixedTimestep, SimulationSet};
+use genesis_core::units::{GeV, GeVInv, Kelvin};
+
+pub mod background;
+pub mod distances;
+pub mod epochs;
+pub mod eras;
+pub mod thermal;
+
+pub use background::{rebuild_background_table, Background, BackgroundTable};
+pub use distances::Distances;
+pub use epochs::{detect_cosmic_epoch, rebuild_epoch_criteria, EpochCriteria};
+pub use eras::{
+    cosmic_epoch_ranges, reschedule_cosmic_epochs, BackgroundEpoch, InflationEpoch,
+    SLOW_ROLL_PANEL,
+};
+pub use thermal::ThermalHistory;
 
 /// Cosmological constants used in Friedmann equation calculations
 pub mod constants {
@@ -70,11 +99,26 @@ pub mod constants {
     /// Inflationary Hubble parameter in GeV: H ≈ 10¹⁴ GeV
     /// Converted to SI: 1 GeV ≈ 1.602 × 10⁻¹⁰ J, so H ≈ 1.6 × 10⁴ J
     pub const INFLATION_HUBBLE_GEV: f64 = 1e14;
+
+    /// Number of e-folds by which inflation expands the universe
+    pub const INFLATION_EFOLDS: f64 = 60.0;
+
+    /// Largest number of e-folds a single inflation step is allowed to take
+    pub const MAX_INFLATION_EFOLDS_PER_STEP: f64 = 1.0;
     
     /// 1 GeV in Joules
     pub const GEV_TO_JOULES: f64 = 1.602e-10;
+
+    /// Largest H·dt a single RK4 step of the background is trusted with
+    pub const MAX_RK4_EXPANSION: f64 = 0.1;
 }
 
+/// Reduced Planck mass squared in GeV²: (2.435 × 10^18)^2 = 5.929225 × 10^36
+const M_PL_SQUARED: f64 = 5.929225e36;
+
+/// 8π/(3 M_pl²) = 1/(3 M̄_pl²) factor of the Friedmann equation in natural units
+const FRIEDMANN_PREFACTOR: f64 = 1.0 / (3.0 * M_PL_SQUARED);
+
 /// Curvature parameter of the universe
 /// 
 /// -1: Open universe (hyperbolic geometry)
@@ -91,6 +135,15 @@ impl Curvature {
     pub fn to_f64(self) -> f64 {
         self as i32 as f64
     }
+
+    /// Curvature named by `cosmology.curvature` ("open", "closed", otherwise flat)
+    pub fn from_config(config: &CosmologyConfig) -> Self {
+        match config.curvature.as_str() {
+            "open" => Curvature::Open,
+            "closed" => Curvature::Closed,
+            _ => Curvature::Flat,
+        }
+    }
 }
 
 /// Energy density of the universe
@@ -100,7 +153,7 @@ impl Curvature {
 /// - Radiation density (photons, neutrinos)
 /// - Dark energy density (cosmological constant)
 /// - Inflaton field energy density (during inflation)
-#[derive(Debug, Clone, Copy, Resource)]
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
 pub struct EnergyDensity {
     /// Total energy density in GeV⁴ (natural units)
     pub total: f64,
@@ -166,8 +219,10 @@ pub enum CosmicEpoch {
     Planck,
     /// Inflation epoch: 10⁻³⁶s – 10⁻³²s (exponential metric expansion)
     Inflation,
-    /// Quark-Gluon Plasma: 10⁻³²s – 10⁻⁶s (QGP cooling, confinement)
+    /// Quark-Gluon Plasma: 10⁻³²s – 10⁻⁵s (free quarks and gluons)
     QuarkGluonPlasma,
+    /// Hadron epoch: 10⁻⁵s – 3 min (quarks confined into hadrons)
+    Hadron,
     /// Big Bang Nucleosynthesis: 3 min – 20 min (light element formation)
     Nucleosynthesis,
     /// Recombination: ~380,000 yr (electron capture, CMB release)
@@ -180,6 +235,46 @@ pub enum CosmicEpoch {
     Structure,
 }
 
+impl CosmicEpoch {
+    /// All epochs in chronological order
+    pub const ALL: [CosmicEpoch; 9] = [
+        CosmicEpoch::Planck,
+        CosmicEpoch::Inflation,
+        CosmicEpoch::QuarkGluonPlasma,
+        CosmicEpoch::Hadron,
+        CosmicEpoch::Nucleosynthesis,
+        CosmicEpoch::Recombination,
+        CosmicEpoch::DarkAges,
+        CosmicEpoch::CosmicDawn,
+        CosmicEpoch::Structure,
+    ];
+
+    /// Position of this epoch in [`CosmicEpoch::ALL`]
+    pub fn index(self) -> u8 {
+        self as u8
+    }
+
+    /// Epoch at `index` in [`CosmicEpoch::ALL`], if any
+    pub fn from_index(index: u8) -> Option<Self> {
+        Self::ALL.get(index as usize).copied()
+    }
+
+    /// Display name, also used to register the epoch with the epoch manager
+    pub fn name(self) -> &'static str {
+        match self {
+            CosmicEpoch::Planck => "Planck",
+            CosmicEpoch::Inflation => "Inflation",
+            CosmicEpoch::QuarkGluonPlasma => "Quark-Gluon Plasma",
+            CosmicEpoch::Hadron => "Hadron",
+            CosmicEpoch::Nucleosynthesis => "Nucleosynthesis",
+            CosmicEpoch::Recombination => "Recombination",
+            CosmicEpoch::DarkAges => "Dark Ages",
+            CosmicEpoch::CosmicDawn => "Cosmic Dawn",
+            CosmicEpoch::Structure => "Structure Formation",
+        }
+    }
+}
+
 /// Scale factor a(t) of the universe
 /// 
 /// The scale factor describes how the size of the universe changes over time.
@@ -194,8 +289,8 @@ pub struct ScaleFactor {
     /// Time derivative of scale factor: ȧ = d(a)/dt
     pub derivative: f64,
     
-    /// Cosmic time in seconds
-    pub time: f64,
+    /// Cosmic time integrated so far, in natural units
+    pub time: GeVInv,
     
     /// Current cosmic epoch
     pub epoch: CosmicEpoch,
@@ -209,7 +304,7 @@ impl Default for ScaleFactor {
         Self {
             value: 1.0,
             derivative: 0.0,
-            time: 0.0,
+            time: GeVInv::ZERO,
             epoch: CosmicEpoch::Planck,
             temperature: Temperature::default(),
         }
@@ -223,19 +318,16 @@ impl Default for ScaleFactor {
 /// - Initial temperature T₀ ≈ 10²⁷ K at the start of inflation
 #[derive(Debug, Clone, Copy, PartialEq, Resource)]
 pub struct Temperature {
-    /// Current temperature in Kelvin
-    pub value: f64,
+    /// Current temperature
+    pub value: Kelvin,
 
     /// Initial temperature T₀ (approximately 1e27 K at inflation start)
-    pub initial: f64,
+    pub initial: Kelvin,
 }
 
 impl Default for Temperature {
     fn default() -> Self {
-        Self {
-            value: 1e27,
-            initial: 1e27,
-        }
+        Self::new(Kelvin(1e27))
     }
 }
 
@@ -243,11 +335,11 @@ impl Temperature {
     /// Create a new temperature with specified initial temperature
     ///
     /// # Arguments
-    /// * `initial` - Initial temperature T₀ in Kelvin
+    /// * `initial` - Initial temperature T₀
     ///
     /// # Returns
     /// A new Temperature instance with value and initial both set to the given initial temperature
-    pub fn new(initial: f64) -> Self {
+    pub fn new(initial: Kelvin) -> Self {
         Self { value: initial, initial }
     }
 }
@@ -256,27 +348,28 @@ impl Temperature {
 ///
 /// # Arguments
 /// * `a0` - Initial scale factor
-/// * `t_elapsed` - Elapsed time during inflation (in GeV⁻¹)
-/// * `h` - Hubble parameter H during inflation (in GeV)
+/// * `t_elapsed` - Elapsed time during inflation
+/// * `h` - Hubble parameter H during inflation
 ///
 /// # Returns
 /// Scale factor value at time t
 ///
 /// # Example
 /// ```
+/// use genesis_core::units::{GeV, GeVInv};
 /// use genesis_physics::cosmology::compute_exponential_scale_factor;
-/// let a = compute_exponential_scale_factor(1.0, 1e-35, 1e14);
+/// let a = compute_exponential_scale_factor(1.0, GeVInv(1e-35), GeV(1e14));
 /// // a ≈ 1.0 + small expansion
 /// ```
-pub fn compute_exponential_scale_factor(a0: f64, t_elapsed: f64, h: f64) -> f64 {
+pub fn compute_exponential_scale_factor(a0: f64, t_elapsed: GeVInv, h: GeV) -> f64 {
     a0 * (h * t_elapsed).exp()
 }
 
 /// Compute matter-dominated scale factor: a(t) = (t/t_eq)^(2/3)
 ///
 /// # Arguments
-/// * `t` - Cosmic time (in any time units)
-/// * `t_eq` - Matter-radiation equality time (normalization constant, same units as t)
+/// * `t` - Cosmic time
+/// * `t_eq` - Matter-radiation equality time (normalization constant)
 ///
 /// # Returns
 /// Scale factor value at time t during the matter-dominated era
@@ -289,13 +382,14 @@ pub fn compute_exponential_scale_factor(a0: f64, t_elapsed: f64, h: f64) -> f64
 ///
 /// # Example
 /// ```
+/// use genesis_core::units::GeVInv;
 /// use genesis_physics::cosmology::compute_matter_dominated_scale_factor;
 /// // At t = 2*t_eq, the scale factor should be (2)^(2/3) ≈ 1.587
-/// let a = compute_matter_dominated_scale_factor(2.0, 1.0);
+/// let a = compute_matter_dominated_scale_factor(GeVInv(2.0), GeVInv(1.0));
 /// ```
-pub fn compute_matter_dominated_scale_factor(t: f64, t_eq: f64) -> f64 {
+pub fn compute_matter_dominated_scale_factor(t: GeVInv, t_eq: GeVInv) -> f64 {
     // Handle t <= 0 gracefully to avoid division issues
-    if t <= 0.0 {
+    if t <= GeVInv::ZERO {
         return 0.0;
     }
     
@@ -310,11 +404,11 @@ pub fn compute_matter_dominated_scale_factor(t: f64, t_eq: f64) -> f64 {
 ///
 /// # Arguments
 /// * `scale_factor` - The cosmological scale factor a (dimensionless)
-/// * `initial_temp` - The initial temperature T₀ at a=1 (in Kelvin)
+/// * `initial_temp` - The initial temperature T₀ at a=1
 ///
 /// # Returns
-/// The temperature in Kelvin at the given scale factor
-pub fn compute_temperature(scale_factor: f64, initial_temp: f64) -> f64 {
+/// The temperature at the given scale factor
+pub fn compute_temperature(scale_factor: f64, initial_temp: Kelvin) -> Kelvin {
     initial_temp / scale_factor
 }
 
@@ -359,15 +453,23 @@ pub struct Cosmology {
     
     /// Curvature parameter (k = -1, 0, +1)
     pub curvature: Curvature,
+
+    /// Scale factor value before the most recent fixed physics step
+    pub previous_scale_factor: f64,
+
+    /// Present-day parameters the post-inflation expansion is integrated for
+    pub background: Background,
 }
 
 impl Default for Cosmology {
     fn default() -> Self {
         Self {
+            previous_scale_factor: ScaleFactor::default().value,
             scale_factor: ScaleFactor::default(),
             hubble: HubbleParameter::default(),
             energy_density: EnergyDensity::default(),
             curvature: Curvature::Flat,
+            background: Background::default(),
         }
     }
 }
@@ -386,13 +488,130 @@ impl Cosmology {
         }
     }
 
+    /// Create the present-day (a = 1) cosmology state from the `[cosmology]` config
+    ///
+    /// Each component density is ρᵢ = Ωᵢ ρ_crit, with the critical density chosen so
+    /// that [`Cosmology::compute_hubble`] of the flat total reproduces H₀. The
+    /// temperature starts at the CMB temperature, from which the [`ThermalHistory`]
+    /// fixes T(a) along the background.
+    ///
+    /// # Arguments
+    /// * `config` - Cosmological parameters (density parameters, H₀, curvature)
+    pub fn from_config(config: &CosmologyConfig) -> Self {
+        let curvature = Curvature::from_config(config);
+        let background = Background::from_config(config);
+        let h0 = background.hubble_today();
+
+        Self {
+            scale_factor: ScaleFactor {
+                temperature: Temperature::new(Kelvin(config.t_cmb)),
+                ..Default::default()
+            },
+            hubble: HubbleParameter {
+                value: h0,
+                squared: h0 * h0,
+            },
+            energy_density: background.present,
+            curvature,
+            background,
+            ..Default::default()
+        }
+    }
+
+    /// Scale factor blended between the previous and current fixed physics step
+    ///
+    /// Interpolates geometrically, since a(t) changes by orders of magnitude within a
+    /// step during inflation.
+    ///
+    /// # Arguments
+    /// * `alpha` - Progress towards the next step in `[0, 1]`, see
+    ///   [`interpolation_alpha`](genesis_core::time::interpolation_alpha)
+    pub fn interpolated_scale_factor(&self, alpha: f64) -> f64 {
+        let (previous, current) = (self.previous_scale_factor, self.scale_factor.value);
+        if previous > 0.0 && current > 0.0 {
+            previous * (current / previous).powf(alpha)
+        } else {
+            previous + (current - previous) * alpha
+        }
+    }
+
+    /// Distance and age calculator for this cosmology's background and curvature
+    pub fn distances(&self) -> Distances {
+        Distances::new(self.background, self.curvature)
+    }
+
+    /// Redshift z = 1/a − 1 of the current scale factor
+    pub fn redshift(&self) -> f64 {
+        1.0 / self.scale_factor.value - 1.0
+    }
+
+    /// Snapshot of the background for a saved session
+    pub fn state(&self) -> BackgroundState {
+        BackgroundState {
+            scale_factor: self.scale_factor.value,
+            previous_scale_factor: self.previous_scale_factor,
+            scale_factor_derivative: self.scale_factor.derivative,
+            time_gev_inv: self.scale_factor.time.value(),
+            epoch: self.scale_factor.epoch.index(),
+            temperature_k: self.scale_factor.temperature.value.value(),
+            initial_temperature_k: self.scale_factor.temperature.initial.value(),
+            hubble_gev: self.hubble.value,
+            total_density: self.energy_density.total,
+            matter_density: self.energy_density.matter,
+            radiation_density: self.energy_density.radiation,
+            dark_energy_density: self.energy_density.dark_energy,
+            inflaton_density: self.energy_density.inflaton,
+            curvature: self.curvature as i8,
+        }
+    }
+
+    /// Restores the background from a saved session snapshot
+    ///
+    /// An unknown epoch index falls back to [`CosmicEpoch::Planck`] and an unknown
+    /// curvature to [`Curvature::Flat`]. The snapshot does not carry the present-day
+    /// [`Background`]; restore it from the session's config.
+    pub fn from_state(state: &BackgroundState) -> Self {
+        let curvature = match state.curvature {
+            -1 => Curvature::Open,
+            1 => Curvature::Closed,
+            _ => Curvature::Flat,
+        };
+
+        Self {
+            scale_factor: ScaleFactor {
+                value: state.scale_factor,
+                derivative: state.scale_factor_derivative,
+                time: GeVInv(state.time_gev_inv),
+                epoch: CosmicEpoch::from_index(state.epoch).unwrap_or(CosmicEpoch::Planck),
+                temperature: Temperature {
+                    value: Kelvin(state.temperature_k),
+                    initial: Kelvin(state.initial_temperature_k),
+                },
+            },
+            hubble: HubbleParameter {
+                value: state.hubble_gev,
+                squared: state.hubble_gev * state.hubble_gev,
+            },
+            energy_density: EnergyDensity {
+                total: state.total_density,
+                matter: state.matter_density,
+                radiation: state.radiation_density,
+                dark_energy: state.dark_energy_density,
+                inflaton: state.inflaton_density,
+            },
+            curvature,
+            previous_scale_factor: state.previous_scale_factor,
+            background: Background::default(),
+        }
+    }
+
     /// Compute the Hubble parameter H using the Friedmann equation
     ///
     /// Friedmann equation: H² = (8πG/3)ρ - k/a²
     ///
     /// In natural units (ℏ = c = 1), this becomes:
-    /// H² = (8π/3) * M_pl² * ρ - k/a²
-    /// where M_pl is the reduced Planck mass ~ 2.435 × 10¹⁸ GeV
+    /// H² = ρ / (3 M̄_pl²) - k/a²
+    /// where M̄_pl is the reduced Planck mass ~ 2.435 × 10¹⁸ GeV
     ///
     /// # Arguments
     /// * `energy_density` - Total energy density ρ in GeV⁴
@@ -403,24 +622,18 @@ impl Cosmology {
     /// The Hubble parameter H in GeV
     ///
     /// # Notes
-    /// Uses natural units where ℏ = c = 1. The Planck mass M_pl appears
-    /// because G = 1/M_pl² in natural units.
+    /// Uses natural units where ℏ = c = 1. The Planck mass appears because
+    /// G = 1/M_pl² = 1/(8π M̄_pl²) in natural units.
     pub fn compute_hubble(
         energy_density: f64,
         scale_factor: f64,
         curvature: Curvature,
     ) -> f64 {
-        // Planck mass squared in GeV²: (2.435 × 10^18)^2 = 5.929225 × 10^36
-        const M_PL_SQUARED: f64 = 5.929225e36;
-
-        // (8π/3) * M_pl² factor
-        const PREFACTOR: f64 = (8.0 * std::f64::consts::PI / 3.0) * M_PL_SQUARED;
-
         // Curvature term: k/a²
         let curvature_term = curvature.to_f64() / (scale_factor * scale_factor);
 
-        // Friedmann equation: H² = (8π/3)M_pl²ρ - k/a²
-        let h_squared = PREFACTOR * energy_density - curvature_term;
+        // Friedmann equation: H² = ρ/(3M̄_pl²) - k/a²
+        let h_squared = FRIEDMANN_PREFACTOR * energy_density - curvature_term;
 
         // Ensure H² is non-negative (physical requirement)
         let h_squared = h_squared.max(0.0);
@@ -477,17 +690,17 @@ impl Cosmology {
     /// a(t + dt) = a(t) + ȧ * dt
     ///
     /// # Arguments
-    /// * `dt` - Time step in GeV⁻¹ (natural units)
+    /// * `dt` - Time step in natural units
     ///
     /// # Notes
     /// This is a simple Euler integration step. For higher accuracy, use
     /// the RK4 solver (to be implemented in a separate subtask).
-    pub fn integrate_scale_factor_euler(&mut self, dt: f64) {
+    pub fn integrate_scale_factor_euler(&mut self, dt: GeVInv) {
         // Update derivative first
         self.update_scale_factor_derivative();
 
         // Euler step: a(t + dt) = a(t) + ȧ * dt
-        self.scale_factor.value += self.scale_factor.derivative * dt;
+        self.scale_factor.value += self.scale_factor.derivative * dt.value();
 
         // Update time
         self.scale_factor.time += dt;
@@ -498,99 +711,169 @@ impl Cosmology {
 
     /// Perform a single integration step for the scale factor using RK4 method
     ///
-    /// This advances the scale factor forward in time by dt using the
-    /// 4th-order Runge-Kutta method, which provides higher accuracy than
-    /// the simple Euler method.
+    /// Advances the scale factor by dt along the [`Background`], solving the full
+    /// Friedmann equation
     ///
-    /// The differential equations being solved are:
-    /// - da/dt = H * a
-    /// - dȧ/dt = H * ȧ (ignoring the a*d(H)/dt term for simplicity)
+    /// ```text
+    /// da/dt = a H(a),   H²(a) = ρ(a) / (3 M̄_pl²) + Ω_k H₀² / a²
+    /// ```
     ///
-    /// The state vector is [a, ȧ] where:
-    /// - a = scale_factor.value (the scale factor)
-    /// - ȧ = scale_factor.derivative (the time derivative of scale factor)
+    /// with H re-evaluated from the evolving component densities at every RK4 stage.
+    /// Afterwards ȧ, H and the energy density are brought in line with the new a.
     ///
     /// # Arguments
     /// * `dt` - Time step in GeV⁻¹ (natural units)
-    ///
-    /// # Notes
-    /// The RK4 method uses four slope estimates to achieve 4th-order accuracy:
-    /// - k1 = f(t, y)
-    /// - k2 = f(t + dt/2, y + k1*dt/2)
-    /// - k3 = f(t + dt/2, y + k2*dt/2)
-    /// - k4 = f(t + dt, y + k3*dt)
-    ///
-    /// The derivative calculation for dȧ/dt simplifies to H*ȧ, which ignores
-    /// the a*d(H)/dt term that would require computing time derivatives of
-    /// density parameters. This is a reasonable approximation when the energy
-    /// density changes slowly compared to the expansion timescale.
-    pub fn integrate_scale_factor_rk4(&mut self, dt: f64) {
-        // Update derivative first to ensure ȧ is consistent with current H
-        self.update_scale_factor_derivative();
-
-        // Capture current H for use in the derivative function
-        // During the RK4 step, we assume H is constant (i.e., we ignore d(H)/dt)
-        let h = self.hubble.value;
-
-        // Define the derivative function for the state vector [a, ȧ]
-        // Returns [da/dt, dȧ/dt] = [ȧ, H*ȧ]
-        let derivative = |_t: f64, state: &[f64]| -> Vec<f64> {
-            let _a = state[0];      // scale factor value (not used in simplified form)
-            let a_dot = state[1];  // scale factor derivative
+    pub fn integrate_scale_factor_rk4(&mut self, dt: GeVInv) {
+        let background = self.background;
+        let derivative = |_t: f64, a: &f64, dadt: &mut f64| {
+            *dadt = a * background.hubble_at(*a);
+        };
 
-            // da/dt = ȧ
-            let da_dt = a_dot;
+        let mut a = self.scale_factor.value;
+        Rk4::new(&a).step(&mut a, self.scale_factor.time.value(), dt.value(), derivative);
+        self.scale_factor.value = a;
+        self.scale_factor.time += dt;
+        self.sync_with_background();
+    }
 
-            // dȧ/dt = d(H*a)/dt = H*d(a)/dt + a*d(H)/dt
-            // For simplicity, we assume H is constant during the step, so d(H)/dt ≈ 0
-            // Therefore: dȧ/dt ≈ H*ȧ
-            let da_dot_dt = h * a_dot;
+    /// Advance the post-inflation background by dt
+    ///
+    /// Steps that are long compared to the Hubble time would make RK4 overshoot, so
+    /// when H·dt exceeds [`constants::MAX_RK4_EXPANSION`] the scale factor is looked
+    /// up in the [`BackgroundTable`] instead. Without a table it falls back to the
+    /// bisection in [`Background::scale_factor_at`].
+    ///
+    /// # Arguments
+    /// * `dt` - Time step in GeV⁻¹
+    /// * `table` - Tabulated a(t) of this background, if one has been built
+    pub fn advance_background(&mut self, dt: GeVInv, table: Option<&BackgroundTable>) {
+        let expansion = self.background.hubble_at(self.scale_factor.value) * dt.value();
+        if expansion > constants::MAX_RK4_EXPANSION {
+            let t = self.scale_factor.time + dt;
+            match table {
+                Some(table) => {
+                    self.set_on_background(t, table.scale_factor_at(t));
+                    return;
+                }
+                None if self.match_background(t) => return,
+                None => {}
+            }
+        }
+        self.integrate_scale_factor_rk4(dt);
+    }
 
-            vec![da_dt, da_dot_dt]
+    /// Place the state on the [`Background`] at cosmic time `t`
+    ///
+    /// Used when the expansion enters the background at the end of inflation or
+    /// after the clock jumps, so that the integration continues from a(t) rather
+    /// than from wherever the previous state left off.
+    ///
+    /// # Arguments
+    /// * `t` - Cosmic time since the Big Bang
+    ///
+    /// # Returns
+    /// `false`, leaving the state untouched, when the background has no Big Bang
+    /// to measure `t` from
+    pub fn match_background(&mut self, t: GeVInv) -> bool {
+        let Some(a) = self.background.scale_factor_at(t) else {
+            return false;
         };
+        self.set_on_background(t, a);
+        true
+    }
 
-        // Current state vector: [a, ȧ]
-        let y = &[self.scale_factor.value, self.scale_factor.derivative];
-        let t = self.scale_factor.time;
-
-        // Perform RK4 step
-        let y_new = rk4_step(y, t, dt, derivative);
-
-        // Update scale factor from the new state
-        self.scale_factor.value = y_new[0];
-        self.scale_factor.derivative = y_new[1];
+    /// Place the state on the [`Background`] at cosmic time `t`, looking a(t) up in
+    /// the [`BackgroundTable`] when there is one
+    ///
+    /// Without a table this is [`Cosmology::match_background`].
+    pub fn enter_background(&mut self, t: GeVInv, table: Option<&BackgroundTable>) {
+        match table {
+            Some(table) => self.set_on_background(t, table.scale_factor_at(t)),
+            None => {
+                self.match_background(t);
+            }
+        }
+    }
 
-        // Update time
-        self.scale_factor.time += dt;
+    /// Place the state on the [`Background`] at cosmic time `t` and scale factor `a`
+    ///
+    /// Like [`Cosmology::match_background`] with a(t) already known, for example
+    /// from a [`BackgroundTable`].
+    pub fn set_on_background(&mut self, t: GeVInv, a: f64) {
+        self.scale_factor.value = a;
+        self.scale_factor.time = t;
+        self.sync_with_background();
+    }
+
+    /// Recompute ȧ, H and the energy density from the background at the current a
+    fn sync_with_background(&mut self) {
+        let a = self.scale_factor.value;
+        let h = self.background.hubble_at(a);
+        self.energy_density = self.background.density_at(a);
+        self.hubble.value = h;
+        self.hubble.squared = h * h;
+        self.scale_factor.derivative = h * a;
+    }
 
-        // Recompute Hubble for the new state
-        self.update_hubble();
+    /// Place the state on the inflationary trajectory at cosmic time `t`
+    ///
+    /// Inflation starts at `a_end`·e^(−N) with N = `INFLATION_EFOLDS` and grows as
+    /// e^(H(t − t_start)) until it reaches `a_end`, the scale factor of the background
+    /// at the end of inflation. The energy density is that of the inflaton which,
+    /// through the Friedmann equation, gives H = `INFLATION_HUBBLE_GEV`.
+    ///
+    /// # Arguments
+    /// * `t` - Cosmic time to place the state at
+    /// * `t_start` - Start of inflation
+    /// * `a_end` - Scale factor at the end of inflation
+    pub fn enter_inflation(&mut self, t: GeVInv, t_start: GeVInv, a_end: f64) {
+        let h = GeV(constants::INFLATION_HUBBLE_GEV);
+        let efolds = (h * (t - t_start)).clamp(0.0, constants::INFLATION_EFOLDS);
+        self.scale_factor.value = (a_end.ln() - constants::INFLATION_EFOLDS + efolds).exp();
+        self.scale_factor.time = t;
+        self.set_inflationary_hubble();
     }
 
     /// Update scale factor using exponential expansion during inflation epoch
     ///
     /// This method applies a(t) = a₀e^(Ht) where H is constant during inflation.
     /// During inflation, the Hubble parameter is approximately constant at
-    /// H ≈ 10¹⁴ GeV.
+    /// H ≈ 10¹⁴ GeV. The step advances ln a, taking at most
+    /// `MAX_INFLATION_EFOLDS_PER_STEP` e-folds and never growing past `a_end`, so
+    /// that a stays finite however long the step.
     ///
     /// # Arguments
     /// * `dt` - Time step in GeV⁻¹
+    /// * `a_end` - Scale factor at which inflation ends (infinite for no limit)
     ///
     /// # Side Effects
     /// - Updates `self.scale_factor.value` to the new exponential value
     /// - Updates `self.scale_factor.time` by adding `dt`
     /// - Updates `self.scale_factor.derivative` to H*a (consistent with ȧ = H*a for exponential expansion)
-    pub fn integrate_scale_factor_inflation(&mut self, dt: f64) {
-        let a0 = self.scale_factor.value;
-        let h = constants::INFLATION_HUBBLE_GEV;
-        let new_a = compute_exponential_scale_factor(a0, dt, h);
+    /// - Sets `self.hubble` and `self.energy_density` to those of the inflaton
+    pub fn integrate_scale_factor_inflation(&mut self, dt: GeVInv, a_end: f64) {
+        let ln_a = self.scale_factor.value.ln();
+        let h = GeV(constants::INFLATION_HUBBLE_GEV);
+        let efolds = (h * dt)
+            .min(constants::MAX_INFLATION_EFOLDS_PER_STEP)
+            .min((a_end.ln() - ln_a).max(0.0));
 
         // Update scale factor state
-        self.scale_factor.value = new_a;
+        self.scale_factor.value = (ln_a + efolds).exp();
         self.scale_factor.time += dt;
+        self.set_inflationary_hubble();
+    }
 
-        // For exponential expansion: ȧ = H * a
-        self.scale_factor.derivative = h * new_a;
+    /// Set H to the inflationary H, ȧ = H·a, and ρ to the inflaton energy density
+    /// 3 M̄_pl² H² that gives this H in the Friedmann equation
+    ///
+    /// Curvature is left out, as inflation drives it to zero.
+    fn set_inflationary_hubble(&mut self) {
+        let h = constants::INFLATION_HUBBLE_GEV;
+        self.energy_density = EnergyDensity::inflaton_dominated(h * h / FRIEDMANN_PREFACTOR);
+        self.hubble.value = h;
+        self.hubble.squared = h * h;
+        self.update_scale_factor_derivative();
     }
 
     /// Update scale factor using matter-dominated expansion post-inflation
@@ -612,8 +895,9 @@ impl Cosmology {
     /// - Updates `self.scale_factor.value` using `compute_matter_dominated_scale_factor()` with the new time
     /// - Updates `self.scale_factor.time` by adding `dt`
     /// - Updates `self.scale_factor.derivative` to (2/3) * a/t (consistent with matter-dominated expansion)
-    /// - Updates `self.scale_factor.epoch` to `CosmicEpoch::QuarkGluonPlasma`
-    pub fn integrate_scale_factor_matter_dominated(&mut self, dt: f64, t_eq: f64) {
+    ///
+    /// The epoch is left to [`detect_cosmic_epoch`].
+    pub fn integrate_scale_factor_matter_dominated(&mut self, dt: GeVInv, t_eq: GeVInv) {
         // Calculate new time
         let new_time = self.scale_factor.time + dt;
 
@@ -624,83 +908,101 @@ impl Cosmology {
         self.scale_factor.time = new_time;
 
         // For matter-dominated expansion: ȧ = (2/3) * a/t
-        self.scale_factor.derivative = (2.0 / 3.0) * self.scale_factor.value / self.scale_factor.time;
-
-        // Update epoch to Quark-Gluon Plasma (matter-dominated era follows inflation)
-        self.scale_factor.epoch = CosmicEpoch::QuarkGluonPlasma;
+        self.scale_factor.derivative =
+            (2.0 / 3.0) * self.scale_factor.value / self.scale_factor.time.value();
     }
 }
 
-/// Converts years to GeV⁻¹ (natural time units).
-///
-/// # Arguments
-/// * `years` - Time in years
-///
-/// # Returns
-/// Equivalent time in GeV⁻¹
-///
-/// # Formula
-/// In natural units, 1 GeV⁻¹ = ħ / 1 GeV ≈ 6.582 × 10⁻²⁵ s
-/// So 1 year = 31,557,600 s / (6.582 × 10⁻²⁵ s/GeV⁻¹) ≈ 4.79 × 10³¹ GeV⁻¹
-fn years_to_gev_inv(years: f64) -> f64 {
-    const H_BAR: f64 = 6.582e-25; // ħ in GeV·s
-    years * SECONDS_PER_YEAR / H_BAR
+/// Stages of a fixed physics step of the expansion, run in order in
+/// [`SimulationSet::Physics`]
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExpansionSet {
+    /// Systems of the active epoch that integrate the scale factor over the step
+    Integrate,
+    /// Temperature and epoch of the state just reached
+    Observe,
 }
 
-/// System that updates scale factor using the appropriate expansion law
-/// based on the current cosmic epoch.
-///
-/// # Epoch Behavior
-/// - **Inflation (1e-44 to 1e-32 years)**: Exponential expansion a(t) = a₀e^(Ht)
-/// - **Post-inflation (> 1e-32 years)**: RK4 integration using Friedmann equation
+/// System that remembers the scale factor before the fixed step, for
+/// [`Cosmology::interpolated_scale_factor`]
+pub fn record_previous_scale_factor(mut cosmology: ResMut<Cosmology>) {
+    cosmology.previous_scale_factor = cosmology.scale_factor.value;
+}
+
+/// System that sets the temperature from the scale factor the step reached
 ///
-/// # Arguments
-/// * `cosmology` - Mutable reference to cosmology state
-/// * `time_accumulator` - Time tracking resource (cosmic time in years)
-/// * `time` - Bevy's time resource for delta time calculation
-#[allow(dead_code)]
-pub fn update_scale_factor_by_epoch(
-    mut cosmology: ResMut<Cosmology>,
-    time_accumulator: Res<TimeAccumulator>,
-    time: Res<Time>,
-) {
-    // Get delta time in years
-    let delta_seconds = time.delta_secs() as f64;
-    let delta_years = delta_seconds * time_accumulator.acceleration / SECONDS_PER_YEAR;
-
-    // Convert delta time from years to GeV⁻¹ (natural units)
-    let dt_gev_inv = years_to_gev_inv(delta_years);
-
-    // Determine the current cosmic epoch and use the appropriate integration method
-    if time_accumulator.years < INFLATION_END_YEARS {
-        // During inflation: use exponential expansion
-        cosmology.integrate_scale_factor_inflation(dt_gev_inv);
-    } else {
-        // After inflation: use RK4 integration
-        cosmology.integrate_scale_factor_rk4(dt_gev_inv);
-    }
-
-    // Update temperature based on scale factor: T = T₀ / a
-    let temp = compute_temperature(cosmology.scale_factor.value, cosmology.scale_factor.temperature.initial);
+/// A [`BackgroundTable`] for the same background supplies the temperature from its
+/// [`ThermalHistory`]; without one the temperature falls as T₀ / a.
+pub fn update_temperature(mut cosmology: ResMut<Cosmology>, table: Option<Res<BackgroundTable>>) {
+    let a = cosmology.scale_factor.value;
+    let temp = match table.filter(|table| *table.background() == cosmology.background) {
+        Some(table) => table.thermal().photon_temperature(a),
+        None => compute_temperature(a, cosmology.scale_factor.temperature.initial),
+    };
     cosmology.scale_factor.temperature.value = temp;
 }
 
+/// Plugin that owns the [`Cosmology`] state and expands it on every fixed step
+///
+/// Registers the epochs from inflation onwards as [`InflationEpoch`] and
+/// [`BackgroundEpoch`], so that the scale factor is stepped by whichever epoch is
+/// active, followed by the shared temperature update and epoch detection. The epoch
+/// ranges follow the `[epochs]` and `[cosmology]` config.
 pub struct CosmologyPlugin;
 
 impl Plugin for CosmologyPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Cosmology>()
+            .init_resource::<FixedTimestep>()
             .init_resource::<ScaleFactor>()
             .init_resource::<HubbleParameter>()
             .init_resource::<EnergyDensity>()
             .init_resource::<Temperature>()
-            .add_systems(PostUpdate, update_scale_factor_by_epoch);
+            .add_systems(
+                PreUpdate,
+                (
+                    (rebuild_background_table, rebuild_epoch_criteria),
+                    reschedule_cosmic_epochs.before(update_active_epoch),
+                )
+                    .chain(),
+            )
+            .configure_sets(
+                FixedUpdate,
+                (ExpansionSet::Integrate, ExpansionSet::Observe)
+                    .chain()
+                    .in_set(SimulationSet::Physics),
+            )
+            .add_systems(
+                FixedUpdate,
+                record_previous_scale_factor
+                    .before(ExpansionSet::Integrate)
+                    .in_set(SimulationSet::Physics),
+            )
+            .add_systems(
+                FixedUpdate,
+                (update_temperature, detect_cosmic_epoch)
+                    .chain()
+                    .in_set(ExpansionSet::Observe),
+            );
+
+        // Each epoch from inflation onwards steps the expansion while it is active
+        let criteria = EpochCriteria::from_config(&EpochConfig::default(), &CosmologyConfig::default());
+        let table = BackgroundTable::from_config(&CosmologyConfig::default())
+            .expect("the default cosmology has a Big Bang");
+        for (epoch, start_year, end_year) in cosmic_epoch_ranges(&criteria, &table) {
+            if epoch == CosmicEpoch::Inflation {
+                app.add_epoch(InflationEpoch { start_year, end_year });
+            } else {
+                app.add_epoch(BackgroundEpoch { epoch, start_year, end_year });
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use genesis_core::units::Years;
     
     #[test]
     fn test_curvature_conversion() {
@@ -709,6 +1011,106 @@ mod tests {
         assert_eq!(Curvature::Closed.to_f64(), 1.0);
     }
     
+    #[test]
+    fn test_from_config_reproduces_h0() {
+        let config = CosmologyConfig::default();
+        let cosmology = Cosmology::from_config(&config);
+
+        // Ω_total slightly exceeds 1 because of radiation, so H(a=1) = H₀ √Ω_total
+        let h = Cosmology::compute_hubble(cosmology.energy_density.total, 1.0, Curvature::Flat);
+        let expected = cosmology.hubble.value * config.omega_total().sqrt();
+        let relative_error = (h - expected).abs() / expected;
+        assert!(relative_error < 1e-12, "H(a=1) should equal H₀ √Ω_total, got {} vs {}", h, expected);
+        // H₀ = 67.36 km/s/Mpc ≈ 1.44 × 10⁻⁴² GeV
+        assert!((cosmology.hubble.value - 1.437e-42).abs() < 1e-44);
+
+        let ratio = cosmology.energy_density.dark_energy / cosmology.energy_density.matter;
+        assert!((ratio - config.omega_lambda / config.omega_m).abs() < 1e-12);
+        assert_eq!(cosmology.curvature, Curvature::Flat);
+    }
+
+    #[test]
+    fn test_critical_density_matches_reduced_planck_mass() {
+        let config = CosmologyConfig::default();
+        let cosmology = Cosmology::from_config(&config);
+
+        // ρ_c = 3 M̄_pl² H₀² ≈ 8.1 × 10⁻⁴⁷ h² GeV⁴
+        let h = config.h0 / 100.0;
+        let critical_density = cosmology.energy_density.total / config.omega_total();
+        let expected = 8.098e-47 * h * h;
+        assert!(
+            (critical_density / expected - 1.0).abs() < 1e-2,
+            "ρ_c = {:e} GeV⁴, expected {:e}",
+            critical_density,
+            expected
+        );
+    }
+
+    /// Plays one second of real time at 10⁹x, split into frames of `frame_seconds`
+    fn play_one_second(frame_seconds: f64) -> Cosmology {
+        use bevy::time::{TimePlugin, TimeUpdateStrategy};
+        use genesis_core::{SimulationCommand, TimeIntegrationPlugin};
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TimeIntegrationPlugin, CosmologyPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(frame_seconds)))
+            .insert_resource(FixedTimestep { hz: 50.0, substeps: 4 })
+            .insert_resource(Cosmology::from_config(&CosmologyConfig::default()));
+        app.world_mut().send_event(SimulationCommand::SeekTo(1e6));
+        app.world_mut().send_event(SimulationCommand::SetSpeed(1e9));
+
+        for _ in 0..=(1.0 / frame_seconds).round() as usize {
+            app.update();
+        }
+        app.world().resource::<Cosmology>().clone()
+    }
+
+    #[test]
+    fn test_fixed_timestep_is_independent_of_frame_rate() {
+        // Frame lengths are whole nanoseconds, so every run takes exactly 50 fixed steps
+        let vsync = play_one_second(1.0 / 100.0);
+        let uncapped = play_one_second(1.0 / 400.0);
+        let slow = play_one_second(1.0 / 25.0);
+
+        let start = vsync.background.scale_factor_at(GeVInv::from(Years(1e6))).unwrap();
+        assert!(vsync.scale_factor.value > start, "expansion should have advanced");
+        for other in [&uncapped, &slow] {
+            assert_eq!(vsync.scale_factor.value.to_bits(), other.scale_factor.value.to_bits());
+            assert_eq!(vsync.scale_factor.time.0.to_bits(), other.scale_factor.time.0.to_bits());
+            assert_eq!(vsync.hubble.value.to_bits(), other.hubble.value.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_interpolated_scale_factor_is_geometric() {
+        let mut cosmology = Cosmology::new();
+        cosmology.previous_scale_factor = 1.0;
+        cosmology.scale_factor.value = 100.0;
+        assert!((cosmology.interpolated_scale_factor(0.5) - 10.0).abs() < 1e-12);
+        assert_eq!(cosmology.interpolated_scale_factor(1.0), 100.0);
+    }
+
+    #[test]
+    fn test_background_state_round_trip() {
+        let mut cosmology = Cosmology::from_config(&CosmologyConfig::default());
+        cosmology.scale_factor.epoch = CosmicEpoch::Recombination;
+        cosmology.integrate_scale_factor_rk4(GeVInv(1e30));
+
+        let restored = Cosmology::from_state(&cosmology.state());
+        assert_eq!(restored.state(), cosmology.state());
+        assert_eq!(restored.scale_factor.epoch, CosmicEpoch::Recombination);
+        assert_eq!(restored.hubble.squared, cosmology.hubble.squared);
+    }
+
+    #[test]
+    fn test_from_config_open_preset() {
+        let config = genesis_core::config::CosmologyPreset::Open.config();
+        let cosmology = Cosmology::from_config(&config);
+        assert_eq!(cosmology.curvature, Curvature::Open);
+        assert_eq!(cosmology.energy_density.dark_energy, 0.0);
+    }
+
     #[test]
     fn test_energy_density_default() {
         let density = EnergyDensity::default();
@@ -754,7 +1156,7 @@ mod tests {
         let a = ScaleFactor::default();
         assert_eq!(a.value, 1.0);
         assert_eq!(a.derivative, 0.0);
-        assert_eq!(a.time, 0.0);
+        assert_eq!(a.time, GeVInv::ZERO);
     }
     
     #[test]
@@ -794,10 +1196,9 @@ mod tests {
         
         let h = Cosmology::compute_hubble(rho, a, curvature);
         
-        // H should be very large for high energy density
-        // H² = (8π/3) * M_pl² * ρ ≈ 4.96e37 * 1e64 = 4.96e101
-        // H ≈ 7e50 GeV (above Planck scale as expected at very early times)
-        assert!(h > 1e40, "Hubble parameter should be very large during early universe");
+        // H² = ρ / (3 M̄_pl²) = 1e64 / 1.779e37 ≈ 5.62e26 GeV²
+        // H ≈ 2.37e13 GeV, a typical inflationary Hubble scale
+        assert!((h / 2.371e13 - 1.0).abs() < 1e-3, "H = {} GeV", h);
         
         // H should be positive
         assert!(h > 0.0, "Hubble parameter must be positive");
@@ -936,7 +1337,7 @@ mod tests {
         c.update_hubble();
         
         // Integrate one step
-        let dt = 1e-35; // Small time step
+        let dt = GeVInv(1e-15); // H dt ≈ 0.02 at H ≈ 2.4e13 GeV
         c.integrate_scale_factor_euler(dt);
         
         // Scale factor should increase
@@ -959,7 +1360,7 @@ mod tests {
         c.scale_factor.value = 1.0;
         c.curvature = Curvature::Flat;
         
-        let dt = 1e-35;
+        let dt = GeVInv(1e-15);
         let steps = 10;
         
         let initial_a = c.scale_factor.value;
@@ -1030,22 +1431,22 @@ mod tests {
     #[test]
     fn test_temperature_computation() {
         // Test T = T₀ / a
-        let initial_temp = 100.0;
+        let initial_temp = Kelvin(100.0);
         let scale_factor = 2.0;
         let temp = compute_temperature(scale_factor, initial_temp);
-        assert!((temp - 50.0).abs() < 1e-10);
+        assert!((temp - Kelvin(50.0)).value().abs() < 1e-10);
     }
     
     #[test]
     fn test_temperature_struct_default() {
         let temp = Temperature::default();
-        assert_eq!(temp.initial, 1e27);
-        assert_eq!(temp.value, 1e27);
+        assert_eq!(temp.initial, Kelvin(1e27));
+        assert_eq!(temp.value, Kelvin(1e27));
     }
     
     #[test]
     fn test_temperature_struct_new() {
-        let initial = 500.0;
+        let initial = Kelvin(500.0);
         let temp = Temperature::new(initial);
         assert_eq!(temp.initial, initial);
         assert_eq!(temp.value, initial);
@@ -1055,6 +1456,9 @@ mod tests {
 #[cfg(test)]
 mod rk4_tests {
     use super::*;
+    use crate::integrator::rk4_step;
+    use genesis_core::time::INFLATION_END_YEARS;
+    use genesis_core::units::Years;
 
     #[test]
     fn test_rk4_step_basic() {
@@ -1096,7 +1500,7 @@ mod rk4_tests {
     fn test_integrate_scale_factor_rk4_increases_time() {
         let mut cosmology = Cosmology::default();
         let initial_time = cosmology.scale_factor.time;
-        let dt = 1.0;
+        let dt = GeVInv(1.0);
         
         cosmology.integrate_scale_factor_rk4(dt);
         
@@ -1105,14 +1509,90 @@ mod rk4_tests {
 
     #[test]
     fn test_integrate_scale_factor_rk4_updates_hubble() {
-        let mut cosmology = Cosmology::default();
-        cosmology.energy_density = EnergyDensity::inflaton_dominated(1e64);
-        cosmology.update_hubble();
-        
-        cosmology.integrate_scale_factor_rk4(0.1);
-        
-        // Hubble parameter should be updated (update_hubble() was called)
-        assert!(cosmology.hubble.value > 0.0, "Hubble parameter should be positive after integration");
+        let mut cosmology = Cosmology::from_config(&CosmologyConfig::default());
+        let h0 = cosmology.hubble.value;
+
+        cosmology.integrate_scale_factor_rk4(GeVInv(0.01 / h0));
+
+        // H, ȧ and the densities follow the new scale factor
+        let a = cosmology.scale_factor.value;
+        assert!(a > 1.0);
+        assert!(cosmology.hubble.value > 0.0 && cosmology.hubble.value < h0);
+        assert_eq!(cosmology.scale_factor.derivative, cosmology.hubble.value * a);
+        assert_eq!(cosmology.energy_density, cosmology.background.density_at(a));
+    }
+
+    /// Integrates `cosmology` with `steps` equal RK4 steps up to cosmic time `t_end`
+    fn integrate_to(cosmology: &mut Cosmology, t_end: f64, steps: usize) {
+        let dt = (t_end - cosmology.scale_factor.time.value()) / steps as f64;
+        for _ in 0..steps {
+            cosmology.integrate_scale_factor_rk4(GeVInv(dt));
+        }
+    }
+
+    #[test]
+    fn test_rk4_matches_einstein_de_sitter() {
+        let config = genesis_core::config::CosmologyPreset::EinsteinDeSitter.config();
+        let mut cosmology = Cosmology::from_config(&config);
+        let h0 = cosmology.hubble.value;
+
+        // a(t) = (3H₀t / 2)^(2/3) reaches 1 at t₀ = 2 / (3H₀)
+        let t_start = 1e-3 * 2.0 / (3.0 * h0);
+        assert!(cosmology.match_background(GeVInv(t_start)));
+        assert!((cosmology.scale_factor.value - 1e-2).abs() < 1e-12);
+
+        integrate_to(&mut cosmology, 2.0 / (3.0 * h0), 2000);
+        assert!((cosmology.scale_factor.value - 1.0).abs() < 1e-7, "a = {}", cosmology.scale_factor.value);
+        assert!((cosmology.hubble.value - h0).abs() / h0 < 1e-7);
+    }
+
+    #[test]
+    fn test_rk4_matches_de_sitter() {
+        let config = genesis_core::config::CosmologyPreset::DeSitter.config();
+        let mut cosmology = Cosmology::from_config(&config);
+        let h0 = cosmology.hubble.value;
+
+        // a(t) = e^(H₀t) with a constant H
+        integrate_to(&mut cosmology, 3.0 / h0, 300);
+        let expected = 3.0_f64.exp();
+        assert!((cosmology.scale_factor.value - expected).abs() / expected < 1e-9);
+        assert_eq!(cosmology.hubble.value, h0);
+    }
+
+    #[test]
+    fn test_planck_background_reaches_today_at_13_8_gyr() {
+        let mut cosmology = Cosmology::from_config(&CosmologyConfig::default());
+        assert!(cosmology.match_background(GeVInv::from(Years(INFLATION_END_YEARS))));
+
+        // Logarithmic steps of 1% in time from the end of inflation until a = 1
+        while cosmology.scale_factor.value < 1.0 {
+            let dt = cosmology.scale_factor.time * 0.01;
+            cosmology.advance_background(dt, None);
+        }
+        let age = Years::from(cosmology.scale_factor.time).value();
+        assert!((age - 13.79e9).abs() < 0.2e9, "a = 1 reached after {} Gyr", age / 1e9);
+    }
+
+    #[test]
+    fn test_long_background_steps_use_table() {
+        let config = CosmologyConfig::default();
+        let table = BackgroundTable::from_config(&config).unwrap();
+        let mut cosmology = Cosmology::from_config(&config);
+        let t0 = GeVInv::from(Years(1e6));
+        cosmology.set_on_background(t0, table.scale_factor_at(t0));
+
+        // A step of ten times the current age is far beyond the RK4 limit
+        let dt = t0 * 10.0;
+        cosmology.advance_background(dt, Some(&table));
+        assert_eq!(cosmology.scale_factor.time, t0 + dt);
+        assert_eq!(cosmology.scale_factor.value, table.scale_factor_at(t0 + dt));
+
+        // Without a table the exact background gives the same a(t)
+        let mut exact = Cosmology::from_config(&config);
+        exact.set_on_background(t0, table.scale_factor_at(t0));
+        exact.advance_background(dt, None);
+        let relative = (exact.scale_factor.value / cosmology.scale_factor.value - 1.0).abs();
+        assert!(relative < 1e-4, "table and bisection differ by {:e}", relative);
     }
 
     #[test]
@@ -1121,7 +1601,7 @@ mod rk4_tests {
         let mut cosmology_rk4 = Cosmology::default();
         let mut cosmology_euler = Cosmology::default();
         
-        let dt = 0.001; // Small time step
+        let dt = GeVInv(0.001); // Small time step
         cosmology_rk4.integrate_scale_factor_rk4(dt);
         cosmology_euler.integrate_scale_factor_euler(dt);
         
@@ -1139,8 +1619,8 @@ mod exponential_tests {
     fn test_compute_exponential_scale_factor_basic() {
         // Test that a(t) = a₀e^(Ht) produces expected values
         let a0 = 1.0;
-        let h = 1e14;
-        let t = 0.0;
+        let h = GeV(1e14);
+        let t = GeVInv(0.0);
         let result = compute_exponential_scale_factor(a0, t, h);
         // At t=0, a(t) = a₀
         assert!((result - a0).abs() < 1e-10);
@@ -1150,8 +1630,8 @@ mod exponential_tests {
     fn test_compute_exponential_scale_factor_positive_time() {
         // Test that exponential growth works for positive time
         let a0 = 1.0;
-        let h = 1.0; // Use H=1 for easier calculation
-        let t = 1.0;
+        let h = GeV(1.0); // Use H=1 for easier calculation
+        let t = GeVInv(1.0);
         let result = compute_exponential_scale_factor(a0, t, h);
         // a(t) = 1 * e^1 ≈ 2.718
         assert!((result - std::f64::consts::E).abs() < 1e-10);
@@ -1161,16 +1641,16 @@ mod exponential_tests {
     fn test_exponential_derivative_consistency() {
         // Test that ȧ = H*a for exponential expansion
         let a0 = 1.0;
-        let h = 1e14;
+        let h = GeV(1e14);
         // Use very small time step for accurate finite difference: h*t = 0.001
-        let dt = 1e-17;
+        let dt = GeVInv(1e-17);
 
         // Compute a(t)
         let a1 = compute_exponential_scale_factor(a0, dt, h);
 
         // Compute derivative: ȧ = H*a (should equal (a1 - a0)/dt for small dt)
-        let expected_derivative = h * a0;
-        let computed_derivative = (a1 - a0) / dt;
+        let expected_derivative = h.value() * a0;
+        let computed_derivative = (a1 - a0) / dt.value();
 
         // Relative tolerance due to floating point arithmetic
         let relative_diff = (computed_derivative - expected_derivative).abs() / expected_derivative.abs();
@@ -1181,9 +1661,9 @@ mod exponential_tests {
     fn test_exponential_growth_monotonic() {
         // Test that a(t) is monotonically increasing during inflation
         let a0 = 1.0;
-        let h = 1e14;
+        let h = GeV(1e14);
         // Use larger time step to see meaningful changes: h*t = 0.1
-        let dt = 1e-15;
+        let dt = GeVInv(1e-15);
 
         let a1 = compute_exponential_scale_factor(a0, dt, h);
         let a2 = compute_exponential_scale_factor(a1, dt, h);
@@ -1203,8 +1683,8 @@ mod exponential_tests {
         let initial_time = c.scale_factor.time;
 
         // Use larger time step to see meaningful change: h*t = 0.1
-        let dt = 1e-15;
-        c.integrate_scale_factor_inflation(dt);
+        let dt = GeVInv(1e-15);
+        c.integrate_scale_factor_inflation(dt, f64::INFINITY);
 
         // Scale factor should increase
         assert!(c.scale_factor.value > initial_a,
@@ -1225,11 +1705,11 @@ mod exponential_tests {
         // Test that integrate_scale_factor_inflation produces exponential growth
         let mut c = Cosmology::new();
         c.scale_factor.value = 1.0;
-        let h = constants::INFLATION_HUBBLE_GEV;
+        let h = GeV(constants::INFLATION_HUBBLE_GEV);
 
         // Use larger time step to see meaningful change
-        let dt = 1e-15;
-        c.integrate_scale_factor_inflation(dt);
+        let dt = GeVInv(1e-15);
+        c.integrate_scale_factor_inflation(dt, f64::INFINITY);
 
         // Check that the result matches compute_exponential_scale_factor
         let a0 = 1.0;
@@ -1244,15 +1724,15 @@ mod exponential_tests {
         // Test that multiple integration steps produce consistent exponential growth
         let mut c = Cosmology::new();
         c.scale_factor.value = 1.0;
-        let h = constants::INFLATION_HUBBLE_GEV;
+        let h = GeV(constants::INFLATION_HUBBLE_GEV);
 
         // Use larger time step to see meaningful change
-        let dt = 1e-15;
+        let dt = GeVInv(1e-15);
         let steps = 5;
 
         // Integrate multiple steps
         for _ in 0..steps {
-            c.integrate_scale_factor_inflation(dt);
+            c.integrate_scale_factor_inflation(dt, f64::INFINITY);
         }
 
         // Compute expected value: a = a0 * e^(H * total_time)
@@ -1267,8 +1747,8 @@ mod exponential_tests {
     fn test_exponential_formula_consistency() {
         // Test that the formula a(t) = a₀e^(Ht) is consistently applied
         let a0_values = vec![0.5, 1.0, 2.0];
-        let h = 1e14;
-        let t = 1e-35;
+        let h = GeV(1e14);
+        let t = GeVInv(1e-35);
 
         for a0 in a0_values {
             let result = compute_exponential_scale_factor(a0, t, h);
@@ -1283,8 +1763,8 @@ mod exponential_tests {
         // Test exponential growth with different Hubble parameters
         let a0 = 1.0;
         // Use time such that h*t = 0.1 for h=1e14
-        let t = 1e-15;
-        let h_values = vec![1e13, 1e14, 1e15];
+        let t = GeVInv(1e-15);
+        let h_values = vec![GeV(1e13), GeV(1e14), GeV(1e15)];
 
         for h in h_values {
             let result = compute_exponential_scale_factor(a0, t, h);
@@ -1304,15 +1784,15 @@ mod matter_dominated_tests {
     #[test]
     fn test_compute_matter_dominated_scale_factor_zero_time() {
         // Test with t=0 returns 0.0 (edge case handling)
-        let t_eq = 1.0;
-        let result = compute_matter_dominated_scale_factor(0.0, t_eq);
+        let t_eq = GeVInv(1.0);
+        let result = compute_matter_dominated_scale_factor(GeVInv(0.0), t_eq);
         assert_eq!(result, 0.0, "Scale factor should be 0 at t=0");
     }
 
     #[test]
     fn test_compute_matter_dominated_scale_factor_normalization() {
         // Test with t=t_eq returns 1.0 (normalization condition)
-        let t_eq = 1.0;
+        let t_eq = GeVInv(1.0);
         let result = compute_matter_dominated_scale_factor(t_eq, t_eq);
         assert_eq!(result, 1.0, "Scale factor should be 1 at t=t_eq");
     }
@@ -1320,7 +1800,7 @@ mod matter_dominated_tests {
     #[test]
     fn test_compute_matter_dominated_scale_factor_scaling() {
         // Test with t=8*t_eq returns 4.0 (since (8)^(2/3) = 4)
-        let t_eq = 1.0;
+        let t_eq = GeVInv(1.0);
         let result = compute_matter_dominated_scale_factor(8.0 * t_eq, t_eq);
         // (8)^(2/3) = 4
         assert!((result - 4.0).abs() < 1e-10, "Scale factor should be ~4.0 at t=8*t_eq, got {}", result);
@@ -1329,16 +1809,16 @@ mod matter_dominated_tests {
     #[test]
     fn test_compute_matter_dominated_scale_factor_negative_time() {
         // Test with negative t returns 0.0 (edge case)
-        let t_eq = 1.0;
-        let result = compute_matter_dominated_scale_factor(-1.0, t_eq);
+        let t_eq = GeVInv(1.0);
+        let result = compute_matter_dominated_scale_factor(GeVInv(-1.0), t_eq);
         assert_eq!(result, 0.0, "Scale factor should be 0 for negative time");
     }
 
     #[test]
     fn test_compute_matter_dominated_scale_factor_power_law() {
         // Test the power law: a(t) = (t/t_eq)^(2/3)
-        let t_eq = 10.0;
-        let t = 40.0; // 4 * t_eq
+        let t_eq = GeVInv(10.0);
+        let t = GeVInv(40.0); // 4 * t_eq
         
         let result = compute_matter_dominated_scale_factor(t, t_eq);
         // (40/10)^(2/3) = 4^(2/3) ≈ 2.5198
@@ -1351,10 +1831,10 @@ mod matter_dominated_tests {
     #[test]
     fn test_compute_matter_dominated_scale_factor_monotonic() {
         // Test that a(t) is monotonically increasing for positive t
-        let t_eq = 1.0;
-        let a1 = compute_matter_dominated_scale_factor(1.0, t_eq);
-        let a2 = compute_matter_dominated_scale_factor(2.0, t_eq);
-        let a3 = compute_matter_dominated_scale_factor(4.0, t_eq);
+        let t_eq = GeVInv(1.0);
+        let a1 = compute_matter_dominated_scale_factor(GeVInv(1.0), t_eq);
+        let a2 = compute_matter_dominated_scale_factor(GeVInv(2.0), t_eq);
+        let a3 = compute_matter_dominated_scale_factor(GeVInv(4.0), t_eq);
 
         assert!(a2 > a1, "Scale factor should increase with time");
         assert!(a3 > a2, "Scale factor should increase with time");
@@ -1364,11 +1844,11 @@ mod matter_dominated_tests {
     fn test_integrate_scale_factor_matter_dominated_value_update() {
         // Test that scale_factor.value is updated correctly after integration
         let mut c = Cosmology::new();
-        let t_eq = 1.0;
-        let dt = 1.0;
+        let t_eq = GeVInv(1.0);
+        let dt = GeVInv(1.0);
         
         // Start with some initial state
-        c.scale_factor.time = 1.0; // Start at t=1
+        c.scale_factor.time = GeVInv(1.0); // Start at t=1
         
         c.integrate_scale_factor_matter_dominated(dt, t_eq);
         
@@ -1385,8 +1865,8 @@ mod matter_dominated_tests {
     fn test_integrate_scale_factor_matter_dominated_time_increment() {
         // Test that scale_factor.time is incremented by dt
         let mut c = Cosmology::new();
-        let t_eq = 1.0;
-        let dt = 0.5;
+        let t_eq = GeVInv(1.0);
+        let dt = GeVInv(0.5);
         
         let initial_time = c.scale_factor.time;
         c.integrate_scale_factor_matter_dominated(dt, t_eq);
@@ -1399,16 +1879,16 @@ mod matter_dominated_tests {
     fn test_integrate_scale_factor_matter_dominated_derivative() {
         // Test that scale_factor.derivative follows ȧ = (2/3) * a/t
         let mut c = Cosmology::new();
-        let t_eq = 1.0;
-        let dt = 1.0;
+        let t_eq = GeVInv(1.0);
+        let dt = GeVInv(1.0);
         
         // Start at a non-zero time to avoid division issues
-        c.scale_factor.time = 2.0;
+        c.scale_factor.time = GeVInv(2.0);
         c.integrate_scale_factor_matter_dominated(dt, t_eq);
         
         let a = c.scale_factor.value;
         let t = c.scale_factor.time;
-        let expected_derivative = (2.0 / 3.0) * a / t;
+        let expected_derivative = (2.0 / 3.0) * a / t.value();
         
         assert!((c.scale_factor.derivative - expected_derivative).abs() < 1e-10,
                 "Derivative should equal (2/3) * a/t: expected {}, got {}",
@@ -1417,30 +1897,30 @@ mod matter_dominated_tests {
 
     #[test]
     fn test_integrate_scale_factor_matter_dominated_epoch() {
-        // Test that scale_factor.epoch is set to CosmicEpoch::QuarkGluonPlasma
+        // Test that the expansion law leaves the epoch to detect_cosmic_epoch
         let mut c = Cosmology::new();
-        let t_eq = 1.0;
-        let dt = 0.5;
+        let t_eq = GeVInv(1.0);
+        let dt = GeVInv(0.5);
         
         // Initially in Planck epoch
         assert_eq!(c.scale_factor.epoch, CosmicEpoch::Planck);
         
         c.integrate_scale_factor_matter_dominated(dt, t_eq);
         
-        assert_eq!(c.scale_factor.epoch, CosmicEpoch::QuarkGluonPlasma,
-                   "Epoch should be set to QuarkGluonPlasma");
+        assert_eq!(c.scale_factor.epoch, CosmicEpoch::Planck,
+                   "Epoch should be left unchanged");
     }
 
     #[test]
     fn test_integrate_scale_factor_matter_dominated_multiple_steps() {
         // Test that multiple integration steps produce consistent results
         let mut c = Cosmology::new();
-        let t_eq = 1.0;
-        let dt = 0.5;
+        let t_eq = GeVInv(1.0);
+        let dt = GeVInv(0.5);
         let steps = 4;
         
         // Start at a non-zero time
-        c.scale_factor.time = 1.0;
+        c.scale_factor.time = GeVInv(1.0);
         
         // Integrate multiple steps
         for _ in 0..steps {
@@ -1448,7 +1928,7 @@ mod matter_dominated_tests {
         }
         
         // Final time should be 1.0 + 4 * 0.5 = 3.0
-        let expected_time = 1.0 + steps as f64 * dt;
+        let expected_time = GeVInv(1.0) + dt * steps as f64;
         assert_eq!(c.scale_factor.time, expected_time,
                    "Time should be accumulated correctly");
         
@@ -1462,11 +1942,11 @@ mod matter_dominated_tests {
     fn test_integrate_scale_factor_matter_dominated_from_zero() {
         // Test integration starting from t=0
         let mut c = Cosmology::new();
-        let t_eq = 1.0;
-        let dt = 1.0;
+        let t_eq = GeVInv(1.0);
+        let dt = GeVInv(1.0);
         
         // Start at t=0
-        c.scale_factor.time = 0.0;
+        c.scale_factor.time = GeVInv::ZERO;
         c.scale_factor.value = 0.0;
         
         c.integrate_scale_factor_matter_dominated(dt, t_eq);
@@ -1488,10 +1968,10 @@ mod matter_dominated_tests {
         // Since ȧ = (2/3)*a/t, we get: ä = (2/3)*((2/3)*a/t² - a/t²) = (2/3)*(a/t²)*(2/3 - 1) = -(1/9)*a/t² < 0
         // So expansion is decelerating (negative ä)
         
-        let t_eq = 1.0;
+        let t_eq = GeVInv(1.0);
         let t = 8.0;
 
-        let a = compute_matter_dominated_scale_factor(t, t_eq);
+        let a = compute_matter_dominated_scale_factor(GeVInv(t), t_eq);
         let _a_dot = (2.0 / 3.0) * a / t;
         let a_double_dot = - (1.0 / 9.0) * a / (t * t);
 
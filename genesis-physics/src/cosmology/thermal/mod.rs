@@ -0,0 +1,401 @@
+//! Thermal history of the radiation bath
+//!
+//! The photon temperature does not simply fall as 1/a: whenever a species becomes
+//! non-relativistic and annihilates, its entropy heats the particles it is still in
+//! equilibrium with. Entropy conservation in the coupled plasma,
+//!
+//! ```text
+//! g*s(T) T³ a³ = const
+//! ```
+//!
+//! fixes T(a), with the effective numbers of relativistic degrees of freedom
+//!
+//! ```text
+//! g*(T)  = Σ_bosons gᵢ (Tᵢ/T)⁴ + 7/8 Σ_fermions gᵢ (Tᵢ/T)⁴     (energy density)
+//! g*s(T) = Σ_bosons gᵢ (Tᵢ/T)³ + 7/8 Σ_fermions gᵢ (Tᵢ/T)³     (entropy density)
+//! ```
+//!
+//! generalised to massive species by integrating their Fermi–Dirac or Bose–Einstein
+//! distributions. [`ThermalHistory`] follows the Standard Model from g* = 106.75
+//! through the QCD transition (quarks and gluons to pions), instantaneous neutrino
+//! decoupling at [`NEUTRINO_DECOUPLING_GEV`] and e⁺e⁻ annihilation, after which
+//! T_ν / T_γ ≈ (4/11)^(1/3) and g*s ≈ 3.92.
+//!
+//! The expansion rate of the [`Background`](super::Background) still scales its
+//! present-day radiation density as a⁻⁴.
+
+use genesis_core::units::{GeV, Kelvin};
+
+/// Temperature of the QCD crossover in GeV
+pub const QCD_CROSSOVER_GEV: f64 = 0.155;
+
+/// Relative width of the QCD crossover in ln T
+const QCD_CROSSOVER_WIDTH: f64 = 0.1;
+
+/// Temperature at which the neutrinos decouple from the plasma, in GeV
+pub const NEUTRINO_DECOUPLING_GEV: f64 = 1.5e-3;
+
+/// Neutrino degrees of freedom 7/8 × 6 (three flavours of ν and ν̄)
+const NEUTRINO_DOF: f64 = 5.25;
+
+/// Lowest tabulated temperature in GeV, far below e⁺e⁻ annihilation
+const TABLE_MIN_GEV: f64 = 1e-6;
+
+/// Highest tabulated temperature in GeV, above the top quark mass
+const TABLE_MAX_GEV: f64 = 1e5;
+
+/// Table nodes per decade of temperature
+const TABLE_NODES_PER_DECADE: usize = 40;
+
+/// m/T beyond which a species is Boltzmann suppressed to nothing
+const MAX_MASS_RATIO: f64 = 40.0;
+
+/// m/T below which a species counts as massless
+const MIN_MASS_RATIO: f64 = 1e-3;
+
+/// Simpson intervals in momentum for the degrees of freedom of a massive species
+const MOMENTUM_INTERVALS: usize = 200;
+
+/// Which phase of QCD a species belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Present on both sides of the QCD transition
+    Any,
+    /// Quarks and gluons, above the transition
+    Deconfined,
+    /// Hadrons, below the transition
+    Confined,
+}
+
+/// A particle species in equilibrium with the plasma
+#[derive(Debug, Clone, Copy)]
+struct Species {
+    /// Internal degrees of freedom (spin, colour, antiparticle)
+    dof: f64,
+    /// Fermi–Dirac rather than Bose–Einstein statistics
+    fermion: bool,
+    /// Mass in GeV
+    mass: f64,
+    phase: Phase,
+}
+
+/// Standard Model species coupled to the photons, neutrinos excluded
+const PLASMA: [Species; 15] = [
+    species(2.0, false, 0.0, Phase::Any),           // γ
+    species(4.0, true, 5.109_99e-4, Phase::Any),    // e±
+    species(4.0, true, 0.105_658, Phase::Any),      // μ±
+    species(4.0, true, 1.776_86, Phase::Any),       // τ±
+    species(3.0, false, 0.138, Phase::Confined),    // π⁰, π±
+    species(16.0, false, 0.0, Phase::Deconfined),   // gluons
+    species(12.0, true, 2.2e-3, Phase::Deconfined), // u
+    species(12.0, true, 4.7e-3, Phase::Deconfined), // d
+    species(12.0, true, 0.095, Phase::Deconfined),  // s
+    species(12.0, true, 1.27, Phase::Deconfined),   // c
+    species(12.0, true, 4.18, Phase::Deconfined),   // b
+    species(12.0, true, 172.8, Phase::Deconfined),  // t
+    species(6.0, false, 80.38, Phase::Any),         // W±
+    species(3.0, false, 91.19, Phase::Any),         // Z
+    species(1.0, false, 125.1, Phase::Any),         // H
+];
+
+const fn species(dof: f64, fermion: bool, mass: f64, phase: Phase) -> Species {
+    Species {
+        dof,
+        fermion,
+        mass,
+        phase,
+    }
+}
+
+/// Relativistic degrees of freedom and the temperature–scale factor relation
+///
+/// Tabulates the plasma's g* and g*s in ln T once per CMB temperature, and
+/// interpolates linearly between nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalHistory {
+    t_cmb: Kelvin,
+    ln_t: Vec<f64>,
+    plasma_g_rho: Vec<f64>,
+    plasma_g_s: Vec<f64>,
+    ln_at: Vec<f64>,
+    ln_a: Vec<f64>,
+    decoupling_g_s: f64,
+}
+
+impl ThermalHistory {
+    /// Tabulate the thermal history for a CMB temperature today
+    pub fn new(t_cmb: Kelvin) -> Self {
+        let ln_min = TABLE_MIN_GEV.ln();
+        let ln_max = TABLE_MAX_GEV.ln();
+        let decades = (TABLE_MAX_GEV / TABLE_MIN_GEV).log10();
+        let nodes = (decades * TABLE_NODES_PER_DECADE as f64).round() as usize + 1;
+        let step = (ln_max - ln_min) / (nodes - 1) as f64;
+
+        let ln_t: Vec<f64> = (0..nodes).map(|i| ln_min + i as f64 * step).collect();
+        let (plasma_g_rho, plasma_g_s) = ln_t.iter().map(|&x| plasma_dof(x.exp())).unzip();
+
+        let mut history = Self {
+            t_cmb,
+            ln_t,
+            plasma_g_rho,
+            plasma_g_s,
+            ln_at: Vec::new(),
+            ln_a: Vec::new(),
+            decoupling_g_s: 0.0,
+        };
+        history.decoupling_g_s = history.plasma_entropy_dof(NEUTRINO_DECOUPLING_GEV);
+
+        // ln(aT / T_CMB) from entropy conservation, with g*s = 2 photons today
+        history.ln_at = history
+            .ln_t
+            .iter()
+            .map(|&x| {
+                let t = x.exp();
+                if t < NEUTRINO_DECOUPLING_GEV {
+                    (2.0 / history.plasma_entropy_dof(t)).ln() / 3.0
+                } else {
+                    let at_decoupling = (2.0 / history.decoupling_g_s).ln() / 3.0;
+                    let coupled = history.decoupling_g_s + NEUTRINO_DOF;
+                    at_decoupling + (coupled / history.entropy_dof_gev(t)).ln() / 3.0
+                }
+            })
+            .collect();
+
+        // ln a = ln T_CMB + ln(aT/T_CMB) − ln T, decreasing along the table
+        let ln_cmb = GeV::from(t_cmb).value().ln();
+        history.ln_a = history
+            .ln_t
+            .iter()
+            .zip(&history.ln_at)
+            .map(|(ln_t, ln_at)| ln_cmb + ln_at - ln_t)
+            .collect();
+        history
+    }
+
+    /// CMB temperature today
+    pub fn t_cmb(&self) -> Kelvin {
+        self.t_cmb
+    }
+
+    /// Effective relativistic degrees of freedom g* for the energy density
+    pub fn effective_dof(&self, temperature: Kelvin) -> f64 {
+        let t = GeV::from(temperature).value();
+        self.interpolate(&self.plasma_g_rho, t) + NEUTRINO_DOF * self.neutrino_ratio_gev(t).powi(4)
+    }
+
+    /// Effective relativistic degrees of freedom g*s for the entropy density
+    pub fn entropy_dof(&self, temperature: Kelvin) -> f64 {
+        self.entropy_dof_gev(GeV::from(temperature).value())
+    }
+
+    /// Photon temperature T_γ at scale factor `a`
+    pub fn photon_temperature(&self, a: f64) -> Kelvin {
+        let ln_cmb = GeV::from(self.t_cmb).value().ln();
+        let ln_a = a.ln();
+
+        // Beyond the table aT is constant
+        let last = self.ln_a.len() - 1;
+        let ln_t = if ln_a >= self.ln_a[0] {
+            ln_cmb + self.ln_at[0] - ln_a
+        } else if ln_a <= self.ln_a[last] {
+            ln_cmb + self.ln_at[last] - ln_a
+        } else {
+            let i = self.ln_a.partition_point(|&node| node > ln_a).clamp(1, last) - 1;
+            let s = (self.ln_a[i] - ln_a) / (self.ln_a[i] - self.ln_a[i + 1]);
+            self.ln_t[i] + s * (self.ln_t[i + 1] - self.ln_t[i])
+        };
+        Kelvin::from(GeV(ln_t.exp()))
+    }
+
+    /// Neutrino temperature T_ν at scale factor `a`
+    ///
+    /// Equal to T_γ until decoupling, after which T_ν ∝ 1/a while e⁺e⁻ annihilation
+    /// heats the photons.
+    pub fn neutrino_temperature(&self, a: f64) -> Kelvin {
+        let photons = self.photon_temperature(a);
+        let ratio = self.neutrino_ratio_gev(GeV::from(photons).value());
+        Kelvin(photons.value() * ratio)
+    }
+
+    /// Scale factor at which the photons have cooled to `temperature`
+    pub fn scale_factor_at(&self, temperature: Kelvin) -> f64 {
+        let t = GeV::from(temperature).value();
+        let ln_at = self.interpolate(&self.ln_at, t);
+        ln_at.exp() * self.t_cmb.value() / temperature.value()
+    }
+
+    /// g*s of the whole bath at `t` GeV, neutrinos included
+    fn entropy_dof_gev(&self, t: f64) -> f64 {
+        self.plasma_entropy_dof(t) + NEUTRINO_DOF * self.neutrino_ratio_gev(t).powi(3)
+    }
+
+    /// g*s of the species coupled to the photons at `t` GeV
+    fn plasma_entropy_dof(&self, t: f64) -> f64 {
+        self.interpolate(&self.plasma_g_s, t)
+    }
+
+    /// T_ν / T_γ at photon temperature `t` GeV
+    fn neutrino_ratio_gev(&self, t: f64) -> f64 {
+        if t >= NEUTRINO_DECOUPLING_GEV {
+            1.0
+        } else {
+            (self.plasma_entropy_dof(t) / self.decoupling_g_s).cbrt()
+        }
+    }
+
+    /// Linear interpolation of tabulated `values` in ln T, constant beyond the ends
+    fn interpolate(&self, values: &[f64], t: f64) -> f64 {
+        let x = t.ln();
+        let last = self.ln_t.len() - 1;
+        if x <= self.ln_t[0] {
+            return values[0];
+        }
+        if x >= self.ln_t[last] {
+            return values[last];
+        }
+        let step = self.ln_t[1] - self.ln_t[0];
+        let i = (((x - self.ln_t[0]) / step) as usize).min(last - 1);
+        let s = (x - self.ln_t[i]) / step;
+        values[i] + s * (values[i + 1] - values[i])
+    }
+}
+
+/// g* and g*s of the species coupled to the photons at temperature `t` GeV
+fn plasma_dof(t: f64) -> (f64, f64) {
+    // Weight of the deconfined phase, smoothed across the QCD crossover
+    let deconfined = 0.5 * (1.0 + ((t / QCD_CROSSOVER_GEV).ln() / QCD_CROSSOVER_WIDTH).tanh());
+
+    PLASMA.iter().fold((0.0, 0.0), |(g_rho, g_s), species| {
+        let weight = match species.phase {
+            Phase::Any => 1.0,
+            Phase::Deconfined => deconfined,
+            Phase::Confined => 1.0 - deconfined,
+        };
+        let (rho, s) = species_dof(species, t);
+        (g_rho + weight * rho, g_s + weight * s)
+    })
+}
+
+/// Contribution of one species to g* and g*s at temperature `t` GeV
+///
+/// ```text
+/// g*  = g (15/π⁴) ∫ y² E / (e^E ± 1) dy
+/// g*s = g (45/4π⁴) ∫ (y² E + y⁴ / 3E) / (e^E ± 1) dy
+/// ```
+///
+/// with y = p/T and E = √(y² + (m/T)²).
+fn species_dof(species: &Species, t: f64) -> (f64, f64) {
+    let statistics = if species.fermion { 7.0 / 8.0 } else { 1.0 };
+    let x = species.mass / t;
+    if x < MIN_MASS_RATIO {
+        return (species.dof * statistics, species.dof * statistics);
+    }
+    if x > MAX_MASS_RATIO {
+        return (0.0, 0.0);
+    }
+
+    let sign = if species.fermion { 1.0 } else { -1.0 };
+    let upper = x + MAX_MASS_RATIO;
+    let h = upper / MOMENTUM_INTERVALS as f64;
+    let (mut rho, mut s) = (0.0, 0.0);
+    for i in 0..=MOMENTUM_INTERVALS {
+        let y = i as f64 * h;
+        let weight = if i == 0 || i == MOMENTUM_INTERVALS {
+            1.0
+        } else if i % 2 == 1 {
+            4.0
+        } else {
+            2.0
+        };
+        let e = (y * y + x * x).sqrt();
+        let occupation = 1.0 / (e.exp() + sign);
+        rho += weight * y * y * e * occupation;
+        s += weight * (y * y * e + y.powi(4) / (3.0 * e)) * occupation;
+    }
+
+    let pi4 = std::f64::consts::PI.powi(4);
+    (
+        species.dof * 15.0 / pi4 * rho * h / 3.0,
+        species.dof * 45.0 / (4.0 * pi4) * s * h / 3.0,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> ThermalHistory {
+        ThermalHistory::new(Kelvin(2.7255))
+    }
+
+    fn mev(t: f64) -> Kelvin {
+        Kelvin::from(GeV(t * 1e-3))
+    }
+
+    #[test]
+    fn test_degrees_of_freedom_limits() {
+        let history = history();
+
+        // Full Standard Model above the electroweak scale
+        assert!((history.effective_dof(mev(1e7)) - 106.75).abs() < 0.1);
+        assert!((history.entropy_dof(mev(1e7)) - 106.75).abs() < 0.1);
+
+        // γ, e±, ν between the muon and electron masses
+        assert!((history.effective_dof(mev(3.0)) - 10.75).abs() < 0.1);
+
+        // γ and decoupled neutrinos today. The e± are already slightly massive at
+        // decoupling, which leaves the neutrinos a little hotter (N_eff ≈ 3.03)
+        let today = history.t_cmb();
+        assert!((history.effective_dof(today) - 3.377).abs() < 0.005);
+        assert!((history.entropy_dof(today) - 3.924).abs() < 0.005);
+    }
+
+    #[test]
+    fn test_qcd_transition_removes_coloured_dof() {
+        let history = history();
+        let above = history.effective_dof(mev(400.0));
+        let below = history.effective_dof(mev(60.0));
+        assert!(above > 55.0, "g*(400 MeV) = {}", above);
+        assert!(below < 20.0, "g*(60 MeV) = {}", below);
+
+        // g* only falls as the universe cools
+        let mut previous = f64::INFINITY;
+        for i in 0..=100 {
+            let t = 10f64.powf(6.0 - 0.1 * i as f64);
+            let g = history.effective_dof(mev(t));
+            assert!(g <= previous + 1e-9, "g* rose to {} at {} MeV", g, t);
+            previous = g;
+        }
+    }
+
+    #[test]
+    fn test_electron_annihilation_heats_photons() {
+        let history = history();
+
+        // aT rises by (11/4)^(1/3) from before to after e⁺e⁻ annihilation
+        let before = history.scale_factor_at(mev(3.0)) * mev(3.0).value();
+        let after = history.scale_factor_at(mev(0.01)) * mev(0.01).value();
+        let expected = (11.0_f64 / 4.0).cbrt();
+        assert!((after / before / expected - 1.0).abs() < 0.01, "aT grew by {}", after / before);
+
+        // T_ν / T_γ = (4/11)^(1/3) today
+        let ratio = |a: f64| {
+            history.neutrino_temperature(a).value() / history.photon_temperature(a).value()
+        };
+        assert!((ratio(1.0) * expected - 1.0).abs() < 0.01, "T_ν/T_γ = {}", ratio(1.0));
+        assert_eq!(ratio(1e-12), 1.0, "neutrinos share the photon temperature before decoupling");
+    }
+
+    #[test]
+    fn test_temperature_inverts_scale_factor() {
+        let history = history();
+        assert!((history.photon_temperature(1.0).value() - 2.7255).abs() < 1e-9);
+        assert!((history.scale_factor_at(Kelvin(3000.0)) - 2.7255 / 3000.0).abs() < 1e-15);
+
+        for a in [1e-30, 1e-15, 1e-12, 3e-10, 1e-9, 1e-5] {
+            let t = history.photon_temperature(a);
+            let back = history.scale_factor_at(t);
+            assert!((back / a - 1.0).abs() < 1e-9, "a = {} gave {}", a, back);
+        }
+    }
+}
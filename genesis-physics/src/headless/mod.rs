@@ -0,0 +1,345 @@
+//! Headless simulation without a window or renderer
+//!
+//! Builds the simulation on Bevy's `MinimalPlugins` with [`TimeIntegrationPlugin`]
+//! and [`GenesisPhysicsPlugin`] (cosmology, inflaton and particle kinematics),
+//! then advances it as fast as possible to a target cosmic time. Every app update
+//! covers exactly one fixed physics step, so a headless run produces the same
+//! physics state as an interactive run with the same inputs, regardless of how
+//! fast the machine is. This allows regression checks and long batch runs on
+//! machines without a GPU or display.
+//!
+//! A run can start from a saved [`Session`] with [`session_app`], and its final
+//! state can be captured with [`capture_session`] to be resumed later, headless or
+//! interactively. A fresh run starts with an empty particle store, so only the
+//! background is evolved; a resumed session keeps drifting its particles.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use genesis_core::Config;
+//! use genesis_core::time::SimulationClock;
+//! use genesis_physics::headless::{run_headless, HeadlessOptions};
+//!
+//! let config = Config::default();
+//! let options = HeadlessOptions { until_years: 380_000.0, ..Default::default() };
+//! let report = run_headless(&config, SimulationClock::new(), &options).unwrap();
+//! report.write_csv("recombination.csv").unwrap();
+//! ```
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use genesis_core::physics::ParticleStore;
+use genesis_core::time::{FixedTimestep, SimulationClock};
+use genesis_core::{Config, Session, TimeIntegrationPlugin};
+
+use crate::cosmology::{Background, Cosmology};
+use crate::inflaton::Inflaton;
+use crate::GenesisPhysicsPlugin;
+
+/// Present-day cosmic time in years (13.8 billion years)
+pub const PRESENT_DAY_YEARS: f64 = 13.8e9;
+
+/// Options for a headless run
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadlessOptions {
+    /// Cosmic time in years at which the run stops
+    pub until_years: f64,
+    /// Maximum number of fixed steps before the run is abandoned
+    pub max_steps: u64,
+    /// Record a sample every this many fixed steps (the final state is always recorded)
+    pub sample_every: u64,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        Self {
+            until_years: PRESENT_DAY_YEARS,
+            max_steps: 10_000_000,
+            sample_every: 1,
+        }
+    }
+}
+
+/// Simulation state recorded after a fixed step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeadlessSample {
+    /// Number of fixed steps taken
+    pub step: u64,
+    /// Cosmic time in years
+    pub years: f64,
+    /// Scale factor a
+    pub scale_factor: f64,
+    /// Hubble parameter H in GeV
+    pub hubble_gev: f64,
+    /// Temperature in Kelvin
+    pub temperature_k: f64,
+}
+
+impl HeadlessSample {
+    fn capture(step: u64, clock: &SimulationClock, cosmology: &Cosmology) -> Self {
+        Self {
+            step,
+            years: clock.years(),
+            scale_factor: cosmology.scale_factor.value,
+            hubble_gev: cosmology.hubble.value,
+            temperature_k: cosmology.scale_factor.temperature.value.value(),
+        }
+    }
+}
+
+/// Outputs of a headless run
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadlessReport {
+    /// Number of fixed steps taken
+    pub steps: u64,
+    /// Cosmic time in years at the end of the run
+    pub years: f64,
+    /// Recorded samples, ending with the final state
+    pub samples: Vec<HeadlessSample>,
+}
+
+impl HeadlessReport {
+    /// Final recorded state
+    pub fn last(&self) -> Option<&HeadlessSample> {
+        self.samples.last()
+    }
+
+    /// Formats the samples as CSV with a header row
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,years,scale_factor,hubble_gev,temperature_k\n");
+        for sample in &self.samples {
+            let _ = writeln!(
+                csv,
+                "{},{:e},{:e},{:e},{:e}",
+                sample.step, sample.years, sample.scale_factor, sample.hubble_gev, sample.temperature_k
+            );
+        }
+        csv
+    }
+
+    /// Writes the samples to `path` as CSV
+    pub fn write_csv(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_csv())
+    }
+}
+
+/// Builds a headless app with the time and physics plugins
+///
+/// The clock is inserted as given (call [`SimulationClock::play`] to let it run)
+/// and each [`App::update`] advances time by exactly one fixed step.
+///
+/// # Arguments
+/// * `config` - Effective configuration (`[physics]` timestep, `[cosmology]` parameters)
+/// * `clock` - Initial simulation clock (start time, speed, stepping mode)
+pub fn headless_app(config: &Config, clock: SimulationClock) -> App {
+    let timestep = FixedTimestep::from_config(&config.physics);
+    let step = timestep.step();
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(TimeIntegrationPlugin)
+        .add_plugins(GenesisPhysicsPlugin)
+        .insert_resource(clock)
+        .insert_resource(timestep)
+        .insert_resource(config.cosmology.clone())
+        .insert_resource(config.epochs.clone())
+        .insert_resource(Cosmology::from_config(&config.cosmology))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(step));
+
+    // A single update must be able to cover a whole fixed step
+    let mut virtual_time = app.world_mut().resource_mut::<Time<Virtual>>();
+    let max_delta = virtual_time.max_delta().max(step);
+    virtual_time.set_max_delta(max_delta);
+
+    app
+}
+
+/// Builds a headless app that continues from a saved session
+///
+/// Like [`headless_app`] with the session's configuration and clock, but with the
+/// cosmological background, inflaton field and particles restored from the session.
+pub fn session_app(session: &Session) -> App {
+    let mut app = headless_app(&session.config, SimulationClock::from_state(&session.clock));
+    app.insert_resource(Cosmology {
+        background: Background::from_config(&session.config.cosmology),
+        ..Cosmology::from_state(&session.background)
+    })
+        .insert_resource(Inflaton::from_state(&session.inflaton))
+        .insert_resource(session.particles.clone());
+    app
+}
+
+/// Captures the physics state of a headless app as a session
+///
+/// Headless runs have no camera, so the camera pose keeps its default.
+pub fn capture_session(app: &App, config: &Config) -> Session {
+    let world = app.world();
+    let mut session = Session::new(config.clone());
+    session.clock = world.resource::<SimulationClock>().state();
+    session.background = world.resource::<Cosmology>().state();
+    session.inflaton = world.resource::<Inflaton>().state();
+    session.particles = world.resource::<ParticleStore>().clone();
+    session
+}
+
+/// Runs the simulation headless until the clock reaches `options.until_years`
+///
+/// The clock is started if it is paused.
+///
+/// # Returns
+/// * `Ok(HeadlessReport)` with the recorded samples
+/// * `Err(String)` if the target is invalid or not reached within `options.max_steps`
+pub fn run_headless(
+    config: &Config,
+    mut clock: SimulationClock,
+    options: &HeadlessOptions,
+) -> Result<HeadlessReport, String> {
+    clock.play();
+    let mut app = headless_app(config, clock);
+    run_headless_app(&mut app, options)
+}
+
+/// Runs an app built by [`headless_app`] or [`session_app`] until its clock reaches
+/// `options.until_years`
+///
+/// The clock must be playing. The app is left in its final state, so it can be
+/// saved with [`capture_session`] afterwards.
+///
+/// # Returns
+/// * `Ok(HeadlessReport)` with the recorded samples
+/// * `Err(String)` if the target is invalid or not reached within `options.max_steps`
+pub fn run_headless_app(app: &mut App, options: &HeadlessOptions) -> Result<HeadlessReport, String> {
+    if !(options.until_years.is_finite() && options.until_years >= 0.0) {
+        return Err(format!(
+            "target time must be a non-negative number of years, got {}",
+            options.until_years
+        ));
+    }
+
+    let sample_every = options.sample_every.max(1);
+    let mut samples = Vec::new();
+    let mut steps = 0;
+
+    // The first update only starts Bevy's clocks; every later one is a fixed step
+    app.update();
+
+    let capture = |app: &App, steps| {
+        let world = app.world();
+        HeadlessSample::capture(
+            steps,
+            world.resource::<SimulationClock>(),
+            world.resource::<Cosmology>(),
+        )
+    };
+
+    loop {
+        let years = app.world().resource::<SimulationClock>().years();
+        if years >= options.until_years {
+            break;
+        }
+        if steps >= options.max_steps {
+            return Err(format!(
+                "stopped after {} steps at {:e} years without reaching {:e} years",
+                steps, years, options.until_years
+            ));
+        }
+        app.update();
+        steps += 1;
+        if steps % sample_every == 0 {
+            samples.push(capture(app, steps));
+        }
+    }
+
+    if samples.last().map(|sample| sample.step) != Some(steps) {
+        samples.push(capture(app, steps));
+    }
+    let years = app.world().resource::<SimulationClock>().years();
+
+    Ok(HeadlessReport { steps, years, samples })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genesis_core::time::TimeMode;
+
+    fn one_decade_clock() -> SimulationClock {
+        let mut clock = SimulationClock::new();
+        clock.set_mode(TimeMode::Logarithmic, 1.0);
+        clock.seek_to(1e6);
+        clock
+    }
+
+    #[test]
+    fn test_run_headless_reaches_target() {
+        let options = HeadlessOptions {
+            until_years: 1e7,
+            sample_every: 10,
+            ..Default::default()
+        };
+        let report = run_headless(&Config::default(), one_decade_clock(), &options).unwrap();
+
+        // One decade at one decade per second and 60 steps per second, plus the seek step
+        assert!(report.years >= 1e7);
+        assert!((60..=62).contains(&report.steps), "took {} steps", report.steps);
+        assert_eq!(report.last().unwrap().step, report.steps);
+        assert!(report.last().unwrap().scale_factor > report.samples[0].scale_factor);
+
+        let csv = report.to_csv();
+        assert!(csv.starts_with("step,years,scale_factor,hubble_gev,temperature_k\n"));
+        assert_eq!(csv.lines().count(), report.samples.len() + 1);
+    }
+
+    #[test]
+    fn test_run_headless_is_reproducible() {
+        let options = HeadlessOptions {
+            until_years: 1e7,
+            ..Default::default()
+        };
+        let first = run_headless(&Config::default(), one_decade_clock(), &options).unwrap();
+        let second = run_headless(&Config::default(), one_decade_clock(), &options).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_restored_session_continues_identically() {
+        let config = Config::default();
+        let mut clock = one_decade_clock();
+        clock.play();
+
+        let mut uninterrupted = headless_app(&config, clock.clone());
+        let mut interrupted = headless_app(&config, clock);
+        for _ in 0..21 {
+            uninterrupted.update();
+            interrupted.update();
+        }
+
+        // Save mid-run and continue in a fresh app
+        let bytes = capture_session(&interrupted, &config).to_bytes().unwrap();
+        let mut resumed = session_app(&Session::from_bytes(&bytes).unwrap());
+        resumed.update();
+
+        for _ in 0..20 {
+            uninterrupted.update();
+            resumed.update();
+        }
+        let expected = uninterrupted.world().resource::<Cosmology>().state();
+        assert_eq!(resumed.world().resource::<Cosmology>().state(), expected);
+        assert_eq!(
+            resumed.world().resource::<SimulationClock>().years(),
+            uninterrupted.world().resource::<SimulationClock>().years()
+        );
+    }
+
+    #[test]
+    fn test_run_headless_gives_up_after_max_steps() {
+        let options = HeadlessOptions {
+            max_steps: 5,
+            ..Default::default()
+        };
+        assert!(run_headless(&Config::default(), SimulationClock::new(), &options).is_err());
+    }
+}
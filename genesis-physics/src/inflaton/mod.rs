@@ -1,6 +1,7 @@
 // Inflaton field module for cosmic inflation physics
 
 use bevy::prelude::*;
+use genesis_core::session::InflatonState;
 
 /// Inflaton mass constant (in GeV)
 /// The inflaton field typically has a mass of ~10^16 GeV, which in natural units
@@ -102,6 +103,7 @@ impl Inflaton {
     ///
     /// # Arguments
     /// * `phi` - The inflaton field value (dimensionless in natural units)
+    ///
     /// Note: For quadratic potential, the second derivative is constant (m²)
     ///
     /// # Returns
@@ -180,6 +182,46 @@ impl Inflaton {
         self.update_potential();
         self.update_slow_roll_parameters();
     }
+
+    /// Snapshot of the field for a saved session
+    pub fn state(&self) -> InflatonState {
+        InflatonState {
+            phi: self.phi,
+            potential: self.potential,
+            potential_first_derivative: self.potential_first_derivative,
+            potential_second_derivative: self.potential_second_derivative,
+            epsilon: self.epsilon,
+            eta: self.eta,
+        }
+    }
+
+    /// Restores the field from a saved session snapshot
+    ///
+    /// Values are taken as saved rather than recomputed, so a field at φ = 0 (where
+    /// the slow-roll parameters are undefined) restores without panicking.
+    pub fn from_state(state: &InflatonState) -> Self {
+        Self {
+            phi: state.phi,
+            potential: state.potential,
+            potential_first_derivative: state.potential_first_derivative,
+            potential_second_derivative: state.potential_second_derivative,
+            epsilon: state.epsilon,
+            eta: state.eta,
+        }
+    }
+}
+
+/// Plugin that initializes the Inflaton field resource
+///
+/// This plugin registers the Inflaton as a Bevy Resource with
+/// initial field value and computed potential, derivatives,
+/// and slow-roll parameters.
+pub struct InflatonPlugin;
+
+impl Plugin for InflatonPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Inflaton::default());
+    }
 }
 
 #[cfg(test)]
@@ -543,16 +585,3 @@ mod tests {
         assert!((inflaton.eta - expected_eta).abs() < 1e-15);
     }
 }
-
-/// Plugin that initializes the Inflaton field resource
-///
-/// This plugin registers the Inflaton as a Bevy Resource with
-/// initial field value and computed potential, derivatives,
-/// and slow-roll parameters.
-pub struct InflatonPlugin;
-
-impl Plugin for InflatonPlugin {
-    fn build(&self, app: &mut App) {
-        app.insert_resource(Inflaton::default());
-    }
-}
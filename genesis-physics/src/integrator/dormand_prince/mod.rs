@@ -0,0 +1,626 @@
+//! Adaptive Dormand–Prince 5(4) integrator
+//!
+//! An embedded Runge–Kutta pair: each step computes a 5th-order solution and a
+//! 4th-order one from the same seven stages, and their difference estimates the
+//! local error. Steps whose error exceeds the tolerances are rejected and retried
+//! with a smaller step; accepted steps choose the next step size from the error,
+//! so the step grows by orders of magnitude along power-law solutions such as
+//! the expanding background.
+//!
+//! The last stage is evaluated at the end of the step and reused as the first
+//! stage of the next (first same as last), and every accepted step carries a
+//! 4th-order [`DenseStep`] interpolant for output between steps.
+//!
+//! # Example
+//!
+//! ```rust
+//! use genesis_physics::integrator::{dopri5_integrate, AdaptiveOptions};
+//!
+//! // dy/dt = -y from t = 0 to t = 10
+//! let options = AdaptiveOptions {
+//!     dense_output: true,
+//!     ..Default::default()
+//! };
+//! let solution = dopri5_integrate(&[1.0], 0.0, 10.0, &options, |_t, y| vec![-y[0]]).unwrap();
+//!
+//! assert!((solution.y[0] - (-10.0f64).exp()).abs() < 1e-10);
+//! let midway = solution.sample(5.0).unwrap();
+//! assert!((midway[0] - (-5.0f64).exp()).abs() < 1e-8);
+//! println!("{} steps, {} rejected", solution.stats.accepted_steps, solution.stats.rejected_steps);
+//! ```
+
+use std::fmt;
+
+use super::events::EventHit;
+
+/// Nodes c of the Dormand–Prince tableau
+const C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+
+/// Coupling coefficients a of the Dormand–Prince tableau, row by row
+const A: [[f64; 6]; 7] = [
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [19372.0 / 6561.0, -25360.0 / 2187.0, 64448.0 / 6561.0, -212.0 / 729.0, 0.0, 0.0],
+    [9017.0 / 3168.0, -355.0 / 33.0, 46732.0 / 5247.0, 49.0 / 176.0, -5103.0 / 18656.0, 0.0],
+    [35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0],
+];
+
+/// Difference between the 5th- and 4th-order weights, giving the error estimate
+const E: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+/// Weights of the 4th-order dense output polynomial
+const D: [f64; 7] = [
+    -12715105075.0 / 11282082432.0,
+    0.0,
+    87487479700.0 / 32700410799.0,
+    -10690763975.0 / 1880347072.0,
+    701980252875.0 / 199316789632.0,
+    -1453857185.0 / 822651844.0,
+    69997945.0 / 29380423.0,
+];
+
+/// Fraction of the optimal step size actually taken
+const SAFETY: f64 = 0.9;
+
+/// Largest factor the step may shrink by after a step
+const MIN_STEP_FACTOR: f64 = 0.2;
+
+/// Largest factor the step may grow by after a step
+const MAX_STEP_FACTOR: f64 = 10.0;
+
+/// Settings of an adaptive integration
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdaptiveOptions {
+    /// Absolute error tolerance per component
+    pub absolute_tolerance: f64,
+    /// Relative error tolerance per component
+    pub relative_tolerance: f64,
+    /// First step size to try (None = estimated from the derivative)
+    pub initial_step: Option<f64>,
+    /// Smallest step size before the integration gives up
+    pub min_step: f64,
+    /// Largest step size allowed
+    pub max_step: f64,
+    /// Largest number of accepted plus rejected steps
+    pub max_steps: usize,
+    /// Keep every step's interpolant in the [`Solution`]
+    pub dense_output: bool,
+}
+
+impl Default for AdaptiveOptions {
+    fn default() -> Self {
+        Self {
+            absolute_tolerance: 1e-10,
+            relative_tolerance: 1e-8,
+            initial_step: None,
+            min_step: 0.0,
+            max_step: f64::INFINITY,
+            max_steps: 100_000,
+            dense_output: false,
+        }
+    }
+}
+
+/// Work done by an adaptive integration
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IntegrationStats {
+    /// Steps whose error was within the tolerances
+    pub accepted_steps: usize,
+    /// Steps retried with a smaller step size
+    pub rejected_steps: usize,
+    /// Calls of the derivative function
+    pub evaluations: usize,
+    /// Evaluations of the Jacobian, by the stiff solvers only
+    pub jacobian_evaluations: usize,
+}
+
+/// Reason an adaptive integration stopped before reaching its end
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntegrationError {
+    /// The step size needed to meet the tolerances fell below the minimum
+    StepSizeTooSmall {
+        /// Time the integration reached
+        t: f64,
+        /// Step size that was required
+        step: f64,
+    },
+    /// `max_steps` steps were taken without reaching the end
+    TooManySteps {
+        /// Time the integration reached
+        t: f64,
+    },
+}
+
+impl fmt::Display for IntegrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrationError::StepSizeTooSmall { t, step } => {
+                write!(f, "step size {:e} too small to meet the tolerances at t = {:e}", step, t)
+            }
+            IntegrationError::TooManySteps { t } => {
+                write!(f, "step limit reached at t = {:e}", t)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntegrationError {}
+
+/// Interpolant over one accepted step
+///
+/// Evaluates the solution anywhere within the step to 4th order, from the stages
+/// already computed for the step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenseStep {
+    t: f64,
+    h: f64,
+    coefficients: [Vec<f64>; 5],
+}
+
+impl DenseStep {
+    /// Time at the start of the step
+    pub fn start(&self) -> f64 {
+        self.t
+    }
+
+    /// Time at the end of the step
+    pub fn end(&self) -> f64 {
+        self.t + self.h
+    }
+
+    /// Whether `t` lies within the step
+    pub fn contains(&self, t: f64) -> bool {
+        let (lo, hi) = if self.h >= 0.0 {
+            (self.start(), self.end())
+        } else {
+            (self.end(), self.start())
+        };
+        (lo..=hi).contains(&t)
+    }
+
+    /// Interpolated state at time `t`
+    pub fn evaluate(&self, t: f64) -> Vec<f64> {
+        let theta = (t - self.t) / self.h;
+        let theta1 = 1.0 - theta;
+        let [r1, r2, r3, r4, r5] = &self.coefficients;
+        (0..r1.len())
+            .map(|i| r1[i] + theta * (r2[i] + theta1 * (r3[i] + theta * (r4[i] + theta1 * r5[i]))))
+            .collect()
+    }
+}
+
+/// Result of [`dopri5_integrate`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    /// Time reached, the requested end time or that of a terminal event
+    pub t: f64,
+    /// State at `t`
+    pub y: Vec<f64>,
+    /// Work done
+    pub stats: IntegrationStats,
+    /// Event crossings, in the order they happened
+    ///
+    /// Only filled by [`dopri5_integrate_with_events`](super::dopri5_integrate_with_events).
+    pub events: Vec<EventHit>,
+    pub(super) steps: Vec<DenseStep>,
+}
+
+impl Solution {
+    /// Interpolants of every accepted step
+    ///
+    /// Empty without `dense_output`, and from solvers that have no interpolant.
+    pub fn dense_steps(&self) -> &[DenseStep] {
+        &self.steps
+    }
+
+    /// Interpolated state at time `t`
+    ///
+    /// # Returns
+    /// `None` without `dense_output` or when `t` is outside the integrated range
+    pub fn sample(&self, t: f64) -> Option<Vec<f64>> {
+        self.steps
+            .iter()
+            .find(|step| step.contains(t))
+            .map(|step| step.evaluate(t))
+    }
+}
+
+/// Step-by-step Dormand–Prince integrator
+///
+/// Each call to [`DormandPrince::step`] advances by one accepted step, retrying
+/// internally after rejections, and returns that step's interpolant. The last
+/// step is shortened to land exactly on the end time.
+pub struct DormandPrince<F> {
+    f: F,
+    options: AdaptiveOptions,
+    t: f64,
+    t_end: f64,
+    y: Vec<f64>,
+    k1: Vec<f64>,
+    h: f64,
+    stats: IntegrationStats,
+}
+
+impl<F> DormandPrince<F>
+where
+    F: FnMut(f64, &[f64]) -> Vec<f64>,
+{
+    /// Prepare to integrate dy/dt = f(t, y) from `y0` at `t0` to `t1`
+    ///
+    /// `t1` may lie before `t0` to integrate backwards in time.
+    pub fn new(y0: &[f64], t0: f64, t1: f64, options: &AdaptiveOptions, mut f: F) -> Self {
+        let k1 = f(t0, y0);
+        let mut integrator = Self {
+            f,
+            options: options.clone(),
+            t: t0,
+            t_end: t1,
+            y: y0.to_vec(),
+            k1,
+            h: 0.0,
+            stats: IntegrationStats {
+                evaluations: 1,
+                ..Default::default()
+            },
+        };
+        let direction = (t1 - t0).signum();
+        integrator.h = direction
+            * options.initial_step.map(f64::abs).unwrap_or_else(|| {
+                integrator.stats.evaluations += 1;
+                initial_step(&mut integrator.f, t0, t1, y0, &integrator.k1, options, 4)
+            });
+        integrator
+    }
+
+    /// Current time
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    /// Current state
+    pub fn y(&self) -> &[f64] {
+        &self.y
+    }
+
+    /// Work done so far
+    pub fn stats(&self) -> IntegrationStats {
+        self.stats
+    }
+
+    /// Whether the end time has been reached
+    pub fn is_finished(&self) -> bool {
+        self.t == self.t_end
+    }
+
+    /// Take one accepted step
+    ///
+    /// # Returns
+    /// * `Ok(DenseStep)` with the interpolant of the accepted step
+    /// * `Err(IntegrationError)` if the tolerances cannot be met or the step limit
+    ///   is reached; the state stays at the last accepted step
+    pub fn step(&mut self) -> Result<DenseStep, IntegrationError> {
+        let direction = (self.t_end - self.t).signum();
+        let n = self.y.len();
+        let mut stages = vec![vec![0.0; n]; 7];
+        let mut y_stage = vec![0.0; n];
+        let mut rejected_last = false;
+
+        loop {
+            if self.stats.accepted_steps + self.stats.rejected_steps >= self.options.max_steps {
+                return Err(IntegrationError::TooManySteps { t: self.t });
+            }
+
+            // Stay within the limits and do not step past the end
+            let mut h = self.h.abs().min(self.options.max_step);
+            let remaining = (self.t_end - self.t).abs();
+            let last = h >= remaining;
+            if last {
+                h = remaining;
+            }
+            let min_step = self.options.min_step.max(16.0 * f64::EPSILON * self.t.abs());
+            if h < min_step && !last {
+                return Err(IntegrationError::StepSizeTooSmall { t: self.t, step: h });
+            }
+            let h = direction * h;
+
+            // Stages k2..k7; k7 is f at the new state
+            stages[0].copy_from_slice(&self.k1);
+            for s in 1..7 {
+                for i in 0..n {
+                    let increment: f64 = (0..s).map(|j| A[s][j] * stages[j][i]).sum();
+                    y_stage[i] = self.y[i] + h * increment;
+                }
+                stages[s] = (self.f)(self.t + C[s] * h, &y_stage);
+            }
+            self.stats.evaluations += 6;
+            let y_new = y_stage.clone();
+
+            // RMS of the error relative to the mixed tolerance
+            let sum: f64 = (0..n)
+                .map(|i| {
+                    let estimate: f64 = h * (0..7).map(|j| E[j] * stages[j][i]).sum::<f64>();
+                    let scale = self.options.absolute_tolerance
+                        + self.options.relative_tolerance * self.y[i].abs().max(y_new[i].abs());
+                    (estimate / scale).powi(2)
+                })
+                .sum();
+            let error = if n == 0 { 0.0 } else { (sum / n as f64).sqrt() };
+
+            if error.is_nan() || error > 1.0 {
+                // Rejected, or the stages left the domain of f: shrink and retry
+                self.stats.rejected_steps += 1;
+                let factor = if error.is_finite() {
+                    (SAFETY * error.powf(-0.2)).max(MIN_STEP_FACTOR)
+                } else {
+                    MIN_STEP_FACTOR
+                };
+                self.h = h * factor;
+                rejected_last = true;
+                continue;
+            }
+
+            self.stats.accepted_steps += 1;
+            let dense = self.dense_step(h, &y_new, &stages);
+
+            let mut factor = if error == 0.0 {
+                MAX_STEP_FACTOR
+            } else {
+                (SAFETY * error.powf(-0.2)).clamp(MIN_STEP_FACTOR, MAX_STEP_FACTOR)
+            };
+            if rejected_last {
+                factor = factor.min(1.0);
+            }
+
+            self.t = if last { self.t_end } else { self.t + h };
+            self.y = y_new;
+            self.k1 = std::mem::take(&mut stages[6]);
+            if !last {
+                self.h = h * factor;
+            }
+            return Ok(dense);
+        }
+    }
+
+    /// Interpolant of a step of size `h` from the current state to `y_new`
+    fn dense_step(&self, h: f64, y_new: &[f64], stages: &[Vec<f64>]) -> DenseStep {
+        let n = y_new.len();
+        let mut coefficients: [Vec<f64>; 5] = Default::default();
+        coefficients[0] = self.y.clone();
+        for i in 0..n {
+            let difference = y_new[i] - self.y[i];
+            let slope_gap = h * stages[0][i] - difference;
+            coefficients[1].push(difference);
+            coefficients[2].push(slope_gap);
+            coefficients[3].push(difference - h * stages[6][i] - slope_gap);
+            coefficients[4].push(h * (0..7).map(|j| D[j] * stages[j][i]).sum::<f64>());
+        }
+        DenseStep {
+            t: self.t,
+            h,
+            coefficients,
+        }
+    }
+}
+
+/// Estimate a first step from the size of the state and its derivatives
+///
+/// Takes the step for which an explicit Euler step would change the state by
+/// about 1% of its scale, then limits it by the second derivative, assuming a
+/// local error of order `order + 1`. Costs one evaluation of `f`.
+pub(super) fn initial_step<F>(
+    f: &mut F,
+    t: f64,
+    t_end: f64,
+    y: &[f64],
+    dydt: &[f64],
+    options: &AdaptiveOptions,
+    order: i32,
+) -> f64
+where
+    F: FnMut(f64, &[f64]) -> Vec<f64>,
+{
+    let span = (t_end - t).abs();
+    if span == 0.0 {
+        return 0.0;
+    }
+
+    let scale = |i: usize| options.absolute_tolerance + options.relative_tolerance * y[i].abs();
+    let norm = |values: &[f64]| {
+        let sum: f64 = (0..values.len()).map(|i| (values[i] / scale(i)).powi(2)).sum();
+        (sum / values.len().max(1) as f64).sqrt()
+    };
+
+    let d0 = norm(y);
+    let d1 = norm(dydt);
+    let h0 = if d0 < 1e-5 || d1 < 1e-5 { 1e-6 } else { 0.01 * d0 / d1 };
+    let h0 = h0.min(span);
+
+    // Second derivative from one explicit Euler step
+    let direction = (t_end - t).signum();
+    let y1: Vec<f64> = (0..y.len()).map(|i| y[i] + direction * h0 * dydt[i]).collect();
+    let f1 = f(t + direction * h0, &y1);
+    let change: Vec<f64> = (0..f1.len()).map(|i| f1[i] - dydt[i]).collect();
+    let d2 = norm(&change) / h0;
+
+    let h1 = if d1.max(d2) <= 1e-15 {
+        (h0 * 1e-3).max(1e-6)
+    } else {
+        (0.01 / d1.max(d2)).powf(1.0 / (order + 1) as f64)
+    };
+    (100.0 * h0).min(h1).min(span)
+}
+
+/// Integrate dy/dt = f(t, y) from `t0` to `t1` with adaptive Dormand–Prince steps
+///
+/// # Arguments
+/// * `y0` - Initial state vector at time t0
+/// * `t0` - Initial time
+/// * `t1` - Final time, before `t0` to integrate backwards
+/// * `options` - Tolerances, step limits and whether to keep the dense output
+/// * `f` - Derivative function dy/dt = f(t, y)
+///
+/// # Returns
+/// * `Ok(Solution)` with the state at `t1` and the statistics of the run
+/// * `Err(IntegrationError)` if the tolerances could not be met
+pub fn dopri5_integrate<F>(
+    y0: &[f64],
+    t0: f64,
+    t1: f64,
+    options: &AdaptiveOptions,
+    f: F,
+) -> Result<Solution, IntegrationError>
+where
+    F: FnMut(f64, &[f64]) -> Vec<f64>,
+{
+    let mut integrator = DormandPrince::new(y0, t0, t1, options, f);
+    let mut steps = Vec::new();
+    while !integrator.is_finished() {
+        let step = integrator.step()?;
+        if options.dense_output {
+            steps.push(step);
+        }
+    }
+
+    Ok(Solution {
+        t: integrator.t(),
+        y: integrator.y().to_vec(),
+        stats: integrator.stats(),
+        events: Vec::new(),
+        steps,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tolerances(relative: f64) -> AdaptiveOptions {
+        AdaptiveOptions {
+            absolute_tolerance: relative * 1e-2,
+            relative_tolerance: relative,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_exponential_decay_meets_tolerance() {
+        for relative in [1e-4, 1e-8, 1e-11] {
+            let solution = dopri5_integrate(&[1.0], 0.0, 5.0, &tolerances(relative), |_t, y| {
+                vec![-y[0]]
+            })
+            .unwrap();
+            let exact = (-5.0f64).exp();
+            assert_eq!(solution.t, 5.0);
+            assert!(
+                (solution.y[0] - exact).abs() / exact < 100.0 * relative,
+                "rtol {}: {} vs {}",
+                relative,
+                solution.y[0],
+                exact
+            );
+        }
+    }
+
+    #[test]
+    fn test_tighter_tolerances_take_more_steps() {
+        let oscillator = |_t: f64, y: &[f64]| vec![y[1], -y[0]];
+        let loose = dopri5_integrate(&[1.0, 0.0], 0.0, 20.0, &tolerances(1e-4), oscillator).unwrap();
+        let tight = dopri5_integrate(&[1.0, 0.0], 0.0, 20.0, &tolerances(1e-10), oscillator).unwrap();
+
+        assert!(tight.stats.accepted_steps > 5 * loose.stats.accepted_steps);
+        assert!((tight.y[0] - 20.0f64.cos()).abs() < 1e-8);
+        assert!((tight.y[1] + 20.0f64.sin()).abs() < 1e-8);
+
+        // Seven stages per attempt, the last reused, plus the start-up evaluations
+        let attempts = tight.stats.accepted_steps + tight.stats.rejected_steps;
+        assert_eq!(tight.stats.evaluations, 6 * attempts + 2);
+    }
+
+    #[test]
+    fn test_steps_are_rejected_and_recover() {
+        // A first step far too large for the tolerance is cut down, then grows again
+        let options = AdaptiveOptions {
+            initial_step: Some(5.0),
+            ..tolerances(1e-9)
+        };
+        let solution = dopri5_integrate(&[1.0], 0.0, 20.0, &options, |_t, y| vec![-y[0]]).unwrap();
+
+        assert!(solution.stats.rejected_steps > 0);
+        let exact = (-20.0f64).exp();
+        assert!((solution.y[0] - exact).abs() < 1e-10, "{} vs {}", solution.y[0], exact);
+    }
+
+    #[test]
+    fn test_dense_output_interpolates_between_steps() {
+        let options = AdaptiveOptions {
+            dense_output: true,
+            ..tolerances(1e-9)
+        };
+        let solution =
+            dopri5_integrate(&[1.0, 0.0], 0.0, 10.0, &options, |_t, y| vec![y[1], -y[0]]).unwrap();
+
+        let steps = solution.dense_steps();
+        assert_eq!(steps.len(), solution.stats.accepted_steps);
+        assert!(steps.windows(2).all(|pair| pair[0].end() == pair[1].start()));
+        for i in 0..=100 {
+            let t = 0.1 * i as f64;
+            let y = solution.sample(t).unwrap();
+            assert!((y[0] - t.cos()).abs() < 1e-7, "y({}) = {}", t, y[0]);
+        }
+        assert_eq!(solution.sample(10.5), None);
+    }
+
+    #[test]
+    fn test_backward_integration() {
+        let solution =
+            dopri5_integrate(&[(-2.0f64).exp()], 2.0, 0.0, &tolerances(1e-10), |_t, y| vec![-y[0]])
+                .unwrap();
+        assert_eq!(solution.t, 0.0);
+        assert!((solution.y[0] - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_steps_grow_across_decades() {
+        // Einstein–de Sitter a(t) = t^(2/3) from t = 10⁻²⁰ to 1 in a few hundred steps
+        let t0 = 1e-20_f64;
+        let options = AdaptiveOptions {
+            absolute_tolerance: 0.0,
+            ..tolerances(1e-9)
+        };
+        let solution =
+            dopri5_integrate(&[t0.powf(2.0 / 3.0)], t0, 1.0, &options, |t, y| vec![2.0 * y[0] / (3.0 * t)])
+                .unwrap();
+
+        assert!((solution.y[0] - 1.0).abs() < 1e-6, "a(1) = {}", solution.y[0]);
+        assert!(solution.stats.accepted_steps < 1000, "{:?}", solution.stats);
+    }
+
+    #[test]
+    fn test_failures_are_reported() {
+        let options = AdaptiveOptions {
+            max_steps: 10,
+            ..tolerances(1e-12)
+        };
+        let error = dopri5_integrate(&[1.0, 0.0], 0.0, 100.0, &options, |_t, y| vec![y[1], -y[0]])
+            .unwrap_err();
+        assert!(matches!(error, IntegrationError::TooManySteps { .. }));
+
+        // y' = y² blows up at t = 1
+        let error = dopri5_integrate(&[1.0], 0.0, 2.0, &tolerances(1e-8), |_t, y| vec![y[0] * y[0]])
+            .unwrap_err();
+        match error {
+            IntegrationError::StepSizeTooSmall { t, .. } => assert!((t - 1.0).abs() < 1e-3, "t = {}", t),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,414 @@
+//! Event detection during adaptive integration
+//!
+//! An event is a scalar function g(t, y) whose zero marks something physical
+//! happening: the slow-roll parameter reaching ε = 1, the temperature falling to
+//! n/p freeze-out, or the free electron fraction dropping to x_e = 0.5. After
+//! every accepted Dormand–Prince step the [`EventLocator`] compares the sign of
+//! each event function at both ends of the step. When it changed, the crossing
+//! is located by root finding on the step's [`DenseStep`] interpolant, so the
+//! event time is accurate to the integration tolerances rather than to the
+//! step size, and no extra derivative evaluations are needed.
+//!
+//! Events are reported as [`EventHit`]s. A terminal event also stops the
+//! integration at the crossing.
+//!
+//! Only sign changes between the ends of a step are seen: a function that
+//! crosses zero twice within one step goes undetected, which `max_step` can
+//! rule out when events come in close pairs.
+//!
+//! # Example
+//!
+//! ```rust
+//! use genesis_physics::integrator::{dopri5_integrate_with_events, AdaptiveOptions, Event};
+//!
+//! // A body falling from 10 m stops when it hits the ground
+//! let falling = |_t: f64, y: &[f64]| vec![y[1], -9.81];
+//! let ground = Event::new(|_t, y: &[f64]| y[0]).falling().terminal();
+//! let options = AdaptiveOptions::default();
+//! let solution =
+//!     dopri5_integrate_with_events(&[10.0, 0.0], 0.0, 5.0, &options, falling, vec![ground]).unwrap();
+//!
+//! let impact = (2.0 * 10.0 / 9.81f64).sqrt();
+//! assert!((solution.t - impact).abs() < 1e-9);
+//! assert_eq!(solution.events.len(), 1);
+//! ```
+
+use std::fmt;
+
+use super::dormand_prince::{AdaptiveOptions, DenseStep, DormandPrince, IntegrationError, Solution};
+
+/// Largest number of root-finding iterations per crossing
+const MAX_ROOT_ITERATIONS: usize = 200;
+
+/// Which sign changes of an event function count as the event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Crossing {
+    /// From negative to positive
+    Rising,
+    /// From positive to negative
+    Falling,
+    /// Either way
+    #[default]
+    Either,
+}
+
+impl Crossing {
+    /// Whether a change of sign from `before` to `after` is such a crossing
+    fn matches(self, before: f64, after: f64) -> bool {
+        let crossed = before != 0.0 && (after == 0.0 || before.signum() != after.signum());
+        crossed
+            && match self {
+                Crossing::Rising => before < 0.0,
+                Crossing::Falling => before > 0.0,
+                Crossing::Either => true,
+            }
+    }
+}
+
+/// Boxed event function g(t, y)
+type EventFn<'a> = Box<dyn FnMut(f64, &[f64]) -> f64 + 'a>;
+
+/// An event function g(t, y) that is watched for zero crossings
+pub struct Event<'a> {
+    function: EventFn<'a>,
+    crossing: Crossing,
+    terminal: bool,
+}
+
+impl<'a> Event<'a> {
+    /// Watch `function` for crossings in either direction, without stopping
+    pub fn new(function: impl FnMut(f64, &[f64]) -> f64 + 'a) -> Self {
+        Self {
+            function: Box::new(function),
+            crossing: Crossing::Either,
+            terminal: false,
+        }
+    }
+
+    /// Only count crossings from negative to positive
+    pub fn rising(self) -> Self {
+        self.with_crossing(Crossing::Rising)
+    }
+
+    /// Only count crossings from positive to negative
+    pub fn falling(self) -> Self {
+        self.with_crossing(Crossing::Falling)
+    }
+
+    /// Only count crossings in the given direction
+    pub fn with_crossing(mut self, crossing: Crossing) -> Self {
+        self.crossing = crossing;
+        self
+    }
+
+    /// Stop the integration at the first crossing
+    pub fn terminal(mut self) -> Self {
+        self.terminal = true;
+        self
+    }
+
+    /// Direction of the crossings that count
+    pub fn crossing(&self) -> Crossing {
+        self.crossing
+    }
+
+    /// Whether the integration stops at this event
+    pub fn is_terminal(&self) -> bool {
+        self.terminal
+    }
+}
+
+impl fmt::Debug for Event<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Event")
+            .field("crossing", &self.crossing)
+            .field("terminal", &self.terminal)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An event that happened during an integration
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventHit {
+    /// Index of the event in the list it was given in
+    pub event: usize,
+    /// Time of the crossing
+    ///
+    /// The first time found at which the function has reached zero or changed
+    /// sign, so state criteria evaluated here already see the new side.
+    pub t: f64,
+    /// Interpolated state at `t`
+    pub y: Vec<f64>,
+    /// Whether the integration stopped here
+    pub terminal: bool,
+}
+
+/// Locates event crossings within accepted steps
+///
+/// Feed it every [`DenseStep`] of an integration in order, starting from the
+/// state it was created with.
+#[derive(Debug)]
+pub struct EventLocator<'a> {
+    events: Vec<Event<'a>>,
+    /// Event function values at the end of the last step
+    values: Vec<f64>,
+}
+
+impl<'a> EventLocator<'a> {
+    /// Start watching `events` from the state `y0` at `t0`
+    pub fn new(mut events: Vec<Event<'a>>, t0: f64, y0: &[f64]) -> Self {
+        let values = events.iter_mut().map(|event| (event.function)(t0, y0)).collect();
+        Self { events, values }
+    }
+
+    /// Events that cross zero within `step`, in the order they happen
+    ///
+    /// The list ends at the first terminal event; later crossings in the same
+    /// step are not reported because the integration stops before them.
+    pub fn locate(&mut self, step: &DenseStep) -> Vec<EventHit> {
+        let end = step.end();
+        let y_end = step.evaluate(end);
+
+        let mut hits = Vec::new();
+        for (index, event) in self.events.iter_mut().enumerate() {
+            let before = self.values[index];
+            let after = (event.function)(end, &y_end);
+            self.values[index] = after;
+            if !event.crossing.matches(before, after) {
+                continue;
+            }
+
+            let t = find_crossing(step, before, &mut event.function);
+            hits.push(EventHit {
+                event: index,
+                t,
+                y: step.evaluate(t),
+                terminal: event.terminal,
+            });
+        }
+
+        // In integration order, which is backwards in time for negative steps
+        let start = step.start();
+        hits.sort_by(|a, b| (a.t - start).abs().total_cmp(&(b.t - start).abs()));
+        if let Some(first_terminal) = hits.iter().position(|hit| hit.terminal) {
+            hits.truncate(first_terminal + 1);
+        }
+        hits
+    }
+}
+
+/// Time within `step` at which `function` first leaves the sign of `before`
+///
+/// Illinois-modified regula falsi on the interpolated solution: as fast as the
+/// secant method near a simple root, while always keeping the root bracketed.
+fn find_crossing<G>(step: &DenseStep, before: f64, function: &mut G) -> f64
+where
+    G: FnMut(f64, &[f64]) -> f64 + ?Sized,
+{
+    let g = |function: &mut G, t: f64| function(t, &step.evaluate(t));
+
+    // `a` keeps the sign of `before`; `b` has reached zero or the other sign
+    let (mut a, mut ga) = (step.start(), before);
+    let (mut b, mut gb) = (step.end(), g(function, step.end()));
+    let mut side = 0;
+    let span = (b - a).abs();
+
+    for _ in 0..MAX_ROOT_ITERATIONS {
+        let tolerance = 4.0 * f64::EPSILON * a.abs().max(b.abs()).max(span);
+        if gb == 0.0 || (b - a).abs() <= tolerance {
+            break;
+        }
+
+        // Fall back to bisection when the secant leaves the bracket
+        let mut t = b - gb * (b - a) / (gb - ga);
+        let inside = t > a.min(b) && t < a.max(b);
+        if !inside {
+            t = 0.5 * (a + b);
+        }
+        let gt = g(function, t);
+
+        if gt == 0.0 || gt.signum() != ga.signum() {
+            b = t;
+            gb = gt;
+            if side == -1 {
+                ga *= 0.5;
+            }
+            side = -1;
+        } else {
+            a = t;
+            ga = gt;
+            if side == 1 {
+                gb *= 0.5;
+            }
+            side = 1;
+        }
+    }
+    b
+}
+
+/// Integrate dy/dt = f(t, y) from `t0` to `t1`, watching for events
+///
+/// As [`dopri5_integrate`](super::dopri5_integrate), reporting every crossing
+/// of the `events` in [`Solution::events`]. At the first terminal event the
+/// integration stops, and the solution holds the time and state of the crossing.
+///
+/// # Arguments
+/// * `y0` - Initial state vector at time t0
+/// * `t0` - Initial time
+/// * `t1` - Final time, before `t0` to integrate backwards
+/// * `options` - Tolerances, step limits and whether to keep the dense output
+/// * `f` - Derivative function dy/dt = f(t, y)
+/// * `events` - Event functions to watch
+///
+/// # Returns
+/// * `Ok(Solution)` with the state at `t1` or at the terminal event
+/// * `Err(IntegrationError)` if the tolerances could not be met
+pub fn dopri5_integrate_with_events<F>(
+    y0: &[f64],
+    t0: f64,
+    t1: f64,
+    options: &AdaptiveOptions,
+    f: F,
+    events: Vec<Event<'_>>,
+) -> Result<Solution, IntegrationError>
+where
+    F: FnMut(f64, &[f64]) -> Vec<f64>,
+{
+    let mut integrator = DormandPrince::new(y0, t0, t1, options, f);
+    let mut locator = EventLocator::new(events, t0, y0);
+    let mut steps = Vec::new();
+    let mut hits = Vec::new();
+
+    while !integrator.is_finished() {
+        let step = integrator.step()?;
+        hits.extend(locator.locate(&step));
+        if options.dense_output {
+            steps.push(step);
+        }
+
+        if let Some(stop) = hits.last().filter(|hit| hit.terminal) {
+            return Ok(Solution {
+                t: stop.t,
+                y: stop.y.clone(),
+                stats: integrator.stats(),
+                events: hits,
+                steps,
+            });
+        }
+    }
+
+    Ok(Solution {
+        t: integrator.t(),
+        y: integrator.y().to_vec(),
+        stats: integrator.stats(),
+        events: hits,
+        steps,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tolerances(relative: f64) -> AdaptiveOptions {
+        AdaptiveOptions {
+            absolute_tolerance: relative * 1e-2,
+            relative_tolerance: relative,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_crossings_are_reported_in_order() {
+        // y = cos t crosses zero at π/2, 3π/2 and 5π/2 before t = 10
+        let oscillator = |_t: f64, y: &[f64]| vec![y[1], -y[0]];
+        let events = vec![
+            Event::new(|_t, y: &[f64]| y[0]),
+            Event::new(|_t, y: &[f64]| y[0]).falling(),
+            Event::new(|_t, y: &[f64]| y[0] - 2.0),
+        ];
+        let solution =
+            dopri5_integrate_with_events(&[1.0, 0.0], 0.0, 10.0, &tolerances(1e-10), oscillator, events)
+                .unwrap();
+
+        assert_eq!(solution.t, 10.0);
+        let times = |event: usize| -> Vec<f64> {
+            solution.events.iter().filter(|hit| hit.event == event).map(|hit| hit.t).collect()
+        };
+        let (either, falling) = (times(0), times(1));
+        let half_pi = std::f64::consts::FRAC_PI_2;
+        assert_eq!(either.len(), 3);
+        assert_eq!(falling.len(), 2);
+        for (t, expected) in either.iter().zip([half_pi, 3.0 * half_pi, 5.0 * half_pi]) {
+            assert!((t - expected).abs() < 1e-8, "{} vs {}", t, expected);
+        }
+        for (t, expected) in falling.iter().zip([half_pi, 5.0 * half_pi]) {
+            assert!((t - expected).abs() < 1e-8, "{} vs {}", t, expected);
+        }
+        assert!(solution.events.windows(2).all(|pair| pair[0].t <= pair[1].t));
+        assert!(solution.events.iter().all(|hit| !hit.terminal));
+    }
+
+    #[test]
+    fn test_terminal_event_at_freeze_out_temperature() {
+        // Radiation era a ∝ t^(1/2) with T = 1/a: T = 0.01 at exactly t = 10⁴
+        let expansion = |t: f64, y: &[f64]| vec![y[0] / (2.0 * t)];
+        let freeze_out = Event::new(|_t, y: &[f64]| 1.0 / y[0] - 0.01).falling().terminal();
+        let options = tolerances(1e-11);
+        let solution =
+            dopri5_integrate_with_events(&[1.0], 1.0, 1e6, &options, expansion, vec![freeze_out]).unwrap();
+
+        assert_eq!(solution.events.len(), 1);
+        assert!(solution.events[0].terminal);
+        assert!(((solution.t - 1e4) / 1e4).abs() < 1e-9, "t = {}", solution.t);
+        assert!((solution.y[0] - 100.0).abs() < 1e-7);
+        // The crossing has already happened at the reported time
+        assert!(1.0 / solution.y[0] - 0.01 <= 1e-12);
+    }
+
+    #[test]
+    fn test_inflation_ends_at_unit_slow_roll() {
+        // φ'' + 3Hφ' + m²φ = 0 in units of the reduced Planck mass and 1/m, with
+        // 3H² = φ'²/2 + φ²/2 and ε_H = -Ḣ/H² = φ'²/(2H²)
+        let hubble = |y: &[f64]| ((0.5 * y[1] * y[1] + 0.5 * y[0] * y[0]) / 3.0).sqrt();
+        let field = move |_t: f64, y: &[f64]| vec![y[1], -3.0 * hubble(y) * y[1] - y[0]];
+        let epsilon = move |y: &[f64]| 0.5 * y[1] * y[1] / hubble(y).powi(2);
+        let end_of_inflation = Event::new(move |_t, y: &[f64]| epsilon(y) - 1.0).rising().terminal();
+
+        let slow_roll_velocity = -(2.0f64 / 3.0).sqrt();
+        let solution = dopri5_integrate_with_events(
+            &[15.0, slow_roll_velocity],
+            0.0,
+            1e3,
+            &tolerances(1e-10),
+            field,
+            vec![end_of_inflation],
+        )
+        .unwrap();
+
+        assert!(solution.t < 1e3);
+        assert!((epsilon(&solution.y) - 1.0).abs() < 1e-8, "ε = {}", epsilon(&solution.y));
+        // The slow-roll estimate φ_end = √2 is only approximate
+        assert!(solution.y[0] > 0.5 && solution.y[0] < 1.5, "φ_end = {}", solution.y[0]);
+    }
+
+    #[test]
+    fn test_first_terminal_event_in_a_step_wins() {
+        // Both thresholds fall inside the single step; only the earlier stops it
+        let options = AdaptiveOptions {
+            initial_step: Some(10.0),
+            ..tolerances(1e-8)
+        };
+        let events = vec![
+            Event::new(|_t, y: &[f64]| y[0] - 6.0).terminal(),
+            Event::new(|_t, y: &[f64]| y[0] - 3.0).terminal(),
+        ];
+        let constant = |_t: f64, _y: &[f64]| vec![1.0];
+        let solution = dopri5_integrate_with_events(&[0.0], 0.0, 10.0, &options, constant, events).unwrap();
+
+        assert_eq!(solution.stats.accepted_steps, 1);
+        assert_eq!(solution.events.len(), 1);
+        assert_eq!(solution.events[0].event, 1);
+        assert!((solution.t - 3.0).abs() < 1e-12);
+    }
+}
@@ -5,28 +5,163 @@
 //!
 //! The Runge-Kutta 4th order (RK4) method is a widely used numerical
 //! integration technique that provides good accuracy for many scientific
-//! and engineering applications.
+//! and engineering applications. [`Rk4`] steps any [`OdeState`] in place with
+//! stage buffers it allocates once, so it can advance large systems every frame
+//! without touching the allocator; [`rk4_step`] and [`rk4_integrate`] wrap it
+//! for slices and closures that return a new derivative vector.
+//!
+//! For solutions that span many orders of magnitude, [`dormand_prince`] chooses
+//! the step size itself from absolute and relative error tolerances.
+//!
+//! Stiff systems, such as reaction networks whose rates span many orders of
+//! magnitude, are integrated with the linearly implicit [`rosenbrock`] method,
+//! using the same tolerances.
+//!
+//! [`events`] stops or reports an adaptive integration exactly where an event
+//! function crosses zero, found on the dense output of each step.
 //!
 //! # Time Units
 //!
 //! All time units are in natural units (GeV⁻¹) for consistency with
 //! the GENESIS cosmological simulation.
 
-/// Generic 4th-order Runge-Kutta integrator
+pub mod dormand_prince;
+pub mod events;
+pub mod rosenbrock;
+pub mod state;
+
+pub use dormand_prince::{
+    dopri5_integrate, AdaptiveOptions, DenseStep, DormandPrince, IntegrationError,
+    IntegrationStats, Solution,
+};
+pub use events::{dopri5_integrate_with_events, Crossing, Event, EventHit, EventLocator};
+pub use rosenbrock::{rosenbrock_integrate, rosenbrock_integrate_with_jacobian, JacobianFn, Rosenbrock};
+pub use state::OdeState;
+
+/// Reusable 4th-order Runge-Kutta stepper
 ///
-/// Performs a single RK4 integration step from time `t` to `t + dt`.
+/// Holds the four slope estimates and the intermediate state as buffers of the
+/// same shape as the state, created once in [`Rk4::new`]. The derivative function
+/// writes dy/dt into a buffer it is given instead of returning a new one, so a
+/// step performs no allocation.
 ///
-/// The RK4 algorithm computes four slope estimates and combines them
-/// to achieve 4th-order accuracy:
+/// # Example
+///
+/// ```rust
+/// use genesis_physics::integrator::Rk4;
 ///
-/// ```text
-/// k1 = f(t, y)
-/// k2 = f(t + dt/2, y + k1*dt/2)
-/// k3 = f(t + dt/2, y + k2*dt/2)
-/// k4 = f(t + dt, y + k3*dt)
+/// // Simple harmonic oscillator on a stack-allocated state
+/// let mut y = [1.0, 0.0]; // [position, velocity]
+/// let mut rk4 = Rk4::new(&y);
+/// let oscillator = |_t: f64, state: &[f64; 2], dydt: &mut [f64; 2]| {
+///     dydt[0] = state[1];
+///     dydt[1] = -state[0];
+/// };
 ///
-/// y(t + dt) = y(t) + (k1 + 2*k2 + 2*k3 + k4) * dt / 6
+/// let mut t = 0.0;
+/// for _ in 0..100 {
+///     rk4.step(&mut y, t, 0.01, oscillator);
+///     t += 0.01;
+/// }
+/// assert!((y[0] - 1.0f64.cos()).abs() < 1e-9);
 /// ```
+#[derive(Debug, Clone)]
+pub struct Rk4<S> {
+    k1: S,
+    k2: S,
+    k3: S,
+    k4: S,
+    stage: S,
+}
+
+impl<S: OdeState> Rk4<S> {
+    /// Create a stepper whose buffers have the shape of `template`
+    pub fn new(template: &S) -> Self {
+        Self {
+            k1: template.clone(),
+            k2: template.clone(),
+            k3: template.clone(),
+            k4: template.clone(),
+            stage: template.clone(),
+        }
+    }
+
+    /// Advance `y` in place from time `t` to `t + dt`
+    ///
+    /// ```text
+    /// k1 = f(t, y)
+    /// k2 = f(t + dt/2, y + k1*dt/2)
+    /// k3 = f(t + dt/2, y + k2*dt/2)
+    /// k4 = f(t + dt, y + k3*dt)
+    ///
+    /// y(t + dt) = y(t) + (k1 + 2*k2 + 2*k3 + k4) * dt / 6
+    /// ```
+    ///
+    /// # Arguments
+    /// * `y` - State, overwritten with the state at `t + dt`
+    /// * `t` - Current time
+    /// * `dt` - Time step
+    /// * `f` - Derivative function writing dy/dt = f(t, y) into its last argument
+    pub fn step<F>(&mut self, y: &mut S, t: f64, dt: f64, mut f: F)
+    where
+        F: FnMut(f64, &S, &mut S),
+    {
+        let half_dt = dt / 2.0;
+
+        f(t, y, &mut self.k1);
+        self.stage.set_scaled_sum(y, &self.k1, half_dt);
+        f(t + half_dt, &self.stage, &mut self.k2);
+        self.stage.set_scaled_sum(y, &self.k2, half_dt);
+        f(t + half_dt, &self.stage, &mut self.k3);
+        self.stage.set_scaled_sum(y, &self.k3, dt);
+        f(t + dt, &self.stage, &mut self.k4);
+
+        let sixth_dt = dt / 6.0;
+        y.add_scaled(&self.k1, sixth_dt);
+        y.add_scaled(&self.k2, 2.0 * sixth_dt);
+        y.add_scaled(&self.k3, 2.0 * sixth_dt);
+        y.add_scaled(&self.k4, sixth_dt);
+    }
+
+    /// Advance `y` in place from `t0` to `t1` with fixed time steps
+    ///
+    /// Takes `ceil((t1 - t0) / dt)` equal steps, so the step actually used may be
+    /// slightly smaller than `dt` in order to land exactly on `t1`. A non-positive
+    /// `dt` or interval leaves `y` untouched.
+    ///
+    /// # Returns
+    /// Final time (should equal t1)
+    pub fn integrate<F>(&mut self, y: &mut S, t0: f64, t1: f64, dt: f64, mut f: F) -> f64
+    where
+        F: FnMut(f64, &S, &mut S),
+    {
+        let total_time = t1 - t0;
+        if dt <= 0.0 || total_time <= 0.0 {
+            return t0;
+        }
+
+        let n_steps = (total_time / dt).ceil() as usize;
+        if n_steps == 0 {
+            return t0;
+        }
+
+        // Adjust dt to exactly reach t1
+        let actual_dt = total_time / n_steps as f64;
+        let mut t = t0;
+        for _ in 0..n_steps {
+            self.step(y, t, actual_dt, &mut f);
+            t += actual_dt;
+        }
+        t
+    }
+}
+
+/// Generic 4th-order Runge-Kutta integrator
+///
+/// Performs a single RK4 integration step from time `t` to `t + dt`.
+///
+/// This is a convenience wrapper over [`Rk4`] that allocates the stage buffers
+/// and the returned state on every call; use [`Rk4`] directly in hot loops.
 ///
 /// # Arguments
 /// * `y` - Current state vector
@@ -68,45 +203,10 @@ where
     F: Fn(f64, &[T]) -> Vec<T>,
     T: Copy + std::ops::Add<Output = T> + std::ops::Mul<f64, Output = T> + std::ops::Div<f64, Output = T>,
 {
-    let n = y.len();
-
-    // k1 = f(t, y)
-    let k1 = f(t, y);
-
-    // Compute y + k1 * dt/2 for k2 calculation
-    let mut y_temp: Vec<T> = Vec::with_capacity(n);
-    let half_dt = dt / 2.0;
-    for i in 0..n {
-        y_temp.push(y[i] + k1[i] * half_dt);
-    }
-
-    // k2 = f(t + dt/2, y + k1*dt/2)
-    let k2 = f(t + half_dt, &y_temp);
-
-    // Compute y + k2 * dt/2 for k3 calculation
-    for i in 0..n {
-        y_temp[i] = y[i] + k2[i] * half_dt;
-    }
-
-    // k3 = f(t + dt/2, y + k2*dt/2)
-    let k3 = f(t + half_dt, &y_temp);
-
-    // Compute y + k3 * dt for k4 calculation
-    for i in 0..n {
-        y_temp[i] = y[i] + k3[i] * dt;
-    }
-
-    // k4 = f(t + dt, y + k3*dt)
-    let k4 = f(t + dt, &y_temp);
-
-    // Combine all slopes: y_new = y + (k1 + 2*k2 + 2*k3 + k4) * dt / 6
-    let mut y_new: Vec<T> = Vec::with_capacity(n);
-    let sixth_dt = dt / 6.0;
-    for i in 0..n {
-        // (k1 + 2*k2 + 2*k3 + k4) * dt / 6
-        y_new.push(y[i] + (k1[i] + k2[i] * 2.0 + k3[i] * 2.0 + k4[i]) * sixth_dt);
-    }
-
+    let mut y_new = y.to_vec();
+    Rk4::new(&y_new).step(&mut y_new, t, dt, |t, state: &Vec<T>, dydt: &mut Vec<T>| {
+        dydt.copy_from_slice(&f(t, state));
+    });
     y_new
 }
 
@@ -117,7 +217,8 @@ where
 ///
 /// The total number of steps is calculated as `n_steps = ceil((t1 - t0) / dt)`,
 /// and the actual time step used may be slightly smaller than `dt` to ensure
-/// exactly reaching `t1`.
+/// exactly reaching `t1`. The stage buffers are allocated once for the whole
+/// integration, see [`Rk4::integrate`].
 ///
 /// # Arguments
 /// * `y0` - Initial state vector at time t0
@@ -161,40 +262,76 @@ pub fn rk4_integrate<F, T>(
     f: F,
 ) -> (Vec<T>, f64)
 where
-    F: Fn(f64, &[T]) -> Vec<T> + Clone,
+    F: Fn(f64, &[T]) -> Vec<T>,
     T: Copy + std::ops::Add<Output = T> + std::ops::Mul<f64, Output = T> + std::ops::Div<f64, Output = T>,
 {
-    // Calculate the actual time step to exactly reach t1
-    let total_time = t1 - t0;
-    
-    // Handle edge cases
-    if dt <= 0.0 {
-        // Invalid dt, return initial state
-        return (y0.to_vec(), t0);
-    }
-    
-    if total_time <= 0.0 {
-        // No time to advance
-        return (y0.to_vec(), t0);
+    let mut y = y0.to_vec();
+    let t = Rk4::new(&y).integrate(&mut y, t0, t1, dt, |t, state: &Vec<T>, dydt: &mut Vec<T>| {
+        dydt.copy_from_slice(&f(t, state));
+    });
+    (y, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_types_agree_with_expanded_rk4() {
+        // One RK4 step written out by hand, independent of the stepper
+        let f = |t: f64, y: &[f64]| vec![y[1], -y[0] + t];
+        let (y, t, dt) = ([1.0, 0.5], 0.2, 0.1);
+        let k1 = f(t, &y);
+        let k2 = f(t + dt / 2.0, &[y[0] + k1[0] * dt / 2.0, y[1] + k1[1] * dt / 2.0]);
+        let k3 = f(t + dt / 2.0, &[y[0] + k2[0] * dt / 2.0, y[1] + k2[1] * dt / 2.0]);
+        let k4 = f(t + dt, &[y[0] + k3[0] * dt, y[1] + k3[1] * dt]);
+        let expected: Vec<f64> = (0..2)
+            .map(|i| y[i] + (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]) * dt / 6.0)
+            .collect();
+
+        let mut array = y;
+        Rk4::new(&array).step(&mut array, t, dt, |t, y: &[f64; 2], dydt: &mut [f64; 2]| {
+            dydt.copy_from_slice(&f(t, y));
+        });
+        let mut vector = y.to_vec();
+        Rk4::new(&vector).step(&mut vector, t, dt, |t, y: &Vec<f64>, dydt: &mut Vec<f64>| {
+            dydt.copy_from_slice(&f(t, y));
+        });
+        let wrapped = rk4_step(&y, t, dt, f);
+
+        for i in 0..2 {
+            assert!((array[i] - expected[i]).abs() < 1e-15);
+            assert!((vector[i] - expected[i]).abs() < 1e-15);
+            assert!((wrapped[i] - expected[i]).abs() < 1e-15);
+        }
     }
-    
-    // Calculate number of steps
-    let n_steps = (total_time / dt).ceil() as usize;
-    if n_steps == 0 {
-        return (y0.to_vec(), t0);
+
+    #[test]
+    fn test_step_matches_exponential_taylor_truncation() {
+        // For dy/dt = -y one RK4 step multiplies y by the 4th-order Taylor
+        // polynomial of exp(-dt)
+        let dt = 0.1f64;
+        let mut y = 2.0;
+        Rk4::new(&y).step(&mut y, 0.0, dt, |_t, y: &f64, dydt: &mut f64| *dydt = -y);
+        let taylor = 1.0 - dt + dt.powi(2) / 2.0 - dt.powi(3) / 6.0 + dt.powi(4) / 24.0;
+        assert!((y - 2.0 * taylor).abs() < 1e-15, "{} vs {}", y, 2.0 * taylor);
     }
-    
-    // Adjust dt to exactly reach t1
-    let actual_dt = total_time / n_steps as f64;
-    
-    // Perform integration
-    let mut y = y0.to_vec();
-    let mut t = t0;
-    
-    for _ in 0..n_steps {
-        y = rk4_step(&y, t, actual_dt, f.clone());
-        t += actual_dt;
+
+    #[test]
+    fn test_scalar_integration_lands_on_end_time() {
+        let mut y = 1.0;
+        let t = Rk4::new(&y).integrate(&mut y, 0.0, 1.0, 0.03, |_t, y: &f64, dydt: &mut f64| {
+            *dydt = -y;
+        });
+        assert!((t - 1.0).abs() < 1e-12);
+        assert!((y - (-1.0f64).exp()).abs() < 1e-7);
+
+        // Degenerate steps and intervals leave the state untouched
+        let mut y = 1.0;
+        let mut rk4 = Rk4::new(&y);
+        let growth = |_t: f64, _y: &f64, dydt: &mut f64| *dydt = 1.0;
+        assert_eq!(rk4.integrate(&mut y, 0.0, 1.0, 0.0, growth), 0.0);
+        assert_eq!(rk4.integrate(&mut y, 1.0, 0.0, 0.1, growth), 1.0);
+        assert_eq!(y, 1.0);
     }
-    
-    (y, t)
 }
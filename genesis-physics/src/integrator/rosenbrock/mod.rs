@@ -0,0 +1,542 @@
+//! Adaptive Rosenbrock integrator for stiff systems
+//!
+//! Reaction networks such as nucleosynthesis and recombination are stiff: their
+//! rates span many orders of magnitude, and an explicit method must resolve the
+//! fastest one long after it has equilibrated. A Rosenbrock method is linearly
+//! implicit instead. Each step solves linear systems with the matrix
+//! `I/(γh) − J`, where J = ∂f/∂y, so the step size follows the accuracy of the
+//! solution rather than the stiffness of the rates.
+//!
+//! This is the four-stage, 4th-order method of Shampine (1982), with an embedded
+//! 3rd-order solution that estimates the local error. Steps are accepted, rejected
+//! and resized against the same [`AdaptiveOptions`] tolerances as the
+//! Dormand–Prince integrator. The Jacobian is either supplied analytically or
+//! approximated by finite differences, and it is evaluated once per accepted step
+//! and reused while a step is retried.
+//!
+//! # Example
+//!
+//! ```rust
+//! use genesis_physics::integrator::{rosenbrock_integrate, AdaptiveOptions};
+//!
+//! // y relaxes onto cos t a thousand times faster than cos t changes
+//! let options = AdaptiveOptions {
+//!     relative_tolerance: 1e-6,
+//!     absolute_tolerance: 1e-9,
+//!     ..Default::default()
+//! };
+//! let solution =
+//!     rosenbrock_integrate(&[0.0], 0.0, 10.0, &options, |t, y| vec![-1000.0 * (y[0] - t.cos())])
+//!         .unwrap();
+//!
+//! assert!((solution.y[0] - 10.0f64.cos()).abs() < 1e-3);
+//! let stats = solution.stats;
+//! println!("{} steps, {} Jacobians", stats.accepted_steps, stats.jacobian_evaluations);
+//! ```
+
+use nalgebra::{DMatrix, DVector};
+
+use super::dormand_prince::{
+    initial_step, AdaptiveOptions, IntegrationError, IntegrationStats, Solution,
+};
+
+/// Diagonal coefficient γ of the method
+const GAMMA: f64 = 0.5;
+
+/// Coefficients combining earlier stages into the state at which f is evaluated
+const A21: f64 = 2.0;
+const A31: f64 = 48.0 / 25.0;
+const A32: f64 = 6.0 / 25.0;
+
+/// Coefficients of the earlier stages on the right-hand side of each linear solve
+const C21: f64 = -8.0;
+const C31: f64 = 372.0 / 25.0;
+const C32: f64 = 12.0 / 5.0;
+const C41: f64 = -112.0 / 125.0;
+const C42: f64 = -54.0 / 125.0;
+const C43: f64 = -2.0 / 5.0;
+
+/// Weights of the 4th-order solution
+const B: [f64; 4] = [19.0 / 9.0, 1.0 / 2.0, 25.0 / 108.0, 125.0 / 108.0];
+
+/// Weights of the error estimate, the difference to the 3rd-order solution
+const E: [f64; 4] = [17.0 / 54.0, 7.0 / 36.0, 0.0, 125.0 / 108.0];
+
+/// Coefficients of ∂f/∂t for non-autonomous systems, one per stage
+const DT: [f64; 4] = [1.0 / 2.0, -3.0 / 2.0, 121.0 / 50.0, 29.0 / 250.0];
+
+/// Fractions of the step at which the second and third stages evaluate f
+const C2: f64 = 1.0;
+const C3: f64 = 3.0 / 5.0;
+
+/// Fraction of the optimal step size actually taken
+const SAFETY: f64 = 0.9;
+
+/// Largest factor the step may shrink by after a step
+const MIN_STEP_FACTOR: f64 = 0.2;
+
+/// Largest factor the step may grow by after a step
+const MAX_STEP_FACTOR: f64 = 6.0;
+
+/// Jacobian function type used when the Jacobian is approximated by finite differences
+pub type JacobianFn = fn(f64, &[f64]) -> DMatrix<f64>;
+
+/// Where the Jacobian ∂f/∂y comes from
+enum Jacobian<J> {
+    Analytic(J),
+    FiniteDifference,
+}
+
+/// Step-by-step Rosenbrock integrator
+///
+/// Each call to [`Rosenbrock::step`] advances by one accepted step, retrying
+/// internally after rejections. The last step is shortened to land exactly on
+/// the end time.
+pub struct Rosenbrock<F, J = JacobianFn> {
+    f: F,
+    jacobian: Jacobian<J>,
+    options: AdaptiveOptions,
+    t: f64,
+    t_end: f64,
+    y: Vec<f64>,
+    dydt: Vec<f64>,
+    /// ∂f/∂y and ∂f/∂t at the current state, until the next accepted step
+    derivatives: Option<(DMatrix<f64>, DVector<f64>)>,
+    h: f64,
+    stats: IntegrationStats,
+}
+
+impl<F> Rosenbrock<F>
+where
+    F: FnMut(f64, &[f64]) -> Vec<f64>,
+{
+    /// Prepare to integrate dy/dt = f(t, y) from `y0` at `t0` to `t1`
+    ///
+    /// The Jacobian is approximated by finite differences, at the cost of one
+    /// evaluation of `f` per component. `t1` may lie before `t0` to integrate
+    /// backwards in time.
+    pub fn new(y0: &[f64], t0: f64, t1: f64, options: &AdaptiveOptions, f: F) -> Self {
+        Self::with_source(y0, t0, t1, options, f, Jacobian::FiniteDifference)
+    }
+}
+
+impl<F, J> Rosenbrock<F, J>
+where
+    F: FnMut(f64, &[f64]) -> Vec<f64>,
+    J: FnMut(f64, &[f64]) -> DMatrix<f64>,
+{
+    /// Prepare to integrate dy/dt = f(t, y) with an analytic Jacobian
+    ///
+    /// `jacobian(t, y)` returns the matrix ∂fᵢ/∂yⱼ, row i and column j.
+    pub fn with_jacobian(
+        y0: &[f64],
+        t0: f64,
+        t1: f64,
+        options: &AdaptiveOptions,
+        f: F,
+        jacobian: J,
+    ) -> Self {
+        Self::with_source(y0, t0, t1, options, f, Jacobian::Analytic(jacobian))
+    }
+
+    fn with_source(
+        y0: &[f64],
+        t0: f64,
+        t1: f64,
+        options: &AdaptiveOptions,
+        mut f: F,
+        jacobian: Jacobian<J>,
+    ) -> Self {
+        let dydt = f(t0, y0);
+        let mut stats = IntegrationStats {
+            evaluations: 1,
+            ..Default::default()
+        };
+        let direction = (t1 - t0).signum();
+        let h = direction
+            * options.initial_step.map(f64::abs).unwrap_or_else(|| {
+                stats.evaluations += 1;
+                initial_step(&mut f, t0, t1, y0, &dydt, options, 3)
+            });
+
+        Self {
+            f,
+            jacobian,
+            options: options.clone(),
+            t: t0,
+            t_end: t1,
+            y: y0.to_vec(),
+            dydt,
+            derivatives: None,
+            h,
+            stats,
+        }
+    }
+
+    /// Current time
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    /// Current state
+    pub fn y(&self) -> &[f64] {
+        &self.y
+    }
+
+    /// Work done so far
+    pub fn stats(&self) -> IntegrationStats {
+        self.stats
+    }
+
+    /// Whether the end time has been reached
+    pub fn is_finished(&self) -> bool {
+        self.t == self.t_end
+    }
+
+    /// Take one accepted step
+    ///
+    /// # Returns
+    /// * `Ok(())` once the step is accepted
+    /// * `Err(IntegrationError)` if the tolerances cannot be met or the step limit
+    ///   is reached; the state stays at the last accepted step
+    pub fn step(&mut self) -> Result<(), IntegrationError> {
+        let direction = (self.t_end - self.t).signum();
+        let n = self.y.len();
+        let y0 = DVector::from_column_slice(&self.y);
+        let dydt0 = DVector::from_column_slice(&self.dydt);
+        let mut rejected_last = false;
+
+        if self.derivatives.is_none() {
+            self.derivatives = Some(self.derivatives_at_current_state());
+        }
+
+        loop {
+            if self.stats.accepted_steps + self.stats.rejected_steps >= self.options.max_steps {
+                return Err(IntegrationError::TooManySteps { t: self.t });
+            }
+
+            // Stay within the limits and do not step past the end
+            let mut h = self.h.abs().min(self.options.max_step);
+            let remaining = (self.t_end - self.t).abs();
+            let last = h >= remaining;
+            if last {
+                h = remaining;
+            }
+            let min_step = self.options.min_step.max(16.0 * f64::EPSILON * self.t.abs());
+            if h < min_step && !last {
+                return Err(IntegrationError::StepSizeTooSmall { t: self.t, step: h });
+            }
+            let h = direction * h;
+
+            let stages = self.stages(h, &y0, &dydt0);
+            let (y_new, error) = match stages {
+                Some(g) => {
+                    let y_new = &y0 + B[0] * &g[0] + B[1] * &g[1] + B[2] * &g[2] + B[3] * &g[3];
+                    let estimate = E[0] * &g[0] + E[1] * &g[1] + E[2] * &g[2] + E[3] * &g[3];
+
+                    // RMS of the error relative to the mixed tolerance
+                    let sum: f64 = (0..n)
+                        .map(|i| {
+                            let scale = self.options.absolute_tolerance
+                                + self.options.relative_tolerance * y0[i].abs().max(y_new[i].abs());
+                            (estimate[i] / scale).powi(2)
+                        })
+                        .sum();
+                    let error = if n == 0 { 0.0 } else { (sum / n as f64).sqrt() };
+                    (y_new, error)
+                }
+                // The linear system was singular at this step size
+                None => (y0.clone(), f64::NAN),
+            };
+
+            if error.is_nan() || error > 1.0 {
+                // Rejected, or the stages left the domain of f: shrink and retry
+                self.stats.rejected_steps += 1;
+                let factor = if error.is_finite() {
+                    (SAFETY * error.powf(-0.25)).max(MIN_STEP_FACTOR)
+                } else {
+                    MIN_STEP_FACTOR
+                };
+                self.h = h * factor;
+                rejected_last = true;
+                continue;
+            }
+
+            self.stats.accepted_steps += 1;
+            let mut factor = if error == 0.0 {
+                MAX_STEP_FACTOR
+            } else {
+                (SAFETY * error.powf(-0.25)).clamp(MIN_STEP_FACTOR, MAX_STEP_FACTOR)
+            };
+            if rejected_last {
+                factor = factor.min(1.0);
+            }
+
+            self.t = if last { self.t_end } else { self.t + h };
+            self.y = y_new.as_slice().to_vec();
+            self.dydt = (self.f)(self.t, &self.y);
+            self.stats.evaluations += 1;
+            self.derivatives = None;
+            if !last {
+                self.h = h * factor;
+            }
+            return Ok(());
+        }
+    }
+
+    /// The four stages of a step of size `h`, or `None` if `I/(γh) − J` is singular
+    fn stages(&mut self, h: f64, y0: &DVector<f64>, dydt0: &DVector<f64>) -> Option<[DVector<f64>; 4]> {
+        let (dfdy, dfdt) = self.derivatives.as_ref().expect("derivatives are evaluated before stepping");
+        let n = y0.len();
+        let matrix = DMatrix::identity(n, n) / (GAMMA * h) - dfdy;
+        let lu = matrix.lu();
+        let solve = |rhs: DVector<f64>| lu.solve(&rhs);
+
+        let g1 = solve(dydt0 + h * DT[0] * dfdt)?;
+
+        let y2 = y0 + A21 * &g1;
+        let f2 = DVector::from_vec((self.f)(self.t + C2 * h, y2.as_slice()));
+        let g2 = solve(f2 + h * DT[1] * dfdt + C21 * &g1 / h)?;
+
+        let y3 = y0 + A31 * &g1 + A32 * &g2;
+        let f3 = DVector::from_vec((self.f)(self.t + C3 * h, y3.as_slice()));
+        self.stats.evaluations += 2;
+        let g3 = solve(&f3 + h * DT[2] * dfdt + (C31 * &g1 + C32 * &g2) / h)?;
+
+        // The fourth stage reuses f from the third
+        let g4 = solve(f3 + h * DT[3] * dfdt + (C41 * &g1 + C42 * &g2 + C43 * &g3) / h)?;
+
+        Some([g1, g2, g3, g4])
+    }
+
+    /// ∂f/∂y and ∂f/∂t at the current state
+    ///
+    /// ∂f/∂t is always a forward difference; ∂f/∂y is the analytic Jacobian when
+    /// one was given and forward differences otherwise.
+    fn derivatives_at_current_state(&mut self) -> (DMatrix<f64>, DVector<f64>) {
+        let n = self.y.len();
+        let dfdy = match &mut self.jacobian {
+            Jacobian::Analytic(jacobian) => jacobian(self.t, &self.y),
+            Jacobian::FiniteDifference => {
+                let mut dfdy = DMatrix::zeros(n, n);
+                let mut y = self.y.clone();
+                for j in 0..n {
+                    let delta = difference_step(self.y[j]);
+                    y[j] = self.y[j] + delta;
+                    let shifted = (self.f)(self.t, &y);
+                    for i in 0..n {
+                        dfdy[(i, j)] = (shifted[i] - self.dydt[i]) / delta;
+                    }
+                    y[j] = self.y[j];
+                }
+                self.stats.evaluations += n;
+                dfdy
+            }
+        };
+        self.stats.jacobian_evaluations += 1;
+
+        let delta = difference_step(self.t);
+        let shifted = (self.f)(self.t + delta, &self.y);
+        self.stats.evaluations += 1;
+        let dfdt = DVector::from_iterator(n, (0..n).map(|i| (shifted[i] - self.dydt[i]) / delta));
+
+        (dfdy, dfdt)
+    }
+}
+
+/// Forward-difference increment for a variable of the given value
+///
+/// Balances truncation against round-off, with a floor so that components
+/// passing through zero are still perturbed.
+fn difference_step(value: f64) -> f64 {
+    (f64::EPSILON * value.abs().max(1e-5)).sqrt()
+}
+
+/// Integrate a stiff system dy/dt = f(t, y) from `t0` to `t1`
+///
+/// The Jacobian is approximated by finite differences.
+///
+/// # Arguments
+/// * `y0` - Initial state vector at time t0
+/// * `t0` - Initial time
+/// * `t1` - Final time, before `t0` to integrate backwards
+/// * `options` - Tolerances and step limits; `dense_output` is ignored
+/// * `f` - Derivative function dy/dt = f(t, y)
+///
+/// # Returns
+/// * `Ok(Solution)` with the state at `t1` and the statistics of the run
+/// * `Err(IntegrationError)` if the tolerances could not be met
+pub fn rosenbrock_integrate<F>(
+    y0: &[f64],
+    t0: f64,
+    t1: f64,
+    options: &AdaptiveOptions,
+    f: F,
+) -> Result<Solution, IntegrationError>
+where
+    F: FnMut(f64, &[f64]) -> Vec<f64>,
+{
+    run(Rosenbrock::new(y0, t0, t1, options, f))
+}
+
+/// Integrate a stiff system dy/dt = f(t, y) from `t0` to `t1` with an analytic Jacobian
+///
+/// As [`rosenbrock_integrate`], with `jacobian(t, y)` returning ∂fᵢ/∂yⱼ in row i
+/// and column j.
+pub fn rosenbrock_integrate_with_jacobian<F, J>(
+    y0: &[f64],
+    t0: f64,
+    t1: f64,
+    options: &AdaptiveOptions,
+    f: F,
+    jacobian: J,
+) -> Result<Solution, IntegrationError>
+where
+    F: FnMut(f64, &[f64]) -> Vec<f64>,
+    J: FnMut(f64, &[f64]) -> DMatrix<f64>,
+{
+    run(Rosenbrock::with_jacobian(y0, t0, t1, options, f, jacobian))
+}
+
+fn run<F, J>(mut integrator: Rosenbrock<F, J>) -> Result<Solution, IntegrationError>
+where
+    F: FnMut(f64, &[f64]) -> Vec<f64>,
+    J: FnMut(f64, &[f64]) -> DMatrix<f64>,
+{
+    while !integrator.is_finished() {
+        integrator.step()?;
+    }
+
+    Ok(Solution {
+        t: integrator.t(),
+        y: integrator.y().to_vec(),
+        stats: integrator.stats(),
+        events: Vec::new(),
+        steps: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrator::dopri5_integrate;
+
+    fn tolerances(relative: f64, absolute: f64) -> AdaptiveOptions {
+        AdaptiveOptions {
+            relative_tolerance: relative,
+            absolute_tolerance: absolute,
+            ..Default::default()
+        }
+    }
+
+    /// Robertson's chemical kinetics problem, rates spanning nine orders of magnitude
+    fn robertson(_t: f64, y: &[f64]) -> Vec<f64> {
+        let a = -0.04 * y[0] + 1e4 * y[1] * y[2];
+        let c = 3e7 * y[1] * y[1];
+        vec![a, -a - c, c]
+    }
+
+    fn robertson_jacobian(_t: f64, y: &[f64]) -> DMatrix<f64> {
+        DMatrix::from_row_slice(
+            3,
+            3,
+            &[
+                -0.04,
+                1e4 * y[2],
+                1e4 * y[1],
+                0.04,
+                -1e4 * y[2] - 6e7 * y[1],
+                -1e4 * y[1],
+                0.0,
+                6e7 * y[1],
+                0.0,
+            ],
+        )
+    }
+
+    #[test]
+    fn test_fourth_order_convergence() {
+        // Fixed steps on y' = -y + sin t: halving the step cuts the error 16-fold
+        let f = |t: f64, y: &[f64]| vec![-y[0] + t.sin()];
+        let exact = |t: f64| 1.5 * (-t).exp() + 0.5 * (t.sin() - t.cos());
+        let error = |steps: usize| {
+            let options = AdaptiveOptions {
+                initial_step: Some(2.0 / steps as f64),
+                max_step: 2.0 / steps as f64,
+                ..tolerances(1.0, 1.0)
+            };
+            let solution = rosenbrock_integrate(&[1.0], 0.0, 2.0, &options, f).unwrap();
+            (solution.y[0] - exact(2.0)).abs()
+        };
+
+        let ratio = error(20) / error(40);
+        assert!((12.0..20.0).contains(&ratio), "error ratio {}", ratio);
+    }
+
+    #[test]
+    fn test_robertson_matches_reference() {
+        // Reference values at t = 40 from Hairer & Wanner
+        let options = tolerances(1e-8, 1e-12);
+        let numeric = rosenbrock_integrate(&[1.0, 0.0, 0.0], 0.0, 40.0, &options, robertson).unwrap();
+        let analytic = rosenbrock_integrate_with_jacobian(
+            &[1.0, 0.0, 0.0],
+            0.0,
+            40.0,
+            &options,
+            robertson,
+            robertson_jacobian,
+        )
+        .unwrap();
+
+        let reference = [0.715_827_068_7, 9.185_534_765e-6, 0.284_163_745_7];
+        for solution in [&numeric, &analytic] {
+            for (value, expected) in solution.y.iter().zip(reference) {
+                assert!(((value - expected) / expected).abs() < 1e-5, "{:?}", solution.y);
+            }
+            // Linear invariants are conserved to round-off
+            assert!((solution.y.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            assert!(solution.stats.jacobian_evaluations <= solution.stats.accepted_steps + 1);
+        }
+        assert!(analytic.stats.evaluations < numeric.stats.evaluations);
+    }
+
+    #[test]
+    fn test_robertson_long_run_where_explicit_steps_fail() {
+        let options = AdaptiveOptions {
+            max_steps: 2000,
+            ..tolerances(1e-6, 1e-10)
+        };
+        let stiff = rosenbrock_integrate(&[1.0, 0.0, 0.0], 0.0, 1e6, &options, robertson).unwrap();
+        assert!(stiff.stats.accepted_steps < 500, "{:?}", stiff.stats);
+        assert!(stiff.y.iter().all(|&y| y >= -1e-10), "{:?}", stiff.y);
+        assert!(stiff.y[2] > 0.9);
+
+        let explicit = dopri5_integrate(&[1.0, 0.0, 0.0], 0.0, 1e6, &options, robertson);
+        assert!(matches!(explicit, Err(IntegrationError::TooManySteps { .. })));
+    }
+
+    #[test]
+    fn test_van_der_pol_relaxation_oscillation() {
+        // μ = 1000: slow drift along y = ±2 → ±1, then a jump to ∓2 after
+        // μ (3/2 − ln 2) ≈ 807 time units
+        let mu = 1000.0;
+        let f = move |_t: f64, y: &[f64]| vec![y[1], mu * (1.0 - y[0] * y[0]) * y[1] - y[0]];
+        let options = tolerances(1e-6, 1e-6);
+
+        let before = rosenbrock_integrate(&[2.0, 0.0], 0.0, 700.0, &options, f).unwrap();
+        assert!(before.y[0] > 1.0 && before.y[0] < 2.0, "y(700) = {}", before.y[0]);
+
+        let after = rosenbrock_integrate(&[2.0, 0.0], 0.0, 900.0, &options, f).unwrap();
+        assert!(after.y[0] < -1.5 && after.y[0] > -2.1, "y(900) = {}", after.y[0]);
+        assert!(after.stats.accepted_steps < 2000, "{:?}", after.stats);
+    }
+
+    #[test]
+    fn test_backward_integration() {
+        let options = tolerances(1e-8, 1e-12);
+        let solution =
+            rosenbrock_integrate(&[(-2.0f64).exp()], 2.0, 0.0, &options, |_t, y| vec![-y[0]]).unwrap();
+        assert_eq!(solution.t, 0.0);
+        assert!((solution.y[0] - 1.0).abs() < 1e-6);
+    }
+}
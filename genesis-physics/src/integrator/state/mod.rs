@@ -0,0 +1,69 @@
+//! State types the fixed-step integrators can advance in place
+//!
+//! An integrator only needs two operations on the state: forming `base + s·δ`
+//! into an existing buffer, and adding `s·δ` to a buffer. [`OdeState`] provides
+//! both without allocating. It is implemented for scalars, for fixed-size arrays
+//! (stack-allocated, sized at compile time) and for `Vec`s, whose buffers are
+//! allocated once by the integrator and then reused for every step.
+
+use std::ops::{Add, Mul};
+
+/// A state vector that can be combined in place
+///
+/// `Clone` is only used to create scratch buffers of the same shape when an
+/// integrator is set up, never while stepping.
+pub trait OdeState: Clone {
+    /// Set `self` to `base + scale · increment`, component by component
+    fn set_scaled_sum(&mut self, base: &Self, increment: &Self, scale: f64);
+
+    /// Add `scale · increment` to `self`, component by component
+    fn add_scaled(&mut self, increment: &Self, scale: f64);
+}
+
+impl OdeState for f64 {
+    fn set_scaled_sum(&mut self, base: &Self, increment: &Self, scale: f64) {
+        *self = base + increment * scale;
+    }
+
+    fn add_scaled(&mut self, increment: &Self, scale: f64) {
+        *self += increment * scale;
+    }
+}
+
+impl<T, const N: usize> OdeState for [T; N]
+where
+    T: Copy + Add<Output = T> + Mul<f64, Output = T>,
+{
+    fn set_scaled_sum(&mut self, base: &Self, increment: &Self, scale: f64) {
+        for i in 0..N {
+            self[i] = base[i] + increment[i] * scale;
+        }
+    }
+
+    fn add_scaled(&mut self, increment: &Self, scale: f64) {
+        for i in 0..N {
+            self[i] = self[i] + increment[i] * scale;
+        }
+    }
+}
+
+/// Vectors must keep the length they were created with
+impl<T> OdeState for Vec<T>
+where
+    T: Copy + Add<Output = T> + Mul<f64, Output = T>,
+{
+    fn set_scaled_sum(&mut self, base: &Self, increment: &Self, scale: f64) {
+        debug_assert_eq!(self.len(), base.len());
+        debug_assert_eq!(self.len(), increment.len());
+        for ((value, &b), &d) in self.iter_mut().zip(base).zip(increment) {
+            *value = b + d * scale;
+        }
+    }
+
+    fn add_scaled(&mut self, increment: &Self, scale: f64) {
+        debug_assert_eq!(self.len(), increment.len());
+        for (value, &d) in self.iter_mut().zip(increment) {
+            *value = *value + d * scale;
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Particle kinematics
+//!
+//! Moves the particles in the core [`ParticleStore`] on the fixed physics step.
+//! Each particle drifts along its velocity and the result is scaled by the
+//! [`ScaleFactor`] resource:
+//!
+//! ```text
+//! position = (position + velocity × dt) × a
+//! ```
+//!
+//! `dt` is the fixed step in seconds of real time. Particles are updated in
+//! parallel with [`ParticleStore::par_for_each_mut`] and do not move while the
+//! simulation clock is paused.
+
+use bevy::prelude::*;
+use genesis_core::physics::ParticleStore;
+use genesis_core::time::{SimulationClock, SimulationSet};
+
+use crate::cosmology::ScaleFactor;
+
+/// Advances every particle in the store by one fixed step
+pub fn drift_particles(
+    mut store: ResMut<ParticleStore>,
+    clock: Res<SimulationClock>,
+    time: Res<Time>,
+    scale_factor: Res<ScaleFactor>,
+) {
+    if clock.delta().value() == 0.0 {
+        return;
+    }
+
+    let dt = time.delta_secs_f64();
+    let scale = scale_factor.value;
+    store.par_for_each_mut(|particle| {
+        *particle.position = (*particle.position + *particle.velocity * dt) * scale;
+    });
+}
+
+/// Plugin that registers the particle store and the drift system
+pub struct KinematicsPlugin;
+
+impl Plugin for KinematicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ParticleStore>()
+            .init_resource::<ScaleFactor>()
+            .add_systems(FixedUpdate, drift_particles.in_set(SimulationSet::Physics));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::math::DVec3;
+    use genesis_core::physics::Species;
+    use std::time::Duration;
+
+    fn drift_app(clock: SimulationClock) -> App {
+        let mut store = ParticleStore::new();
+        store.push(DVec3::ZERO, DVec3::new(2.0, 0.0, 0.0), 1.0, Species::Baryon);
+
+        let mut app = App::new();
+        app.insert_resource(store)
+            .insert_resource(clock)
+            .insert_resource(ScaleFactor::default())
+            .insert_resource(Time::<()>::default())
+            .add_systems(Update, drift_particles);
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(500));
+        app
+    }
+
+    #[test]
+    fn test_drift_moves_particles_along_velocity() {
+        let mut clock = SimulationClock::new();
+        clock.play();
+        clock.tick(1.0);
+        let mut app = drift_app(clock);
+        app.update();
+
+        let position = app.world().resource::<ParticleStore>().positions()[0];
+        assert_eq!(position, DVec3::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_paused_clock_freezes_particles() {
+        let mut clock = SimulationClock::new();
+        clock.pause();
+        clock.tick(1.0);
+        let mut app = drift_app(clock);
+        app.update();
+
+        assert_eq!(app.world().resource::<ParticleStore>().positions()[0], DVec3::ZERO);
+    }
+}
@@ -12,6 +12,8 @@
 //! - [`perturbations`] - Density perturbations and quantum fluctuations seeding structure
 //! - [`nucleosynthesis`] - Element formation physics for primordial nucleosynthesis
 //! - [`cosmology`] - Cosmological physics for cosmic expansion and Friedmann equations
+//! - [`kinematics`] - Drifts the particles in the core `ParticleStore` on the fixed physics step
+//! - [`headless`] - Runs the physics to a target cosmic time without a window or renderer
 //!
 //! # Physics Systems
 //!
@@ -26,9 +28,12 @@ pub mod inflaton;
 pub mod perturbations;
 pub mod nucleosynthesis;
 pub mod cosmology;
+pub mod headless;
+pub mod kinematics;
 
 // Re-export commonly used types
 pub use inflaton::InflatonPlugin;
+pub use kinematics::KinematicsPlugin;
 pub use crate::cosmology::{ScaleFactor, CosmicEpoch, Temperature};
 pub use perturbations::GaussianRandomField;
 pub use perturbations::PowerSpectrum;
@@ -47,5 +52,6 @@ impl Plugin for GenesisPhysicsPlugin {
         // Register cosmological physics
         app.add_plugins(cosmology::CosmologyPlugin);
         app.add_plugins(inflaton::InflatonPlugin);
+        app.add_plugins(kinematics::KinematicsPlugin);
     }
 }
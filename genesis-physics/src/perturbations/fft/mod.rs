@@ -160,7 +160,7 @@ impl DensityFft {
     /// - Uses deterministic random number generation for reproducibility
     pub fn apply_power_spectrum(
         &mut self,
-        field: &mut Vec<Complex<f64>>,
+        field: &mut [Complex<f64>],
         power_spectrum: &PowerSpectrum,
         seed: u64,
     ) -> Result<(), String> {
@@ -0,0 +1,66 @@
+//! Integration tests for allocation-free RK4 stepping
+//!
+//! A counting global allocator records every allocation made by this test
+//! binary, so the steps can be checked to leave it untouched.
+
+use genesis_physics::integrator::Rk4;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// Both cases run in one test so no other test thread allocates while counting
+#[test]
+fn test_rk4_steps_do_not_allocate() {
+    // Many particles in one heap-allocated state: [x, v] pairs of oscillators
+    let particles = 100_000;
+    let mut y = vec![0.0f64; 2 * particles];
+    for i in 0..particles {
+        y[2 * i] = 1.0;
+    }
+    let mut rk4 = Rk4::new(&y);
+    let oscillators = |_t: f64, state: &Vec<f64>, dydt: &mut Vec<f64>| {
+        for (pair, slope) in state.chunks_exact(2).zip(dydt.chunks_exact_mut(2)) {
+            slope[0] = pair[1];
+            slope[1] = -pair[0];
+        }
+    };
+
+    // A stack-allocated fixed-size state
+    let mut a = [1.0f64, 0.0];
+    let mut rk4_fixed = Rk4::new(&a);
+    let decay = |_t: f64, state: &[f64; 2], dydt: &mut [f64; 2]| {
+        dydt[0] = -state[0];
+        dydt[1] = state[0];
+    };
+
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let t = rk4.integrate(&mut y, 0.0, 1.0, 0.01, oscillators);
+    for step in 0..100 {
+        rk4_fixed.step(&mut a, step as f64 * 0.01, 0.01, decay);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::SeqCst) - before;
+
+    assert_eq!(allocations, 0);
+    assert!((t - 1.0).abs() < 1e-12);
+    assert!((y[0] - 1.0f64.cos()).abs() < 1e-9);
+    assert!((y[2 * particles - 1] + 1.0f64.sin()).abs() < 1e-9);
+    assert!((a[0] - (-1.0f64).exp()).abs() < 1e-9);
+    assert!((a[0] + a[1] - 1.0).abs() < 1e-12);
+}
@@ -46,6 +46,9 @@ use std::f32::consts::PI;
 
 use crate::input::InputState;
 use genesis_core::config::CameraConfig;
+use genesis_core::epoch::{CameraPresetMode, EpochManager};
+use genesis_core::events::EpochEntered;
+use genesis_core::session::CameraPose;
 
 /// Camera mode enumeration
 ///
@@ -148,6 +151,7 @@ impl CameraState {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn start_interpolation(
         &mut self,
         start_pos: Vec3,
@@ -291,6 +295,54 @@ impl OrbitController {
     }
 }
 
+/// Captures the camera pose for a saved session
+///
+/// Any in-progress mode interpolation is not captured; the pose is the camera's
+/// current transform and the mode it is in.
+pub fn camera_pose(
+    state: &CameraState,
+    transform: &Transform,
+    orbit: &OrbitController,
+    free: &CameraController,
+) -> CameraPose {
+    CameraPose {
+        orbit: state.mode == CameraMode::Orbit,
+        translation: transform.translation.to_array(),
+        rotation: transform.rotation.to_array(),
+        orbit_target: state.current_orbit_target.to_array(),
+        orbit_distance: orbit.distance,
+        orbit_yaw: orbit.yaw,
+        orbit_pitch: orbit.pitch,
+        free_yaw: free.yaw,
+        free_pitch: free.pitch,
+    }
+}
+
+/// Restores a saved camera pose, cancelling any in-progress interpolation
+pub fn apply_camera_pose(
+    pose: &CameraPose,
+    state: &mut CameraState,
+    transform: &mut Transform,
+    orbit: &mut OrbitController,
+    free: &mut CameraController,
+) {
+    state.mode = if pose.orbit {
+        CameraMode::Orbit
+    } else {
+        CameraMode::FreeFlight
+    };
+    state.current_orbit_target = Vec3::from_array(pose.orbit_target);
+    state.interpolating = false;
+
+    transform.translation = Vec3::from_array(pose.translation);
+    transform.rotation = Quat::from_array(pose.rotation);
+    orbit.distance = pose.orbit_distance;
+    orbit.yaw = pose.orbit_yaw;
+    orbit.pitch = pose.orbit_pitch;
+    free.yaw = pose.free_yaw;
+    free.pitch = pose.free_pitch;
+}
+
 /// System to update free-flight camera based on input
 ///
 /// Updates camera orientation from mouse movement and applies WASD movement
@@ -575,6 +627,53 @@ fn toggle_camera_mode(
     }
 }
 
+/// Moves the camera to the preset of an epoch when it becomes active
+///
+/// Looks up the [`CameraPreset`](genesis_core::epoch::CameraPreset) of the epoch
+/// named by the latest [`EpochEntered`] event and interpolates to it like a mode
+/// switch: the camera keeps its bearing from the preset's target, moves to the
+/// preset's distance and turns to face the target. Epochs without a preset leave the
+/// camera where it is.
+pub fn apply_epoch_camera_preset(
+    mut entered: EventReader<EpochEntered>,
+    manager: Option<Res<EpochManager>>,
+    mut camera_state: ResMut<CameraState>,
+    camera_query: Query<&Transform, With<Camera3d>>,
+) {
+    let Some(name) = entered.read().last().map(|event| event.name) else {
+        return;
+    };
+    let Some(preset) = manager.and_then(|manager| manager.get(name)?.camera_preset) else {
+        return;
+    };
+    let Ok(camera_transform) = camera_query.get_single() else {
+        return;
+    };
+
+    let offset = camera_transform.translation - preset.target;
+    let bearing = offset.try_normalize().unwrap_or(Vec3::Z);
+    let end_pos = preset.target + bearing * preset.distance;
+    let end_rot = Transform::from_translation(end_pos)
+        .looking_at(preset.target, Vec3::Y)
+        .rotation;
+    let end_mode = match preset.mode {
+        CameraPresetMode::Orbit => CameraMode::Orbit,
+        CameraPresetMode::FreeFlight => CameraMode::FreeFlight,
+    };
+
+    camera_state.current_orbit_target = preset.target;
+    let start_mode = camera_state.mode;
+    camera_state.start_interpolation(
+        camera_transform.translation,
+        camera_transform.rotation,
+        end_pos,
+        end_rot,
+        start_mode,
+        end_mode,
+        1.0,
+    );
+}
+
 /// Smoothly interpolates camera position and rotation during mode transitions
 fn interpolate_camera(
     time: Res<Time>,
@@ -627,7 +726,9 @@ pub struct CameraPlugin;
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CameraState>()
+            .add_event::<EpochEntered>()
             .add_systems(Update, toggle_camera_mode)
+            .add_systems(Update, apply_epoch_camera_preset.before(interpolate_camera))
             .add_systems(Update, interpolate_camera)
             .add_systems(Update, update_free_flight_camera)
             .add_systems(Update, update_orbit_camera)
@@ -641,6 +742,7 @@ impl Plugin for CameraPlugin {
 // ============================================================================
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
     use crate::input::InputState;
@@ -720,7 +822,7 @@ mod tests {
         use bevy::prelude::*;
         let system = IntoSystem::into_system(handle_free_flight_zoom);
         let system_id = world.register_system(system);
-        world.run_system(system_id);
+        world.run_system(system_id).unwrap();
 
         // Verify camera moved forward along its forward vector
         let transform = world.get::<Transform>(entity).unwrap();
@@ -778,7 +880,7 @@ mod tests {
         use bevy::prelude::*;
         let system = IntoSystem::into_system(handle_free_flight_zoom);
         let system_id = world.register_system(system);
-        world.run_system(system_id);
+        world.run_system(system_id).unwrap();
 
         // Verify camera distance is clamped to minimum 1.0
         let transform = world.get::<Transform>(entity).unwrap();
@@ -834,7 +936,7 @@ mod tests {
         use bevy::prelude::*;
         let system = IntoSystem::into_system(handle_free_flight_zoom);
         let system_id = world.register_system(system);
-        world.run_system(system_id);
+        world.run_system(system_id).unwrap();
 
         // Verify camera distance is clamped to maximum 200.0
         let transform = world.get::<Transform>(entity).unwrap();
@@ -890,7 +992,7 @@ mod tests {
         use bevy::prelude::*;
         let system = IntoSystem::into_system(handle_free_flight_zoom);
         let system_id = world.register_system(system);
-        world.run_system(system_id);
+        world.run_system(system_id).unwrap();
 
         // Verify camera moved using the custom zoom_speed
         let transform = world.get::<Transform>(entity).unwrap();
@@ -934,7 +1036,7 @@ mod tests {
         use bevy::prelude::*;
         let system = IntoSystem::into_system(handle_free_flight_zoom);
         let system_id = world.register_system(system);
-        world.run_system(system_id);
+        world.run_system(system_id).unwrap();
 
         // Verify camera moved backward
         let transform = world.get::<Transform>(entity).unwrap();
@@ -984,7 +1086,7 @@ mod tests {
         use bevy::prelude::*;
         let system = IntoSystem::into_system(handle_free_flight_zoom);
         let system_id = world.register_system(system);
-        world.run_system(system_id);
+        world.run_system(system_id).unwrap();
 
         // Verify camera moved along its rotated forward vector
         let transform = world.get::<Transform>(entity).unwrap();
@@ -1047,7 +1149,7 @@ mod tests {
         use bevy::prelude::*;
         let system = IntoSystem::into_system(handle_free_flight_zoom);
         let system_id = world.register_system(system);
-        world.run_system(system_id);
+        world.run_system(system_id).unwrap();
 
         // Verify camera moved forward
         let transform = world.get::<Transform>(entity).unwrap();
@@ -1076,9 +1178,74 @@ mod tests {
         // Distance from origin should be within valid range
         let distance = transform.translation.length();
         assert!(
-            distance >= 1.0 && distance <= 200.0,
+            (1.0..=200.0).contains(&distance),
             "Camera distance {} should be within valid range [1.0, 200.0]",
             distance
         );
     }
+
+    /// Test: Verify a saved camera pose restores mode, transform and controller angles
+    #[test]
+    fn test_camera_pose_round_trip() {
+        let mut state = CameraState::default();
+        state.mode = CameraMode::Orbit;
+        state.current_orbit_target = Vec3::new(1.0, 0.0, -1.0);
+        let transform = Transform::from_xyz(0.0, 10.0, 40.0).looking_at(Vec3::ZERO, Vec3::Y);
+        let orbit = OrbitController { distance: 41.0, yaw: 0.7, pitch: 0.2, ..default() };
+        let free = CameraController { yaw: -0.3, pitch: 0.1, ..default() };
+        let pose = camera_pose(&state, &transform, &orbit, &free);
+
+        let mut restored_state = CameraState::default();
+        restored_state.interpolating = true;
+        let mut restored_transform = Transform::default();
+        let mut restored_orbit = OrbitController::default();
+        let mut restored_free = CameraController::default();
+        apply_camera_pose(
+            &pose,
+            &mut restored_state,
+            &mut restored_transform,
+            &mut restored_orbit,
+            &mut restored_free,
+        );
+
+        assert_eq!(restored_state.mode, CameraMode::Orbit);
+        assert!(!restored_state.interpolating);
+        assert_eq!(restored_state.current_orbit_target, state.current_orbit_target);
+        assert_eq!(restored_transform, transform);
+        assert_eq!(restored_orbit.distance, 41.0);
+        assert_eq!(restored_free.yaw, -0.3);
+        assert_eq!(
+            camera_pose(&restored_state, &restored_transform, &restored_orbit, &restored_free),
+            pose
+        );
+    }
+
+    /// Test: Verify entering an epoch moves the camera towards the epoch's preset
+    #[test]
+    fn test_epoch_entered_starts_preset_interpolation() {
+        use genesis_core::epoch::{EpochInfo, SingularityEpoch};
+
+        let mut world = World::new();
+        let mut manager = EpochManager::new();
+        manager.register(EpochInfo::from_plugin(&SingularityEpoch)).unwrap();
+        world.insert_resource(manager);
+        world.insert_resource(CameraState::default());
+        world.init_resource::<Events<EpochEntered>>();
+        world.spawn((Camera3d::default(), Transform::from_xyz(0.0, 0.0, 50.0)));
+        world.send_event(EpochEntered {
+            name: "Singularity",
+            years: 0.0,
+        });
+
+        let system_id = world.register_system(apply_epoch_camera_preset);
+        world.run_system(system_id).unwrap();
+
+        // The Singularity orbits the origin from 100 units away
+        let state = world.resource::<CameraState>();
+        assert!(state.interpolating);
+        assert_eq!(state.interpolation_end_mode, CameraMode::Orbit);
+        assert!((state.interpolation_end_pos - Vec3::new(0.0, 0.0, 100.0)).length() < 1e-4);
+        let facing = state.interpolation_end_rot * Vec3::NEG_Z;
+        assert!((facing - Vec3::NEG_Z).length() < 1e-4, "camera should face the origin");
+    }
 }
@@ -37,7 +37,8 @@
 //! # Configuration Note
 //!
 //! genesis.toml fields `initial_count`, `max_count`, `base_size` match [`ParticleConfig`]
-//! struct fields. The [`spawn_particles()`] system uses `config.initial_count` and
+//! struct fields. The [`spawn_particles()`] system spawns one entity per particle in the
+//! core `ParticleStore` (created from `config.initial_count`) and uses
 //! `config.base_size` directly from the config resource.
 
 use bevy::asset::Asset;
@@ -51,7 +52,11 @@ use bevy::render::Render;
 use bevy::render::RenderApp;
 use bevy::render::RenderSet;
 use genesis_core::config::ParticleConfig;
-use genesis_core::{events::ScrubbingEvent, time::TimeAccumulator};
+use genesis_core::physics::ParticleStore;
+use genesis_core::{
+    events::ScrubbingEvent,
+    time::{interpolation_alpha, SimulationClock},
+};
 use genesis_physics::cosmology::ScaleFactor;
 
 mod instance_buffer;
@@ -103,6 +108,17 @@ pub struct PointSpriteMaterial {
     pub attenuation_factor: f32,
 }
 
+impl PointSpriteMaterial {
+    /// Creates the white material shared by all particles
+    pub fn new(base_size: f32) -> Self {
+        Self {
+            color: LinearRgba::new(1.0, 1.0, 1.0, 1.0),
+            base_size,
+            attenuation_factor: 0.01, // Size attenuation factor
+        }
+    }
+}
+
 impl Material for PointSpriteMaterial {
     fn vertex_shader() -> ShaderRef {
         "point_sprite.wgsl".into()
@@ -139,25 +155,26 @@ pub struct PointMesh(pub Handle<Mesh>);
 
 /// Component representing a particle in the simulation
 ///
-/// Contains position, velocity, color, and size attributes for rendering.
+/// Holds the presentation attributes (color and size) of one particle and the
+/// index of its simulation state in the core [`ParticleStore`]. The entity's
+/// Transform is copied from the store by [`update_particles`].
 /// This component can be used with instanced rendering for
 /// GPU-accelerated particle systems.
 #[derive(Component, Clone)]
 pub struct Particle {
-    /// World space position of the particle
-    pub position: Vec3,
-    /// Velocity of the particle
-    pub velocity: Vec3,
-    /// Initial spawn position at t=0 (used for timeline scrubbing)
-    pub initial_position: Vec3,
-    /// Initial velocity at spawn time (used for timeline scrubbing)
-    pub initial_velocity: Vec3,
+    /// Index of the particle in the [`ParticleStore`]
+    pub index: usize,
     /// RGBA color of the particle
     pub color: Color,
     /// Particle size in world units
     pub size: f32,
 }
 
+/// Size of the particle at `index`: deterministic pseudo-random in [0.5, 2.0]
+pub fn particle_size(index: usize) -> f32 {
+    0.5 + ((index as f32 * 567.891).fract()) * 1.5
+}
+
 /// Startup system to initialize the shared point mesh resource
 ///
 /// Creates a simple point mesh with a single vertex at the origin.
@@ -331,14 +348,14 @@ fn lerp_rgb(r1: f32, g1: f32, b1: f32, r2: f32, g2: f32, b2: f32, t: f32) -> Col
 
 /// System to spawn a cluster of particles around the origin
 ///
-/// Creates particle entities in a dense cluster representing the
-/// early universe. Uses GPU instancing (automatic batching) where
+/// Creates one particle entity for each particle in the [`ParticleStore`]
+/// (a dense cluster representing the early universe). Uses GPU instancing (automatic batching) where
 /// all entities share the same mesh and material handles for
 /// efficient rendering.
 ///
-/// Spawns particles based on the configuration specified in
-/// ParticleConfig (via the Resource derive). GPU instancing allows efficient rendering
-/// of 100K-1M particles.
+/// The store is created from ParticleConfig (`ParticleStore::from_config`) before
+/// startup; the material uses `config.base_size`. GPU instancing allows efficient
+/// rendering of 100K-1M particles.
 ///
 /// In Bevy 0.15, GPU instancing is automatic: when multiple entities
 /// share the same Mesh3d and MeshMaterial3d handles, the renderer
@@ -355,88 +372,76 @@ pub fn spawn_particles(
     mut materials: ResMut<Assets<PointSpriteMaterial>>,
     point_mesh: Res<PointMesh>,
     config: Res<ParticleConfig>,
+    store: Res<ParticleStore>,
 ) {
-    let particle_count = config.initial_count as u32;
+    spawn_store_particles(&mut commands, &mut materials, &point_mesh, config.base_size, &store);
+}
 
+/// Spawns one particle entity for every particle in the store
+///
+/// Particles start white-hot (maximum energy) with a pseudo-random size. Existing
+/// particle entities are left in place; despawn them first when replacing the
+/// current particles.
+pub fn spawn_store_particles(
+    commands: &mut Commands,
+    materials: &mut Assets<PointSpriteMaterial>,
+    point_mesh: &PointMesh,
+    base_size: f32,
+    store: &ParticleStore,
+) {
     // Create point sprite material for all particles (single material shared by all)
     // Using white color for visibility - individual particle colors will be
     // handled by the shader in future updates
-    let particle_material = PointSpriteMaterial {
-        color: LinearRgba::new(1.0, 1.0, 1.0, 1.0),
-        base_size: config.base_size,
-        attenuation_factor: 0.01,      // Size attenuation factor
-    };
-    let material_handle = materials.add(particle_material);
-
-    // Spawn particles at the exact origin with radial velocity
-    const BASE_SPEED: f32 = 0.5;
-
-    for i in 0..particle_count {
-        // Simple deterministic pseudo-random distribution using loop index
-        // This provides variation without requiring the rand crate
-        let fi = i as f32;
-
-        // Spawn ALL particles at exact origin
-        let position = Vec3::ZERO;
-
-        // Generate outward radial velocity vector using pseudo-random distribution
-        // Use the same pattern to create a direction vector on a unit sphere
-        let dir_x = ((fi * 123.456).fract() - 0.5) * 2.0;
-        let dir_y = ((fi * 789.012).fract() - 0.5) * 2.0;
-        let dir_z = ((fi * 345.678).fract() - 0.5) * 2.0;
-        let direction = Vec3::new(dir_x, dir_y, dir_z).normalize();
-
-        // Scale direction by base speed to get velocity
-        let velocity = direction * BASE_SPEED;
-        let _velocity_magnitude = velocity.length();
-
-        // Set initial color to white-hot (maximum energy = 1.0)
-        // All particles start at the origin with maximum energy
-        let color = energy_to_color(1.0);
-
-        // Random particle size in range [0.5, 2.0]
-        let size = 0.5 + ((fi * 567.891).fract()) * 1.5;
-
-        // Spawn particle entity with shared mesh/material handles
-        // Bevy 0.15 automatically batches entities with same mesh/material for GPU instancing
-        commands.spawn((
-            Mesh3d(point_mesh.0.clone()), // Shared point mesh from resource
-            MeshMaterial3d(material_handle.clone()), // Shared point sprite material
-            Transform::from_translation(position), // Per-instance transform (all at origin)
+    let material_handle = materials.add(PointSpriteMaterial::new(base_size));
+
+    for (index, position) in store.positions().iter().enumerate() {
+        spawn_particle(
+            commands,
+            point_mesh,
+            &material_handle,
+            position.as_vec3(),
             Particle {
-                position,
-                velocity,
-                initial_position: position, // Store spawn position at t=0
-                initial_velocity: velocity, // Store initial velocity at spawn time
-                color,
-                size,
+                index,
+                color: energy_to_color(1.0),
+                size: particle_size(index),
             },
-        ));
+        );
     }
 }
 
-/// System to update particle positions based on physics
+/// Spawns one particle entity with the shared mesh and material handles
 ///
-/// Updates particle positions using velocity-based movement and syncs
-/// Particle.position with Transform.translation for proper rendering.
-/// This ensures Particle.position stays current for energy-based coloring.
+/// Bevy 0.15 automatically batches entities with the same mesh/material for GPU instancing.
+fn spawn_particle(
+    commands: &mut Commands,
+    point_mesh: &PointMesh,
+    material: &Handle<PointSpriteMaterial>,
+    position: Vec3,
+    particle: Particle,
+) {
+    commands.spawn((
+        Mesh3d(point_mesh.0.clone()),
+        MeshMaterial3d(material.clone()),
+        Transform::from_translation(position),
+        particle,
+    ));
+}
+
+/// System to copy particle positions from the simulation
+///
+/// Sets each particle entity's Transform.translation to the position of its
+/// particle in the [`ParticleStore`]. Physics (see `genesis_physics::kinematics`)
+/// owns the positions; rendering only reads them.
 pub fn update_particles(
-    mut query: Query<(&mut Particle, &mut Transform)>,
-    time: Res<Time>,
-    scale_factor: Res<ScaleFactor>,
+    mut query: Query<(&Particle, &mut Transform)>,
+    store: Res<ParticleStore>,
 ) {
-    let delta = time.delta_secs();
-
-    for (mut particle, mut transform) in query.iter_mut() {
-        // Store velocity to avoid borrow conflicts
-        let velocity = particle.velocity;
-        
-        // Update position based on velocity and scale factor:
-        // position = (position + velocity * delta) * scale_factor.value
-        particle.position = (particle.position + velocity * delta) * scale_factor.value as f32;
-        
-        // Sync Particle.position to Transform.translation for rendering
-        transform.translation = particle.position;
+    let positions = store.positions();
+
+    for (particle, mut transform) in query.iter_mut() {
+        if let Some(position) = positions.get(particle.index) {
+            transform.translation = position.as_vec3();
+        }
     }
 }
 
@@ -466,18 +471,6 @@ pub fn update_particle_energy_colors(mut query: Query<(&Transform, &mut Particle
     }
 }
 
-/// Synchronize Transform.translation to Particle.position each frame.
-///
-/// This ensures the energy-based coloring system receives correct position data
-/// by syncing any Transform changes back to the Particle component. This is
-/// important when transforms are modified by other systems (e.g., user interaction,
-/// camera manipulation) to keep Particle.position in sync.
-pub fn sync_particle_position(mut query: Query<(&Transform, &mut Particle)>) {
-    for (transform, mut particle) in query.iter_mut() {
-        particle.position = transform.translation;
-    }
-}
-
 /// System to update ScrubbingState based on ScrubbingEvent events
 ///
 /// This system listens for ScrubbingEvent events emitted by the timeline UI
@@ -496,26 +489,25 @@ pub fn update_scrubbing_state(
 /// System to update particle positions based on timeline scrubbing
 ///
 /// When scrubbing is active (ScrubbingState.is_scrubbing == true), this system
-/// recalculates particle positions from their initial state:
-///     position = initial_position + initial_velocity * years
+/// recalculates the positions in the [`ParticleStore`] from their initial state:
+///     position = (initial_position + initial_velocity * years) * scale_factor
 ///
 /// This allows particles to move backward and forward based on the elapsed cosmic
-/// time (TimeAccumulator.years) during timeline scrubbing.
+/// time during timeline scrubbing. The clock advances on the fixed physics step, so
+/// the time is interpolated between the last two steps
+/// (SimulationClock::interpolated_years).
 ///
-/// When scrubbing is not active, the normal update_particles system handles
-/// physics-based forward integration.
+/// When scrubbing is not active, the physics drift system handles forward
+/// integration.
 ///
 /// # Usage
 ///
-/// This system runs alongside update_particles, but only affects particle
-/// positions when scrubbing is active. The system ordering ensures that:
-/// - During scrubbing: update_particles_for_scrubbing sets positions, update_particles
-///   is effectively skipped (or can be conditionally disabled)
-/// - During normal playback: update_particles handles physics, update_particles_for_scrubbing
-///   does nothing
+/// This system runs before update_particles, which copies the rewound positions
+/// to the particle Transforms.
 pub fn update_particles_for_scrubbing(
-    mut query: Query<(&mut Particle, &mut Transform)>,
-    time_accumulator: Res<TimeAccumulator>,
+    mut store: ResMut<ParticleStore>,
+    clock: Res<SimulationClock>,
+    fixed_time: Res<Time<Fixed>>,
     scrubbing_state: Res<ScrubbingState>,
     scale_factor: Res<ScaleFactor>,
 ) {
@@ -524,16 +516,8 @@ pub fn update_particles_for_scrubbing(
         return;
     }
 
-    let years = time_accumulator.years as f32;
-
-    for (mut particle, mut transform) in query.iter_mut() {
-        // Calculate position from initial state and apply scale factor:
-        // position = (initial_position + initial_velocity * years) * scale_factor
-        particle.position = (particle.initial_position + particle.initial_velocity * years) * scale_factor.value as f32;
-
-        // Sync Particle.position to Transform.translation for rendering
-        transform.translation = particle.position;
-    }
+    let years = clock.interpolated_years(interpolation_alpha(&fixed_time));
+    store.rewind_to(years, scale_factor.value);
 }
 
 /// Plugin for registering particle systems with the Bevy app
@@ -565,9 +549,8 @@ impl Plugin for ParticlePlugin {
             // Update systems
             .add_systems(Update, update_scrubbing_state)
             .add_systems(Update, update_particles_for_scrubbing.after(update_scrubbing_state))
-            .add_systems(Update, update_particles)
-            .add_systems(Update, update_particle_energy_colors)
-            .add_systems(Update, sync_particle_position.before(update_particle_energy_colors));
+            .add_systems(Update, update_particles.after(update_particles_for_scrubbing))
+            .add_systems(Update, update_particle_energy_colors.after(update_particles));
 
         // Initialize ScrubbingState resource
         app.init_resource::<ScrubbingState>();
@@ -660,7 +643,7 @@ impl Plugin for ParticlePlugin {
 // Color data flows through the rendering pipeline as follows:
 //
 // 1. **Particle Component**: Each particle entity has a `Particle` component with:
-//    - `position: Vec3`: World-space position
+//    - `index: usize`: Index of its state in the core `ParticleStore`
 //    - `color: Color`: RGBA color for rendering
 //    - `size: f32`: Particle size in world units
 //
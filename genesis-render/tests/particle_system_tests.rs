@@ -62,10 +62,7 @@ fn test_particle_component_exists() {
 
     // Verify we can create a Particle component
     let _particle = Particle {
-        position: Vec3::ZERO,
-        velocity: Vec3::ZERO,
-        initial_position: Vec3::ZERO,
-        initial_velocity: Vec3::ZERO,
+        index: 0,
         color: Color::WHITE,
         size: 1.0,
     };
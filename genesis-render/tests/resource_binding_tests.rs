@@ -31,6 +31,7 @@ use bevy::render::mesh::PrimitiveTopology;
 use bevy::render::render_resource::{BindGroupLayout, ShaderRef, ShaderStages};
 use bevy::render::ExtractSchedule;
 use genesis_core::config::ParticleConfig;
+use genesis_core::physics::ParticleStore;
 use genesis_render::particle::{
     extract_particle_instances, PointMesh, PointSpriteMaterial, Particle,
     ExtractedParticleInstances, ParticleInstanceBindGroupLayout,
@@ -153,7 +154,7 @@ fn test_binding_1_view_uniform_vertex_visibility() {
     let shader_path = get_shader_path();
 
     let content = fs::read_to_string(&shader_path)
-        .expect(&format!("Failed to read shader file: {}", shader_path));
+        .unwrap_or_else(|_| panic!("Failed to read shader file: {}", shader_path));
 
     // Verify binding 1 exists
     assert!(
@@ -201,7 +202,7 @@ fn test_binding_2_model_matrix_uniform() {
     let shader_path = get_shader_path();
 
     let content = fs::read_to_string(&shader_path)
-        .expect(&format!("Failed to read shader file: {}", shader_path));
+        .unwrap_or_else(|_| panic!("Failed to read shader file: {}", shader_path));
 
     // Verify binding 2 exists
     assert!(
@@ -229,7 +230,7 @@ fn test_all_bindings_present_in_order() {
     let shader_path = get_shader_path();
 
     let content = fs::read_to_string(&shader_path)
-        .expect(&format!("Failed to read shader file: {}", shader_path));
+        .unwrap_or_else(|_| panic!("Failed to read shader file: {}", shader_path));
 
     // Find all binding declarations
     let binding_0_pos = content.find("@group(0) @binding(0)")
@@ -262,7 +263,7 @@ fn test_binding_visibility_flags() {
     let shader_path = get_shader_path();
 
     let content = fs::read_to_string(&shader_path)
-        .expect(&format!("Failed to read shader file: {}", shader_path));
+        .unwrap_or_else(|_| panic!("Failed to read shader file: {}", shader_path));
 
     // Extract vertex shader function
     let vertex_start = content.find("@vertex")
@@ -344,7 +345,7 @@ fn test_material_trait_pipeline_layout() {
                 "Both vertex and fragment shaders should use the same file"
             );
         }
-        _ => assert!(false, "Both shaders should use ShaderRef::Path"),
+        _ => panic!("Both shaders should use ShaderRef::Path"),
     }
 
     // Verify alpha mode is set
@@ -366,11 +367,13 @@ fn test_point_mesh_initialized_before_particles_spawn() {
     let mut app = App::new();
 
     // Add particle config resource
-    app.insert_resource(ParticleConfig {
+    let config = ParticleConfig {
         initial_count: 100,
         max_count: 1000,
         base_size: 5.0,
-    });
+    };
+    app.insert_resource(ParticleStore::from_config(&config));
+    app.insert_resource(config);
 
     // Verify that init_point_mesh system can be added to Startup schedule
     // This ensures the system signature is correct
@@ -511,11 +514,13 @@ fn test_system_ordering_point_mesh_before_spawn() {
     app.init_asset::<genesis_render::particle::PointSpriteMaterial>();
 
     // Add particle config
-    app.insert_resource(ParticleConfig {
+    let config = ParticleConfig {
         initial_count: 10,
         max_count: 100,
         base_size: 5.0,
-    });
+    };
+    app.insert_resource(ParticleStore::from_config(&config));
+    app.insert_resource(config);
 
     // Insert a dummy PointMesh resource to simulate what init_point_mesh does
     // In a real application, init_point_mesh would create this with a valid mesh handle from Assets<Mesh>
@@ -576,11 +581,13 @@ fn test_resources_created_at_startup() {
     app.init_asset::<genesis_render::particle::PointSpriteMaterial>();
 
     // Add particle config
-    app.insert_resource(ParticleConfig {
+    let config = ParticleConfig {
         initial_count: 5,
         max_count: 50,
         base_size: 3.0,
-    });
+    };
+    app.insert_resource(ParticleStore::from_config(&config));
+    app.insert_resource(config);
 
     // Insert a dummy PointMesh resource to simulate what init_point_mesh does
     let dummy_mesh_handle = Handle::<Mesh>::default();
@@ -633,11 +640,13 @@ fn test_resources_accessible_during_update() {
     app.init_asset::<genesis_render::particle::PointSpriteMaterial>();
 
     // Add particle config
-    app.insert_resource(ParticleConfig {
+    let config = ParticleConfig {
         initial_count: 5,
         max_count: 50,
         base_size: 3.0,
-    });
+    };
+    app.insert_resource(ParticleStore::from_config(&config));
+    app.insert_resource(config);
 
     // Insert a dummy PointMesh resource
     let dummy_mesh_handle = Handle::<Mesh>::default();
@@ -722,11 +731,13 @@ fn test_pipeline_cache_no_index_out_of_bounds() {
     app.world_mut().run_schedule(bevy::app::Startup);
 
     // Add particle config
-    app.insert_resource(ParticleConfig {
+    let config = ParticleConfig {
         initial_count: 10,
         max_count: 100,
         base_size: 5.0,
-    });
+    };
+    app.insert_resource(ParticleStore::from_config(&config));
+    app.insert_resource(config);
 
     // Initialize PointMesh
     app.add_systems(
@@ -771,7 +782,7 @@ fn test_mesh_position_attribute() {
     let shader_path = get_shader_path();
 
     let content = fs::read_to_string(&shader_path)
-        .expect(&format!("Failed to read shader file: {}", shader_path));
+        .unwrap_or_else(|_| panic!("Failed to read shader file: {}", shader_path));
 
     // Verify VertexInput struct has position at location(0)
     assert!(
@@ -782,12 +793,6 @@ fn test_mesh_position_attribute() {
     );
 }
 
-/// Test 17: Test that storage buffer binding layout is correctly configured
-///
-/// This test validates the storage buffer approach (Approach 1: Storage Buffer with Instance Index).
-/// The current implementation uses a storage buffer at binding 3 containing particle instance data,
-/// with the vertex shader using @builtin(instance_index) to access it.
-
 /// Test 17: Test that storage buffer binding layout is correctly configured
 ///
 /// This test validates the storage buffer approach (Approach 1: Storage Buffer with Instance Index).
@@ -799,7 +804,7 @@ fn test_storage_buffer_binding_layout() {
     let shader_path = get_shader_path();
 
     let content = fs::read_to_string(&shader_path)
-        .expect(&format!("Failed to read shader file: {}", shader_path));
+        .unwrap_or_else(|_| panic!("Failed to read shader file: {}", shader_path));
 
     // Verify binding 3 exists and is a storage buffer
     assert!(
@@ -833,7 +838,7 @@ fn test_vertex_shader_uses_storage_buffer() {
     let shader_path = get_shader_path();
 
     let content = fs::read_to_string(&shader_path)
-        .expect(&format!("Failed to read shader file: {}", shader_path));
+        .unwrap_or_else(|_| panic!("Failed to read shader file: {}", shader_path));
 
     // Extract vertex shader function
     let vertex_start = content.find("@vertex")
@@ -876,16 +881,13 @@ fn test_vertex_shader_uses_storage_buffer() {
 #[test]
 fn test_particle_component_structure() {
     let particle = Particle {
-        initial_position: Vec3::ZERO,
-        initial_velocity: Vec3::ZERO,
-        position: Vec3::new(1.0, 2.0, 3.0),
-        velocity: Vec3::new(0.0, 0.0, 0.0),
+        index: 3,
         color: bevy::color::Color::from(bevy::color::palettes::css::RED),
         size: 5.0,
     };
 
     // Verify all fields are accessible
-    assert_eq!(particle.position, Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(particle.index, 3);
     assert_eq!(particle.size, 5.0);
 
     // Verify color is valid - check that color components are finite and alpha is valid
@@ -1177,17 +1179,19 @@ fn test_complete_particle_rendering_setup() {
     app.add_plugins(bevy::app::ScheduleRunnerPlugin::run_once())
         .add_plugins(bevy::render::RenderPlugin::default())
         .add_plugins(bevy::asset::AssetPlugin::default())
-        .add_plugins(bevy::time::TimePlugin::default());
+        .add_plugins(bevy::time::TimePlugin);
 
     // Run startup schedule to initialize resources like AssetServer
     app.world_mut().run_schedule(bevy::app::Startup);
 
     // Add particle config
-    app.insert_resource(ParticleConfig {
+    let config = ParticleConfig {
         initial_count: 20,
         max_count: 200,
         base_size: 4.0,
-    });
+    };
+    app.insert_resource(ParticleStore::from_config(&config));
+    app.insert_resource(config);
 
     // Add startup systems
     app.add_systems(
@@ -1259,11 +1263,13 @@ fn test_extract_system_transfers_data() {
     app.world_mut().run_schedule(bevy::app::Startup);
 
     // Add particle config
-    app.insert_resource(ParticleConfig {
+    let config = ParticleConfig {
         initial_count: 5,
         max_count: 50,
         base_size: 3.0,
-    });
+    };
+    app.insert_resource(ParticleStore::from_config(&config));
+    app.insert_resource(config);
 
     // Add startup systems
     app.add_systems(
@@ -1302,7 +1308,7 @@ fn test_comprehensive_binding_validation() {
     let shader_path = get_shader_path();
 
     let content = fs::read_to_string(&shader_path)
-        .expect(&format!("Failed to read shader file: {}", shader_path));
+        .unwrap_or_else(|_| panic!("Failed to read shader file: {}", shader_path));
 
     // CRITICAL BINDINGS - All must exist for rendering to work
 
@@ -5,8 +5,10 @@
 //!
 //! # Modules
 //!
-//! - [`overlay`] - Overlay state tracking and UI rendering (FPS counter, particle count)
-//! - [`timeline`] - Timeline playback state, cosmic time management, and timeline UI controls
+//! - [`overlay`] - Overlay state tracking and UI rendering (FPS counter, particle count,
+//!   redshift and distances)
+//! - [`panels`] - Registry and rendering of the panels contributed by the active epoch
+//! - [`timeline`] - Timeline slider scale and timeline UI controls for the simulation clock
 //!
 //! # Public Exports
 //!
@@ -15,16 +17,19 @@
 //!
 //! # UI Systems
 //!
-//! - `update_overlay_ui` (Update): Renders FPS and particle count overlay
-//! - `timeline_panel_ui` (PostUpdate): Renders timeline with play/pause, slider, speed control
-//! - `sync_time_resources` (Update): Synchronizes TimeAccumulator with PlaybackState
+//! - `update_overlay_ui` (Update): Renders FPS, particle count and distance overlay
+//! - `epoch_panels_ui` (Update): Renders the panels declared by the active epoch
+//! - `timeline_panel_ui` (Update): Renders timeline with play/pause, slider, speed control
+//!   and sends them as `SimulationCommand` events to the `SimulationClock` in genesis-core
 
 use bevy::prelude::*;
 use bevy_egui::EguiPlugin;
 
 pub mod overlay;
+pub mod panels;
 pub mod timeline;
 
+use panels::EpochPanelsPlugin;
 use timeline::TimelinePlugin;
 
 /// Version of the UI library
@@ -35,6 +40,7 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 /// This plugin registers the following:
 /// - `EguiPlugin`: Provides egui integration for UI rendering
 /// - `TimelinePlugin`: Timeline UI with play/pause, logarithmic slider, speed control
+/// - `EpochPanelsPlugin`: Panels of the active epoch
 /// - `update_overlay_ui` system: FPS counter and particle count display
 pub struct GenesisUiPlugin;
 
@@ -42,6 +48,7 @@ impl Plugin for GenesisUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(EguiPlugin)
             .add_plugins(TimelinePlugin)
+            .add_plugins(EpochPanelsPlugin)
             .add_systems(Update, overlay::update_overlay_ui.after(bevy_egui::EguiSet::InitContexts));
     }
 }
@@ -5,6 +5,7 @@
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
+use genesis_physics::cosmology::Cosmology;
 
 /// Resource tracking overlay visibility
 ///
@@ -16,6 +17,8 @@ use bevy_egui::{egui, EguiContexts};
 ///
 /// - `show_fps`: When true, displays the current frame rate counter
 /// - `show_particle_count`: When true, displays the total number of active particles
+/// - `show_distances`: When true, displays the redshift with the comoving horizon and
+///   Hubble radius
 ///
 /// # Note
 ///
@@ -27,6 +30,8 @@ pub struct OverlayState {
     pub show_fps: bool,
     /// Display particle count
     pub show_particle_count: bool,
+    /// Display redshift, horizon and Hubble radius
+    pub show_distances: bool,
 }
 
 impl Default for OverlayState {
@@ -34,6 +39,7 @@ impl Default for OverlayState {
         Self {
             show_fps: true,
             show_particle_count: true,
+            show_distances: true,
         }
     }
 }
@@ -41,17 +47,20 @@ impl Default for OverlayState {
 /// System to update and display the overlay UI
 ///
 /// Renders a semi-transparent overlay in the top-left corner of the screen
-/// showing FPS counter and particle count based on visibility flags.
+/// showing FPS counter, particle count and cosmological distances based on
+/// visibility flags. Distances are only shown once the expansion follows the
+/// background after inflation.
 pub fn update_overlay_ui(
     mut contexts: EguiContexts,
     overlay_state: Res<OverlayState>,
     diagnostics: Res<bevy::diagnostic::DiagnosticsStore>,
-    particles: Query<&genesis_render::particle::Particle>,
+    particles: Res<genesis_core::physics::ParticleStore>,
+    cosmology: Option<Res<Cosmology>>,
 ) {
     let ctx = contexts.ctx_mut();
 
     // Don't show overlay if all visibility flags are false
-    if !overlay_state.show_fps && !overlay_state.show_particle_count {
+    if !overlay_state.show_fps && !overlay_state.show_particle_count && !overlay_state.show_distances {
         return;
     }
 
@@ -74,8 +83,18 @@ pub fn update_overlay_ui(
 
             // Display particle count if enabled
             if overlay_state.show_particle_count {
-                let particle_count = particles.iter().count();
-                ui.label(format!("Particles: {}", particle_count));
+                ui.label(format!("Particles: {}", particles.len()));
+            }
+
+            // Display redshift and distances if enabled
+            if let Some(cosmology) = cosmology.filter(|_| overlay_state.show_distances) {
+                let z = cosmology.redshift();
+                if cosmology.background.has_big_bang() && z.is_finite() && z > -1.0 {
+                    let distances = cosmology.distances();
+                    ui.label(format!("Redshift: z = {:.3e}", z));
+                    ui.label(format!("Horizon: {:.3e} Mpc", distances.horizon_radius(z).value()));
+                    ui.label(format!("Hubble radius: {:.3e} Mpc", distances.hubble_radius(z).value()));
+                }
             }
         });
 }
@@ -0,0 +1,106 @@
+//! Panels contributed by epochs
+//!
+//! Epochs declare their panels through
+//! [`EpochPlugin::ui_panels`](genesis_core::epoch::EpochPlugin::ui_panels) by
+//! identifier only. The [`EpochPanelRegistry`] maps each identifier to the function
+//! that draws the panel, and [`epoch_panels_ui`] draws the panels of the active epoch
+//! in their own windows, so a panel appears and disappears with its epoch.
+
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiSet};
+use genesis_core::epoch::EpochManager;
+use genesis_physics::cosmology::SLOW_ROLL_PANEL;
+use genesis_physics::inflaton::Inflaton;
+
+/// Draws the contents of a panel from the state of the world
+pub type PanelRenderer = fn(&mut egui::Ui, &World);
+
+/// Resource mapping panel identifiers to the functions that draw them
+#[derive(Resource, Default)]
+pub struct EpochPanelRegistry {
+    renderers: HashMap<&'static str, PanelRenderer>,
+}
+
+impl EpochPanelRegistry {
+    /// Registers the renderer of a panel, replacing any previous one
+    pub fn register(&mut self, id: &'static str, renderer: PanelRenderer) {
+        self.renderers.insert(id, renderer);
+    }
+
+    /// Renderer registered for a panel identifier, if any
+    pub fn get(&self, id: &str) -> Option<PanelRenderer> {
+        self.renderers.get(id).copied()
+    }
+}
+
+/// System that draws the panels of the active epoch
+///
+/// Panels without a registered renderer are skipped.
+pub fn epoch_panels_ui(world: &mut World, contexts: &mut SystemState<EguiContexts>) {
+    let ctx = contexts.get_mut(world).ctx_mut().clone();
+    let world: &World = world;
+    let (Some(manager), Some(registry)) = (
+        world.get_resource::<EpochManager>(),
+        world.get_resource::<EpochPanelRegistry>(),
+    ) else {
+        return;
+    };
+    let Some(epoch) = manager.active() else {
+        return;
+    };
+
+    for panel in &epoch.ui_panels {
+        if let Some(renderer) = registry.get(panel.id) {
+            egui::Window::new(panel.title)
+                .resizable(false)
+                .show(&ctx, |ui| renderer(ui, world));
+        }
+    }
+}
+
+/// Draws the inflaton field and its slow-roll parameters
+pub fn slow_roll_panel(ui: &mut egui::Ui, world: &World) {
+    match world.get_resource::<Inflaton>() {
+        Some(inflaton) => {
+            ui.label(format!("φ = {:.3}", inflaton.phi));
+            ui.label(format!("ε = {:.3e}", inflaton.epsilon));
+            ui.label(format!("η = {:.3e}", inflaton.eta));
+        }
+        None => {
+            ui.label("No inflaton");
+        }
+    }
+}
+
+/// Plugin that owns the [`EpochPanelRegistry`] and draws the active epoch's panels
+///
+/// Registers the renderer of the [`SLOW_ROLL_PANEL`] of the inflation epoch.
+pub struct EpochPanelsPlugin;
+
+impl Plugin for EpochPanelsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EpochPanelRegistry>()
+            .add_systems(Update, epoch_panels_ui.after(EguiSet::InitContexts));
+        app.world_mut()
+            .resource_mut::<EpochPanelRegistry>()
+            .register(SLOW_ROLL_PANEL.id, slow_roll_panel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_registers_slow_roll_panel() {
+        let mut app = App::new();
+        app.add_plugins(EpochPanelsPlugin);
+
+        let registry = app.world().resource::<EpochPanelRegistry>();
+        assert!(registry.get(SLOW_ROLL_PANEL.id).is_some());
+        assert!(registry.get("unknown").is_none());
+    }
+}
@@ -1,71 +1,39 @@
-//! Timeline playback state and UI controls
+//! Timeline playback controls
 //!
-//! Defines resources for tracking playback state, cosmic time, and speed.
 //! Timeline UI system (timeline_panel_ui) is implemented with egui, featuring play/pause,
 //! logarithmic slider, and speed control.
 //!
-//! # Dual Time System
+//! # Simulation Clock
 //!
-//! The timeline uses [`CosmicTime`] to track the current cosmic time in years.
-//! This resource stores the timeline position used by the slider UI (0.0 to 13.8 billion years).
-//!
-//! The core simulation uses [`TimeAccumulator::years`] (genesis_core::time) which
-//! tracks accumulated cosmic time based on frame delta and acceleration.
-//!
-//! The timeline slider updates [`CosmicTime::cosmic_time`] directly and synchronizes it
-//! to [`TimeAccumulator::years`] when the timeline is scrubbed. This ensures that
-//! scrubbing the timeline affects both the UI display and the actual simulation time accumulator.
-//!
-//! The [`sync_time_resources()`] system synchronizes:
-//! - TimeAccumulator's paused state with PlaybackState.playing
-//! - PlaybackState.speed to TimeAccumulator.acceleration (direct pass-through)
+//! Cosmic time, pause state and playback speed are owned by
+//! [`SimulationClock`](genesis_core::time::SimulationClock) in genesis-core. The
+//! timeline only reads the clock and sends [`SimulationCommand`] events (play, pause,
+//! reset, set speed, seek); genesis-core applies them at the start of the next frame. The
+//! [`TimelineScale`] resource holds the range covered by the logarithmic slider.
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts, EguiSet};
-use genesis_core::events::ScrubbingEvent;
-use genesis_core::time::{TimeAccumulator, MIN_YEARS};
+use genesis_core::events::{ScrubbingEvent, SimulationCommand};
+use genesis_core::time::{SimulationClock, MAX_SPEED, MIN_SPEED, MIN_YEARS};
 
-/// Resource tracking playback state
+/// Resource describing the range of the timeline slider
 ///
-/// Stores playing state and speed factor for time control.
-/// Timeline UI is implemented via timeline_panel_ui system with egui.
-#[derive(Resource)]
-pub struct PlaybackState {
-    pub playing: bool,
-    pub speed: f32, // 1.0 to 1e12 for direct pass-through playback speed control (no logarithmic scaling)
-}
-
-impl Default for PlaybackState {
-    fn default() -> Self {
-        Self {
-            playing: false,
-            speed: 1.0, // Default to 1x real time
-        }
-    }
-}
-
-/// Resource for cosmic timeline state management
-///
-/// Tracks the current cosmic time in years from 0 (Big Bang) to 13.8 billion years.
+/// Spans cosmic time from 0 (Big Bang) to 13.8 billion years.
 /// Provides methods for logarithmic slider mapping for timeline scrubbing UI.
 #[derive(Resource)]
-pub struct CosmicTime {
-    /// Current cosmic time in years (0.0 to 13.8 billion)
-    pub cosmic_time: f64,
+pub struct TimelineScale {
     /// Minimum time (0.0 = Big Bang)
     pub min_time: f64,
     /// Maximum time (13.8 billion years = 13.8e9)
     pub max_time: f64,
 }
 
-impl CosmicTime {
-    /// Creates a new CosmicTime resource with default values.
+impl TimelineScale {
+    /// Creates a new TimelineScale resource with default values.
     ///
-    /// Initializes with cosmic_time at 0.0 (Big Bang), min_time at 0.0,
-    /// and max_time at 13.8 billion years.
+    /// Initializes with min_time at 0.0 (Big Bang) and max_time at 13.8 billion years.
     pub fn new() -> Self {
         Self {
-            cosmic_time: 0.0,
             min_time: 0.0,
             max_time: 13.8e9,
         }
@@ -124,32 +92,9 @@ impl CosmicTime {
         let log_ratio: f64 = f64::log10(self.max_time / effective_min);
         f64::log10(cosmic_time / effective_min) / log_ratio
     }
-
-    /// Sets the current cosmic time.
-    ///
-    /// Clamps the value between min_time and max_time.
-    ///
-    /// # Arguments
-    /// * `time` - New cosmic time in years
-    pub fn set_time(&mut self, time: f64) {
-        self.cosmic_time = time.clamp(self.min_time, self.max_time);
-    }
-
-    /// Gets the current cosmic time.
-    ///
-    /// # Returns
-    /// Current cosmic time in years
-    pub fn get_time(&self) -> f64 {
-        self.cosmic_time
-    }
-
-    /// Resets cosmic time to the Big Bang (0.0 years).
-    pub fn reset(&mut self) {
-        self.cosmic_time = self.min_time;
-    }
 }
 
-impl Default for CosmicTime {
+impl Default for TimelineScale {
     fn default() -> Self {
         Self::new()
     }
@@ -162,13 +107,19 @@ impl Default for CosmicTime {
 /// - Timeline slider with logarithmic scale
 /// - Speed control slider
 /// - Time display in billions of years
+///
+/// Every control is sent as a [`SimulationCommand`]; the [`SimulationClock`] itself
+/// is only changed by genesis-core.
 pub fn timeline_panel_ui(
     mut contexts: EguiContexts,
-    mut cosmic_time: ResMut<CosmicTime>,
-    mut playback_state: ResMut<PlaybackState>,
-    mut time_accumulator: ResMut<TimeAccumulator>,
+    scale: Res<TimelineScale>,
+    clock: Res<SimulationClock>,
+    mut commands: EventWriter<SimulationCommand>,
     mut scrubbing_events: EventWriter<ScrubbingEvent>,
 ) {
+    // Show a pending seek immediately so the slider does not jump back for a frame
+    let displayed_time = clock.seek_target().unwrap_or(clock.years());
+
     egui::Window::new("Timeline")
         .resizable(false)
         .collapsible(false)
@@ -176,39 +127,36 @@ pub fn timeline_panel_ui(
         .show(contexts.ctx_mut(), |ui| {
             ui.horizontal(|ui| {
                 // Play/Pause button
-                if playback_state.playing && ui.button("⏸ Pause").clicked() {
-                    playback_state.playing = false;
-                } else if !playback_state.playing && ui.button("▶ Play").clicked() {
-                    playback_state.playing = true;
+                if !clock.is_paused() && ui.button("⏸ Pause").clicked() {
+                    commands.send(SimulationCommand::Pause);
+                } else if clock.is_paused() && ui.button("▶ Play").clicked() {
+                    commands.send(SimulationCommand::Play);
                 }
 
                 // Reset button
                 if ui.button("⏮ Reset").clicked() {
-                    cosmic_time.reset();
-                    time_accumulator.reset();
-                    playback_state.playing = false;
+                    commands.send(SimulationCommand::Reset);
                 }
 
                 ui.separator();
 
                 // Timeline slider
                 ui.label("Time:");
-                let current_slider_value = cosmic_time.to_slider(cosmic_time.cosmic_time);
-                let mut slider_value = current_slider_value;
+                let mut slider_value = scale.to_slider(displayed_time);
                 let slider_response = ui.add(egui::Slider::new(&mut slider_value, 0.0..=1.0).show_value(false));
-                
+
                 // Emit ScrubbingEvent when user starts dragging
                 if slider_response.drag_started() {
                     scrubbing_events.send(ScrubbingEvent { is_scrubbing: true });
                 }
-                
-                // Update cosmic time when slider changes
+
+                // Request a seek when the slider changes
                 if slider_response.changed() {
-                    cosmic_time.cosmic_time = cosmic_time.from_slider(slider_value);
-                    // Synchronize TimeAccumulator.years with CosmicTime.cosmic_time
-                    time_accumulator.years = cosmic_time.cosmic_time;
+                    commands.send(SimulationCommand::SeekTo(
+                        scale.from_slider(slider_value).min(scale.max_time),
+                    ));
                 }
-                
+
                 // Emit ScrubbingEvent when user stops dragging
                 if slider_response.drag_stopped() {
                     scrubbing_events.send(ScrubbingEvent { is_scrubbing: false });
@@ -218,55 +166,35 @@ pub fn timeline_panel_ui(
 
                 // Speed control slider
                 ui.label("Speed:");
-                ui.add(
-                    egui::Slider::new(&mut playback_state.speed, 1.0..=1e12)
+                let mut speed = clock.speed();
+                let speed_response = ui.add(
+                    egui::Slider::new(&mut speed, MIN_SPEED..=MAX_SPEED)
                         .logarithmic(true)
                         .prefix("")
                         .suffix("x"),
                 );
+                if speed_response.changed() {
+                    commands.send(SimulationCommand::SetSpeed(speed));
+                }
 
                 ui.separator();
 
                 // Time display in billions of years
-                let time_in_billions = cosmic_time.cosmic_time / 1e9;
+                let time_in_billions = displayed_time / 1e9;
                 ui.label(format!("Time: {:.2} billion years", time_in_billions));
             });
         });
 }
 
-/// System that synchronizes TimeAccumulator with PlaybackState.
-///
-/// Ensures that TimeAccumulator's paused state is consistent with PlaybackState.playing:
-/// - When playing is true and TimeAccumulator is paused, resume TimeAccumulator
-/// - When playing is false and TimeAccumulator is not paused, pause TimeAccumulator
-///
-/// Also maps PlaybackState.speed (1.0-1e12) to TimeAccumulator.acceleration (1.0-1e12)
-/// via direct pass-through
-pub fn sync_time_resources(
-    mut time_accumulator: ResMut<TimeAccumulator>,
-    playback_state: Res<PlaybackState>,
-) {
-    if playback_state.playing && time_accumulator.is_paused() {
-        time_accumulator.resume();
-    } else if !playback_state.playing && !time_accumulator.is_paused() {
-        time_accumulator.pause();
-    }
-
-    // Map PlaybackState.speed (1.0-1e12) to TimeAccumulator.acceleration (1.0-1e12)
-    let speed = playback_state.speed as f64;
-    let acceleration = speed as f64;
-    time_accumulator.set_acceleration(acceleration);
-}
-
 /// Plugin that sets up the timeline UI system and resources.
 pub struct TimelinePlugin;
 
 impl Plugin for TimelinePlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(CosmicTime::new())
-            .insert_resource(PlaybackState::default())
-            .add_systems(bevy::app::Update, timeline_panel_ui.after(EguiSet::InitContexts))
-            .add_systems(bevy::app::Update, sync_time_resources);
+        app.init_resource::<TimelineScale>()
+            .init_resource::<SimulationClock>()
+            .add_event::<SimulationCommand>()
+            .add_systems(bevy::app::Update, timeline_panel_ui.after(EguiSet::InitContexts));
     }
 }
 
@@ -281,14 +209,14 @@ mod tests {
     /// Test from_slider produces correct time ranges
     #[test]
     fn test_from_slider_produces_correct_ranges() {
-        let cosmic_time = CosmicTime::new();
+        let scale = TimelineScale::new();
 
         // from_slider(0.0) should return MIN_YEARS
-        let min_time = cosmic_time.from_slider(0.0);
+        let min_time = scale.from_slider(0.0);
         assert_eq!(min_time, 1e-40, "from_slider(0.0) should return MIN_YEARS");
 
         // from_slider(1.0) should return max_time (with tolerance)
-        let max_time = cosmic_time.from_slider(1.0);
+        let max_time = scale.from_slider(1.0);
         let relative_error = (max_time - 13.8e9).abs() / 13.8e9;
         assert!(
             relative_error < 1e-10,
@@ -296,11 +224,11 @@ mod tests {
         );
 
         // from_slider should be monotonically increasing
-        let s0 = cosmic_time.from_slider(0.0);
-        let s25 = cosmic_time.from_slider(0.25);
-        let s5 = cosmic_time.from_slider(0.5);
-        let s75 = cosmic_time.from_slider(0.75);
-        let s1 = cosmic_time.from_slider(1.0);
+        let s0 = scale.from_slider(0.0);
+        let s25 = scale.from_slider(0.25);
+        let s5 = scale.from_slider(0.5);
+        let s75 = scale.from_slider(0.75);
+        let s1 = scale.from_slider(1.0);
 
         assert!(s0 < s25, "from_slider should be monotonically increasing");
         assert!(s25 < s5, "from_slider should be monotonically increasing");
@@ -311,10 +239,10 @@ mod tests {
     /// Test to_slider produces correct slider value ranges
     #[test]
     fn test_to_slider_produces_correct_ranges() {
-        let cosmic_time = CosmicTime::new();
+        let scale = TimelineScale::new();
 
         // to_slider at MIN_YEARS should return -1.0 (start of pre-1-year range)
-        let slider_at_min = cosmic_time.to_slider(1e-40);
+        let slider_at_min = scale.to_slider(1e-40);
         assert!(
             (slider_at_min - (-1.0)).abs() < EPSILON,
             "to_slider(MIN_YEARS) should return approximately -1.0, got={}",
@@ -322,7 +250,7 @@ mod tests {
         );
 
         // to_slider at just below 1 year should be negative
-        let slider_below_1 = cosmic_time.to_slider(0.999);
+        let slider_below_1 = scale.to_slider(0.999);
         assert!(
             slider_below_1 < 0.0,
             "to_slider should return negative for time < 1 year, got={}",
@@ -330,7 +258,7 @@ mod tests {
         );
 
         // to_slider at 1 year should be 0.0
-        let slider_at_1 = cosmic_time.to_slider(1.0);
+        let slider_at_1 = scale.to_slider(1.0);
         assert!(
             slider_at_1.abs() < EPSILON,
             "to_slider(1.0) should return approximately 0.0, got={}",
@@ -338,7 +266,7 @@ mod tests {
         );
 
         // to_slider at max_time should return 1.0
-        let slider_at_max = cosmic_time.to_slider(13.8e9);
+        let slider_at_max = scale.to_slider(13.8e9);
         assert!(
             (slider_at_max - 1.0).abs() < EPSILON,
             "to_slider(max_time) should return approximately 1.0, got={}",
@@ -349,7 +277,7 @@ mod tests {
     /// Test from_slider() at t=10^-30s (~3.17e-38 years)
     #[test]
     fn test_from_slider_at_10_minus_30_seconds() {
-        let cosmic_time = CosmicTime::new();
+        let scale = TimelineScale::new();
 
         // t = 10^-30s ≈ 3.17e-38 years
         let target_time_years = 1e-30 / SECONDS_PER_YEAR; // ~3.17e-38 years
@@ -360,7 +288,7 @@ mod tests {
         let log_ratio = f64::log10(13.8e9 / 1e-40);
         let expected_slider = f64::log10(target_time_years / 1e-40) / log_ratio;
         
-        let actual_time = cosmic_time.from_slider(expected_slider);
+        let actual_time = scale.from_slider(expected_slider);
         
         assert!(
             (actual_time - target_time_years).abs() < EPSILON * target_time_years.abs(),
@@ -383,7 +311,7 @@ mod tests {
     /// Test from_slider() at t=10^-6s (~3.17e-14 years)
     #[test]
     fn test_from_slider_at_10_minus_6_seconds() {
-        let cosmic_time = CosmicTime::new();
+        let scale = TimelineScale::new();
 
         // t = 10^-6s ≈ 3.17e-14 years
         let target_time_years = 1e-6 / SECONDS_PER_YEAR; // ~3.17e-14 years
@@ -392,7 +320,7 @@ mod tests {
         let log_ratio = f64::log10(13.8e9 / 1e-40);
         let expected_slider = f64::log10(target_time_years / 1e-40) / log_ratio;
         
-        let actual_time = cosmic_time.from_slider(expected_slider);
+        let actual_time = scale.from_slider(expected_slider);
         
         assert!(
             (actual_time - target_time_years).abs() < EPSILON * target_time_years.abs(),
@@ -415,7 +343,7 @@ mod tests {
     /// Test to_slider() returns negative values for pre-1-year times
     #[test]
     fn test_to_slider_negative_for_pre_1year() {
-        let cosmic_time = CosmicTime::new();
+        let scale = TimelineScale::new();
 
         // Test various pre-1-year timescales
         let pre_1year_times = [
@@ -430,7 +358,7 @@ mod tests {
         ];
 
         for &time in &pre_1year_times {
-            let slider_value = cosmic_time.to_slider(time);
+            let slider_value = scale.to_slider(time);
             assert!(
                 slider_value < 0.0,
                 "to_slider() should return negative value for pre-1-year time {}: got={}",
@@ -449,8 +377,8 @@ mod tests {
     /// Test to_slider() returns 0.0 for exactly 1 year
     #[test]
     fn test_to_slider_zero_at_exactly_1_year() {
-        let cosmic_time = CosmicTime::new();
-        let slider_value = cosmic_time.to_slider(1.0);
+        let scale = TimelineScale::new();
+        let slider_value = scale.to_slider(1.0);
         
         assert!(
             slider_value.abs() < EPSILON,
@@ -462,7 +390,7 @@ mod tests {
     /// Test to_slider() returns positive values for post-1-year times
     #[test]
     fn test_to_slider_positive_for_post_1year() {
-        let cosmic_time = CosmicTime::new();
+        let scale = TimelineScale::new();
 
         // Test various post-1-year timescales
         let post_1year_times = [
@@ -476,7 +404,7 @@ mod tests {
         ];
 
         for &time in &post_1year_times {
-            let slider_value = cosmic_time.to_slider(time);
+            let slider_value = scale.to_slider(time);
             assert!(
                 slider_value >= 0.0,
                 "to_slider() should return non-negative value for post-1-year time {}: got={}",
@@ -495,13 +423,13 @@ mod tests {
     /// Test from_slider at key time points across the full range
     #[test]
     fn test_from_slider_at_key_time_points() {
-        let cosmic_time = CosmicTime::new();
+        let scale = TimelineScale::new();
 
         // Test that from_slider covers the full range from MIN_YEARS to max_time
-        let min_time = cosmic_time.from_slider(0.0);
+        let min_time = scale.from_slider(0.0);
         assert_eq!(min_time, 1e-40, "from_slider(0.0) should return MIN_YEARS");
 
-        let max_time = cosmic_time.from_slider(1.0);
+        let max_time = scale.from_slider(1.0);
         let relative_error = (max_time - 13.8e9).abs() / 13.8e9;
         assert!(
             relative_error < 1e-10,
@@ -509,7 +437,7 @@ mod tests {
         );
 
         // Test that from_slider at mid-point gives geometric mean
-        let mid_time = cosmic_time.from_slider(0.5);
+        let mid_time = scale.from_slider(0.5);
         // Geometric mean: sqrt(MIN_YEARS * max_time)
         let expected_mid = f64::sqrt(1e-40 * 13.8e9);
         let relative_error_mid = (mid_time - expected_mid).abs() / expected_mid;
@@ -524,22 +452,22 @@ mod tests {
     /// Test to_slider at key time points across the full range
     #[test]
     fn test_to_slider_at_key_time_points() {
-        let cosmic_time = CosmicTime::new();
+        let scale = TimelineScale::new();
 
         // Test boundary points
-        let slider_min = cosmic_time.to_slider(1e-40);
+        let slider_min = scale.to_slider(1e-40);
         assert!(
             (slider_min - (-1.0)).abs() < EPSILON,
             "to_slider(MIN_YEARS) should return -1.0"
         );
 
-        let slider_1yr = cosmic_time.to_slider(1.0);
+        let slider_1yr = scale.to_slider(1.0);
         assert!(
             slider_1yr.abs() < EPSILON,
             "to_slider(1.0) should return 0.0"
         );
 
-        let slider_max = cosmic_time.to_slider(13.8e9);
+        let slider_max = scale.to_slider(13.8e9);
         assert!(
             (slider_max - 1.0).abs() < EPSILON,
             "to_slider(max_time) should return 1.0"
@@ -547,7 +475,7 @@ mod tests {
 
         // Test that to_slider at mid-point (geometric mean of 1 and max_time) gives 0.5
         let mid_time = f64::sqrt(1.0 * 13.8e9);
-        let slider_mid = cosmic_time.to_slider(mid_time);
+        let slider_mid = scale.to_slider(mid_time);
         assert!(
             (slider_mid - 0.5).abs() < 1e-6,
             "to_slider at geometric mean should return 0.5, got={}",
@@ -558,15 +486,15 @@ mod tests {
     /// Test edge case: min_time = 0.0
     #[test]
     fn test_edge_case_min_time_zero() {
-        let cosmic_time = CosmicTime::new();
-        assert_eq!(cosmic_time.min_time, 0.0);
+        let scale = TimelineScale::new();
+        assert_eq!(scale.min_time, 0.0);
 
         // from_slider at 0.0 should give MIN_YEARS
-        let min_slider = cosmic_time.from_slider(0.0);
+        let min_slider = scale.from_slider(0.0);
         assert_eq!(min_slider, 1e-40, "from_slider(0.0) should return MIN_YEARS");
 
         // from_slider at 1.0 should give max_time (allowing for floating-point precision)
-        let max_slider = cosmic_time.from_slider(1.0);
+        let max_slider = scale.from_slider(1.0);
         let relative_error = (max_slider - 13.8e9).abs() / 13.8e9;
         assert!(
             relative_error < 1e-10,
@@ -579,15 +507,15 @@ mod tests {
     /// Test edge case: min_time > 0
     #[test]
     fn test_edge_case_min_time_positive() {
-        let mut cosmic_time = CosmicTime::new();
-        cosmic_time.min_time = 0.1; // 0.1 years
+        let mut scale = TimelineScale::new();
+        scale.min_time = 0.1; // 0.1 years
 
         // from_slider at 0.0 should give min_time
-        let min_slider = cosmic_time.from_slider(0.0);
+        let min_slider = scale.from_slider(0.0);
         assert_eq!(min_slider, 0.1, "from_slider(0.0) should return min_time");
 
         // to_slider at min_time should return 0.0
-        let slider_value = cosmic_time.to_slider(0.1);
+        let slider_value = scale.to_slider(0.1);
         assert!(
             slider_value.abs() < EPSILON,
             "to_slider(min_time) should return approximately 0.0, got={}",
@@ -598,16 +526,16 @@ mod tests {
     /// Test edge case: max_time variations
     #[test]
     fn test_edge_case_max_time_variations() {
-        let mut cosmic_time = CosmicTime::new();
+        let mut scale = TimelineScale::new();
 
         // Test with different max_time values (must be > 1.0 to avoid division by zero)
         let max_times = [100.0, 1_000.0, 1e9, 13.8e9];
 
         for &max_time in &max_times {
-            cosmic_time.max_time = max_time;
+            scale.max_time = max_time;
 
             // from_slider at 1.0 should give max_time (allowing for floating-point precision)
-            let result = cosmic_time.from_slider(1.0);
+            let result = scale.from_slider(1.0);
             let relative_error = (result - max_time).abs() / max_time;
             assert!(
                 relative_error < 1e-10,
@@ -618,7 +546,7 @@ mod tests {
             );
 
             // to_slider at max_time should return 1.0
-            let slider_value = cosmic_time.to_slider(max_time);
+            let slider_value = scale.to_slider(max_time);
             assert!(
                 (slider_value - 1.0).abs() < EPSILON,
                 "to_slider(max_time) should return approximately 1.0 for max_time={}, got={}",
@@ -631,7 +559,7 @@ mod tests {
     /// Test continuity around 1 year boundary
     #[test]
     fn test_continuity_around_1_year() {
-        let cosmic_time = CosmicTime::new();
+        let scale = TimelineScale::new();
 
         // Test points just below and above 1 year
         let delta = 1e-6;
@@ -639,9 +567,9 @@ mod tests {
         let at_1yr = 1.0;
         let above_1yr = 1.0 + delta;
 
-        let slider_below = cosmic_time.to_slider(below_1yr);
-        let slider_at = cosmic_time.to_slider(at_1yr);
-        let slider_above = cosmic_time.to_slider(above_1yr);
+        let slider_below = scale.to_slider(below_1yr);
+        let slider_at = scale.to_slider(at_1yr);
+        let slider_above = scale.to_slider(above_1yr);
 
         assert!(
             slider_below < 0.0,
@@ -663,11 +591,11 @@ mod tests {
     /// Test log10 scale boundaries
     #[test]
     fn test_log10_scale_boundaries() {
-        let cosmic_time = CosmicTime::new();
+        let scale = TimelineScale::new();
 
         // Verify logarithmic range covers expected span
-        let min_slider_time = cosmic_time.from_slider(0.0);
-        let max_slider_time = cosmic_time.from_slider(1.0);
+        let min_slider_time = scale.from_slider(0.0);
+        let max_slider_time = scale.from_slider(1.0);
 
         assert_eq!(min_slider_time, 1e-40, "Min slider time should be MIN_YEARS");
         
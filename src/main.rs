@@ -6,9 +6,16 @@
 //!
 //! This is the main entry point that initializes the Bevy engine and registers all plugins:
 //!
-//! - **TimeIntegrationPlugin** (genesis-core): Cosmic time accumulation with f64 precision
+//! - **TimeIntegrationPlugin** (genesis-core): Ticks the authoritative `SimulationClock` (f64 precision)
+//!   on the fixed physics timestep (`FixedUpdate`), independent of frame rate and vsync
+//! - **SingularityEpoch** (genesis-core): First epoch, registered with the `EpochManager`
+//! - **CosmologyPlugin** (genesis-physics): Registers the epochs from inflation onwards, whose
+//!   systems step the scale factor while they are active
 //! - **InputPlugin** (genesis-render): Keyboard and mouse input handling (PreUpdate schedule)
-//! - **ParticlePlugin** (genesis-render): Particle spawning, rendering, and GPU instancing
+//! - **KinematicsPlugin** (genesis-physics): Drifts the particles in the core `ParticleStore`
+//!   on the fixed physics timestep
+//! - **ParticlePlugin** (genesis-render): Particle entities drawn from the `ParticleStore`,
+//!   rendering, and GPU instancing
 //! - **CameraPlugin** (genesis-render): Camera control systems (free-flight, orbit rotation)
 //! - **GenesisUiPlugin** (genesis-ui): UI overlay and timeline controls with bevy_egui
 //!
@@ -16,8 +23,14 @@
 //!
 //! - `ConfigResource`: Wraps Config for Bevy resource system
 //! - `ParticleConfig`: Resource for particle spawning (initial_count, max_count, base_size)
+//! - `ParticleStore`: Simulation state of every particle, created from `ParticleConfig`
+//! - `CosmologyConfig`: Cosmological parameters from the `[cosmology]` section (or its preset)
+//! - `EpochConfig`: Physical thresholds between cosmic epochs from the `[epochs]` section
+//! - `FixedTimestep`: Physics steps per second and substeps from the `[physics]` section
 //! - `CameraState`: Tracks camera mode (FreeFlight/Orbit) and orbit target
 //! - `OverlayState`: Controls overlay visibility (show_fps, show_particle_count)
+//! - `ConfigSavePath`: File that Ctrl+S writes the runtime configuration to
+//! - `SessionPath`: File that F5 saves the session to and F9 loads it from
 //!
 //! # Camera System
 //!
@@ -29,36 +42,264 @@
 //!
 //! # Configuration
 //!
-//! Configuration is loaded from `genesis.toml` via `Config::load()` which searches:
-//! 1. `./genesis.toml` - Workspace-local configuration
+//! Configuration is merged key by key from the following layers (later layers win):
+//! 1. `/etc/genesis/config.toml` - System-wide configuration
 //! 2. `~/.config/genesis/config.toml` - User-specific configuration
-//! 3. `/etc/genesis/config.toml` - System-wide configuration
+//! 3. `./genesis.toml` - Workspace-local configuration
+//! 4. `GENESIS_*` environment variables (e.g. `GENESIS_PARTICLE_INITIAL_COUNT`)
+//! 5. Command-line overrides (`--set`, `--seed`)
+//!
+//! # Command Line
+//!
+//! Arguments are parsed by [`genesis_core::Cli`]:
+//! - `--config <PATH>`: Use this file as the local layer instead of `./genesis.toml`
+//! - `--set <KEY=VALUE>`: Override a single config value (e.g. `particle.initial_count=500000`)
+//! - `--seed <SEED>`: Seed for stochastic initial conditions
+//! - `--start-time <YEARS>` / `--speed <FACTOR>`: Preset the cosmic clock
+//! - `--headless [--until <YEARS>] [--output <PATH>]`: Run the physics without a window
+//!   until the given cosmic time (default: present day) and optionally write a CSV
+//! - `--resume <PATH>`: Continue a saved session, using its configuration and clock
+//!   in place of the configuration layers, `--start-time` and `--speed`
+//! - `--save-session <PATH>`: Session file for F5/F9, or written at the end of a
+//!   headless run
+//! - Subcommands: `run` (default), `validate-config`, `print-config [--sources]`,
+//!   `migrate-config`
+//!
+//! `print-config` writes the effective configuration as TOML. While running, Ctrl+S
+//! saves the configuration, including the current camera mode, orbit distance and
+//! playback speed, back to the `--config` file (or `./genesis.toml`). Values set only
+//! by the system or user files, the environment or the command line are not saved
+//! unless they were changed while running.
+//!
+//! # Sessions
+//!
+//! F5 saves the whole simulation (configuration, clock, cosmological background,
+//! inflaton field, camera pose and particles) to the session file and F9 loads it
+//! back, paused. The file is `--save-session`, else `--resume`, else
+//! `./genesis.session`.
+//!
+//! Configuration files written with an older `schema_version` are migrated as they
+//! are loaded and the changes are reported on stderr; `migrate-config` rewrites the
+//! local file in the current schema.
 
 use bevy::prelude::*;
 use bevy::window::{PresentMode, WindowResolution};
-use genesis_core::Config;
+use clap::Parser;
+use genesis_core::config::LayeredConfig;
+use genesis_core::time::SimulationClock;
+use genesis_core::{
+    AppEpochExt, Cli, Command, Config, EpochTransition, FixedTimestep, ParticleStore, Session,
+    SingularityEpoch,
+};
 use genesis_core::TimeIntegrationPlugin;
-use genesis_physics::cosmology::CosmologyPlugin;
-use genesis_render::camera::{CameraController, CameraState, OrbitController};
+use genesis_physics::cosmology::{Background, Cosmology, CosmologyPlugin};
+use genesis_physics::headless::{
+    capture_session, headless_app, run_headless_app, session_app, HeadlessOptions,
+};
+use genesis_physics::inflaton::Inflaton;
+use genesis_physics::KinematicsPlugin;
+use genesis_render::camera::{
+    apply_camera_pose, camera_pose, CameraController, CameraMode, CameraState, OrbitController,
+};
 use genesis_render::input::InputPlugin;
-use genesis_render::particle::ParticlePlugin;
+use genesis_render::particle::{
+    spawn_store_particles, Particle, ParticlePlugin, PointMesh, PointSpriteMaterial,
+};
 use genesis_render::CameraPlugin;
 use genesis_ui::overlay::OverlayState;
 use genesis_ui::GenesisUiPlugin;
+use std::path::PathBuf;
+use std::process::ExitCode;
 
 /// Wrapper for Config to enable it as a Bevy Resource
 #[derive(Resource, Clone)]
 pub struct ConfigResource(pub Config);
 
+/// Configuration as loaded at startup, with the layer that supplied each value
+///
+/// Ctrl+S compares the running configuration against it to decide which keys to save.
+#[derive(Resource, Clone)]
+pub struct LoadedConfig(pub LayeredConfig);
+
+/// Path that the runtime configuration is saved to with Ctrl+S
+#[derive(Resource, Clone)]
+pub struct ConfigSavePath(pub PathBuf);
+
+/// Path that F5 saves the session to and F9 loads it from
+#[derive(Resource, Clone)]
+pub struct SessionPath(pub PathBuf);
+
+/// Loaded session waiting to replace the running simulation
+#[derive(Resource)]
+pub struct PendingSession(pub Session);
+
 /// Main application entry point
 ///
-/// Initializes the Bevy engine, loads configuration, registers plugins,
-/// and sets up the camera system.
-fn main() {
-    // Load configuration from genesis.toml (searches local, user, and system paths)
-    let config = Config::load();
+/// Parses the command line, resolves the effective configuration, and dispatches
+/// to the requested subcommand.
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    if cli.command() == Command::MigrateConfig {
+        let path = cli.local_config_path();
+        return match genesis_core::config::migrate_file(&path) {
+            Ok(report) => {
+                println!("{}", report);
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                eprintln!("Error: {}", error);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    // Merge configuration layers (files, environment, command line)
+    let layered = match cli.load_config() {
+        Ok(layered) => layered,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            return ExitCode::from(2);
+        }
+    };
+
+    for report in layered.migrations.iter().filter(|report| !report.is_empty()) {
+        eprintln!("Warning: {}", report);
+        eprintln!("Run `genesis --config {} migrate-config` to upgrade the file.", report.origin);
+    }
+
+    let config = layered.config.clone();
+
+    match cli.command() {
+        Command::ValidateConfig => match layered.validate() {
+            Ok(()) => {
+                println!("Configuration is valid");
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                eprintln!("Configuration is invalid: {}", error);
+                ExitCode::FAILURE
+            }
+        },
+        Command::PrintConfig { sources: true } => {
+            print!("{}", layered.report());
+            ExitCode::SUCCESS
+        }
+        Command::PrintConfig { sources: false } => match config.to_toml_string() {
+            Ok(toml) => {
+                print!("{}", toml);
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                eprintln!("Error: {}", error);
+                ExitCode::FAILURE
+            }
+        },
+        Command::MigrateConfig => unreachable!("handled before loading the configuration"),
+        Command::Run => {
+            if let Err(error) = layered.validate() {
+                eprintln!("Configuration is invalid: {}", error);
+                return ExitCode::FAILURE;
+            }
+            let resume = match &cli.resume {
+                Some(path) => match Session::load(path) {
+                    Ok(session) => Some(session),
+                    Err(error) => {
+                        eprintln!("Error: {}", error);
+                        return ExitCode::from(2);
+                    }
+                },
+                None => None,
+            };
+            let (config, clock) = match &resume {
+                Some(session) => (
+                    session.config.clone(),
+                    SimulationClock::from_state(&session.clock),
+                ),
+                None => match cli.clock(&config) {
+                    Ok(clock) => (config, clock),
+                    Err(error) => {
+                        eprintln!("Error: {}", error);
+                        return ExitCode::from(2);
+                    }
+                },
+            };
+            if cli.headless {
+                return run_headless_to_target(&cli, &config, clock, resume.as_ref());
+            }
+            let loaded = LayeredConfig { config, ..layered };
+            run(loaded, clock, cli.local_config_path(), cli.session_path(), resume);
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+/// Runs the physics headless to `--until`, from `resume` if given
+///
+/// Writes `--output` and `--save-session` if given.
+fn run_headless_to_target(
+    cli: &Cli,
+    config: &Config,
+    clock: SimulationClock,
+    resume: Option<&Session>,
+) -> ExitCode {
+    let mut options = HeadlessOptions::default();
+    if let Some(until) = cli.until {
+        options.until_years = until;
+    }
+
+    let mut app = match resume {
+        Some(session) => session_app(session),
+        None => headless_app(config, clock),
+    };
+    app.world_mut().resource_mut::<SimulationClock>().play();
+    let report = match run_headless_app(&mut app, &options) {
+        Ok(report) => report,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(path) = &cli.output {
+        if let Err(error) = report.write_csv(path) {
+            eprintln!("Error: could not write {}: {}", path.display(), error);
+            return ExitCode::FAILURE;
+        }
+    }
+    if let Some(path) = &cli.save_session {
+        if let Err(error) = capture_session(&app, config).save(path) {
+            eprintln!("Error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    }
+    if let Some(last) = report.last() {
+        println!(
+            "Reached {:e} years after {} steps: a = {:e}, H = {:e} GeV, T = {:e} K",
+            report.years, report.steps, last.scale_factor, last.hubble_gev, last.temperature_k
+        );
+    }
+    ExitCode::SUCCESS
+}
+
+/// Initializes the Bevy engine, registers plugins, and sets up the camera system.
+///
+/// The simulation clock is preset with the requested start time and speed and
+/// starts paused. Ctrl+S saves the runtime configuration to `save_path`; F5 and F9
+/// save and load the session at `session_path`. A `resume` session is applied on
+/// the first frame.
+fn run(
+    loaded: LayeredConfig,
+    mut clock: SimulationClock,
+    save_path: PathBuf,
+    session_path: PathBuf,
+    resume: Option<Session>,
+) {
+    clock.pause();
+    let config = loaded.config.clone();
 
-    App::new()
+    let mut app = App::new();
+    app
         // Configure window from config settings
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
@@ -78,8 +319,17 @@ fn main() {
         }))
         // Core simulation systems
         .add_plugins(TimeIntegrationPlugin)
+        .insert_resource(clock)
+        .insert_resource(FixedTimestep::from_config(&config.physics))
+        // Epochs of cosmic evolution (one active at a time)
+        .add_epoch(SingularityEpoch)
+        .insert_resource(EpochTransition::new(config.time.epoch_transition_decades))
         // Cosmological physics (scale factor, expansion dynamics)
         .add_plugins(CosmologyPlugin)
+        .insert_resource(Cosmology::from_config(&config.cosmology))
+        // Particle state and motion (rendering reads positions from the store)
+        .add_plugins(KinematicsPlugin)
+        .insert_resource(ParticleStore::from_config(&config.particle))
         // Input handling (WASD, mouse motion, mouse buttons)
         .add_plugins(InputPlugin)
         // Particle rendering with GPU instancing
@@ -88,17 +338,187 @@ fn main() {
         .add_plugins(CameraPlugin)
         // Configuration resources
         .insert_resource(ConfigResource(config.clone()))
+        .insert_resource(LoadedConfig(loaded))
+        .insert_resource(ConfigSavePath(save_path))
+        .insert_resource(SessionPath(session_path))
         .insert_resource(config.particle.clone())
+        .insert_resource(config.cosmology.clone())
+        .insert_resource(config.epochs.clone())
         .insert_resource(CameraState::from_config(&config.camera))
         // UI systems (timeline, overlay)
         .add_plugins(GenesisUiPlugin)
         .insert_resource(OverlayState {
             show_fps: config.display.show_fps,
             show_particle_count: config.display.show_particle_count,
+            ..default()
         })
         // Camera setup system
         .add_systems(Startup, setup_camera)
-        .run();
+        .add_systems(Update, save_config_on_shortcut)
+        .add_systems(Update, (save_session_on_shortcut, load_session_on_shortcut))
+        .add_systems(Update, apply_pending_session.after(load_session_on_shortcut));
+
+    if let Some(session) = resume {
+        app.insert_resource(PendingSession(session));
+    }
+    app.run();
+}
+
+/// Saves the runtime configuration when Ctrl+S is pressed
+///
+/// Copies the parameters that can be changed while running (camera mode, orbit
+/// distance and playback speed) into [`ConfigResource`], then writes it to
+/// [`ConfigSavePath`] with [`LayeredConfig::save_local`]: only values from the local
+/// file or the defaults, and values changed since [`LoadedConfig`], are written.
+/// Comments in an existing file are preserved.
+fn save_config_on_shortcut(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<ConfigResource>,
+    loaded: Res<LoadedConfig>,
+    save_path: Res<ConfigSavePath>,
+    camera_state: Res<CameraState>,
+    orbit_query: Query<&OrbitController>,
+    clock: Res<SimulationClock>,
+) {
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if !(ctrl && keys.just_pressed(KeyCode::KeyS)) {
+        return;
+    }
+
+    config.0.camera.initial_mode = match camera_state.mode {
+        CameraMode::FreeFlight => "free",
+        CameraMode::Orbit => "orbit",
+    }
+    .to_string();
+    if let Ok(orbit) = orbit_query.get_single() {
+        config.0.camera.orbit_distance = f64::from(orbit.distance);
+    }
+    config.0.time.playback_speed = clock.speed();
+
+    match loaded.0.save_local(&config.0, &save_path.0) {
+        Ok(()) => info!("Saved configuration to {}", save_path.0.display()),
+        Err(error) => error!("{}", error),
+    }
+}
+
+/// Saves the whole simulation to [`SessionPath`] when F5 is pressed
+#[allow(clippy::too_many_arguments)]
+fn save_session_on_shortcut(
+    keys: Res<ButtonInput<KeyCode>>,
+    config: Res<ConfigResource>,
+    session_path: Res<SessionPath>,
+    clock: Res<SimulationClock>,
+    cosmology: Res<Cosmology>,
+    inflaton: Option<Res<Inflaton>>,
+    camera_state: Res<CameraState>,
+    camera_query: Query<(&Transform, &OrbitController, &CameraController)>,
+    particles: Res<ParticleStore>,
+) {
+    if !keys.just_pressed(KeyCode::F5) {
+        return;
+    }
+
+    let mut session = Session::new(config.0.clone());
+    session.clock = clock.state();
+    session.background = cosmology.state();
+    if let Some(inflaton) = inflaton {
+        session.inflaton = inflaton.state();
+    }
+    if let Ok((transform, orbit, free)) = camera_query.get_single() {
+        session.camera = camera_pose(&camera_state, transform, orbit, free);
+    }
+    session.particles = particles.clone();
+
+    match session.save(&session_path.0) {
+        Ok(()) => info!("Saved session to {}", session_path.0.display()),
+        Err(error) => error!("{}", error),
+    }
+}
+
+/// Loads the session at [`SessionPath`] when F9 is pressed
+///
+/// The session is applied by [`apply_pending_session`].
+fn load_session_on_shortcut(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    session_path: Res<SessionPath>,
+) {
+    if !keys.just_pressed(KeyCode::F9) {
+        return;
+    }
+
+    match Session::load(&session_path.0) {
+        Ok(session) => {
+            info!("Loaded session from {}", session_path.0.display());
+            commands.insert_resource(PendingSession(session));
+        }
+        Err(error) => error!("{}", error),
+    }
+}
+
+/// Replaces the running simulation with a [`PendingSession`]
+///
+/// Restores the configuration resources, the clock (paused), the cosmological
+/// background, the inflaton field and the camera pose, and respawns the particles.
+/// Sessions saved by a headless run carry no particles; the current ones are kept.
+#[allow(clippy::too_many_arguments)]
+fn apply_pending_session(
+    mut commands: Commands,
+    pending: Option<Res<PendingSession>>,
+    mut camera_state: ResMut<CameraState>,
+    mut camera_query: Query<
+        (&mut Transform, &mut OrbitController, &mut CameraController),
+        Without<Particle>,
+    >,
+    particles: Query<Entity, With<Particle>>,
+    mut materials: ResMut<Assets<PointSpriteMaterial>>,
+    point_mesh: Option<Res<PointMesh>>,
+) {
+    let Some(pending) = pending else {
+        return;
+    };
+    let session = &pending.0;
+    let config = &session.config;
+
+    let mut clock = SimulationClock::from_state(&session.clock);
+    clock.pause();
+    commands.insert_resource(clock);
+    commands.insert_resource(Cosmology {
+        background: Background::from_config(&config.cosmology),
+        ..Cosmology::from_state(&session.background)
+    });
+    commands.insert_resource(Inflaton::from_state(&session.inflaton));
+    commands.insert_resource(ConfigResource(config.clone()));
+    commands.insert_resource(FixedTimestep::from_config(&config.physics));
+    commands.insert_resource(config.particle.clone());
+    commands.insert_resource(config.cosmology.clone());
+    commands.insert_resource(config.epochs.clone());
+
+    if let Ok((mut transform, mut orbit, mut free)) = camera_query.get_single_mut() {
+        apply_camera_pose(
+            &session.camera,
+            &mut camera_state,
+            &mut transform,
+            &mut orbit,
+            &mut free,
+        );
+    }
+
+    if let Some(point_mesh) = point_mesh.filter(|_| !session.particles.is_empty()) {
+        for entity in &particles {
+            commands.entity(entity).despawn();
+        }
+        spawn_store_particles(
+            &mut commands,
+            &mut materials,
+            &point_mesh,
+            config.particle.base_size,
+            &session.particles,
+        );
+        commands.insert_resource(session.particles.clone());
+    }
+
+    commands.remove_resource::<PendingSession>();
 }
 
 /// Camera setup system